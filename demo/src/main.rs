@@ -58,6 +58,8 @@ const COMPONENTS: &[ComponentEntry] = &[
     ComponentEntry { id: "input", name: "Input", description: "Text input with validation", group: "form" },
     ComponentEntry { id: "textarea", name: "Textarea", description: "Multi-line text input", group: "form" },
     ComponentEntry { id: "select", name: "Select", description: "Dropdown selection input", group: "form" },
+    ComponentEntry { id: "combobox", name: "Combobox", description: "Searchable select with async options", group: "form" },
+    ComponentEntry { id: "multi-select", name: "Multi Select", description: "Pick several values, shown as tags", group: "form" },
     ComponentEntry { id: "checkbox", name: "Checkbox", description: "Binary choice input", group: "form" },
     ComponentEntry { id: "radio", name: "Radio", description: "Single choice from options", group: "form" },
     ComponentEntry { id: "toggle", name: "Toggle", description: "Toggle for on/off states", group: "form" },
@@ -65,8 +67,10 @@ const COMPONENTS: &[ComponentEntry] = &[
     ComponentEntry { id: "file-input", name: "File Input", description: "File upload with drag-and-drop", group: "form" },
     ComponentEntry { id: "rating", name: "Rating", description: "Star rating input", group: "form" },
     ComponentEntry { id: "label", name: "Label", description: "Form field label with accessibility", group: "form" },
+    ComponentEntry { id: "use-form", name: "use_form", description: "Form state with sync and async validation", group: "form" },
     ComponentEntry { id: "segmented-input", name: "Segmented Input", description: "OTP/code input with segments", group: "form" },
-    ComponentEntry { id: "calendar", name: "Calendar", description: "Date picker calendar", group: "form" },
+    ComponentEntry { id: "calendar", name: "Calendar", description: "Date and date range calendar", group: "form" },
+    ComponentEntry { id: "date-picker", name: "Date Picker", description: "Date input with a calendar popover", group: "form" },
     // Loading
     ComponentEntry { id: "progress", name: "Progress", description: "Progress indicators", group: "loading" },
     ComponentEntry { id: "spinner", name: "Spinner", description: "Circular loading indicator", group: "loading" },
@@ -100,14 +104,26 @@ const COMPONENTS: &[ComponentEntry] = &[
     ComponentEntry { id: "thread-list", name: "Thread List", description: "Conversation list navigation", group: "chat" },
     ComponentEntry { id: "day-divider", name: "Day Divider", description: "Date separators in messages", group: "chat" },
     ComponentEntry { id: "streaming-status", name: "Streaming Status", description: "Activity indicators", group: "chat" },
+    ComponentEntry { id: "markdown", name: "Markdown", description: "Markdown rendered with glade components", group: "chat" },
     ComponentEntry { id: "attachment-chip", name: "Attachment Chip", description: "File attachment display", group: "chat" },
     ComponentEntry { id: "notification-badge", name: "Notification Badge", description: "Unread count badges", group: "chat" },
     // Data
     ComponentEntry { id: "diff-stats", name: "Diff Stats", description: "Git diff statistics display", group: "data" },
+    ComponentEntry { id: "diff-viewer", name: "Diff Viewer", description: "Unified and split diff display", group: "data" },
+    ComponentEntry { id: "file-tree", name: "File Tree", description: "Expandable file and directory tree", group: "data" },
+    ComponentEntry { id: "log-viewer", name: "Log Viewer", description: "Virtualized log with search and follow-tail", group: "data" },
+    ComponentEntry { id: "json-tree", name: "JSON Tree", description: "Collapsible JSON viewer with search", group: "data" },
+    ComponentEntry { id: "data-table", name: "Data Table", description: "Table with column definitions, sorting and filtering", group: "data" },
+    ComponentEntry { id: "tree-table", name: "Tree Table", description: "Hierarchical rows with lazy children and aggregates", group: "data" },
+    ComponentEntry { id: "virtualized", name: "Virtualized Table & List", description: "Windowed rendering for very long tables and lists", group: "data" },
     ComponentEntry { id: "tool-call-badge", name: "Tool Call Badge", description: "Tool execution status", group: "data" },
     ComponentEntry { id: "code-execution-result", name: "Code Execution Result", description: "Command output panel", group: "data" },
     ComponentEntry { id: "code-block", name: "Code Block", description: "Syntax highlighted code", group: "data" },
     ComponentEntry { id: "stat", name: "Stat", description: "Statistics/metrics display", group: "data" },
+    ComponentEntry { id: "chart", name: "Chart", description: "SVG line, area and bar charts and sparklines", group: "data" },
+    ComponentEntry { id: "activity-heatmap", name: "Activity Heatmap", description: "Daily activity calendar heatmap", group: "data" },
+    ComponentEntry { id: "timeline", name: "Timeline", description: "Spans over time with zoom and pan", group: "data" },
+    ComponentEntry { id: "kanban-board", name: "Kanban Board", description: "Columns of draggable cards with WIP limits", group: "data" },
     ComponentEntry { id: "list", name: "List", description: "Styled list with items", group: "data" },
     ComponentEntry { id: "descriptions", name: "Descriptions", description: "Key-value pairs display", group: "data" },
     ComponentEntry { id: "table", name: "Table", description: "Tabular data display", group: "data" },
@@ -1,17 +1,43 @@
-//! Chat page - ChatBubble, MessageComposer, MessageGroup, MessageList, ThreadList, etc.
+//! Chat page - ChatBubble, MessageComposer, MessageGroup, MessageList, ThreadList, Markdown, etc.
 
 use dioxus::prelude::*;
 use glade::{
-    AttachmentChip, AttachmentType, ChatBubble, ChatBubbleAlign, ChatBubbleVariant,
-    DayDivider, GroupMessage, MessageComposer, MessageGroup, MessageList, NewMessagesDivider,
+    AttachmentChip, AttachmentType, Button, ButtonVariant, ChatBubble, ChatBubbleAlign, ChatBubbleVariant,
+    DayDivider, GroupMessage, Markdown, MessageComposer, MessageGroup, MessageList, NewMessagesDivider,
     NotificationBadge, NotificationBadgeSize, Row, RowAlign, Section, Stack, StreamingStatus,
     StreamingStatusVariant, SubSection, ThreadList, ThreadListHeader, ThreadListItem,
     ThreadListSection,
 };
 
+const SAMPLE_MARKDOWN: &str = r#"## Release checklist
+
+Markdown is rendered with **glade components**: `InlineCode`, [links](https://github.com/bearcove/glade),
+tables, code blocks and ~~plain HTML~~ task lists.
+
+- [x] Bump the version in `Cargo.toml`
+- [x] Update the changelog
+- [ ] Tag the release
+
+| Crate | Version | Status |
+|:------|--------:|:------:|
+| glade | 0.1.0 | ready |
+| demo | 0.1.0 | draft |
+
+```rust
+fn main() {
+    println!("Hello, glade!");
+}
+```
+
+> Raw HTML such as <script>alert(1)</script> is shown as text, never executed.
+"#;
+
 #[component]
 pub fn ChatPage() -> Element {
     let composer_value = use_signal(String::new);
+    let mut streamed = use_signal(|| 0usize);
+    let streamed_content = &SAMPLE_MARKDOWN[..streamed()];
+    let streaming = streamed() < SAMPLE_MARKDOWN.len();
 
     rsx! {
         Section { id: "chat-bubble".to_string(), title: "Chat Bubble".to_string(),
@@ -189,6 +215,39 @@ pub fn ChatPage() -> Element {
             }
         }
 
+        Section { id: "markdown".to_string(), title: "Markdown".to_string(),
+            SubSection { title: "Rendered Message".to_string(),
+                Markdown { content: SAMPLE_MARKDOWN.to_string() }
+            }
+            SubSection { title: "Streaming".to_string(),
+                Stack {
+                    Row {
+                        Button {
+                            disabled: !streaming,
+                            onclick: move |_| {
+                                // Append the next chunk, as tokens would arrive from a model
+                                let mut end = (streamed() + 24).min(SAMPLE_MARKDOWN.len());
+                                while !SAMPLE_MARKDOWN.is_char_boundary(end) {
+                                    end += 1;
+                                }
+                                streamed.set(end);
+                            },
+                            "Next chunk"
+                        }
+                        Button { variant: ButtonVariant::Secondary, onclick: move |_| streamed.set(0), "Reset" }
+                    }
+                    ChatBubble { align: ChatBubbleAlign::Start, sender: Some("Assistant".to_string()),
+                        Markdown { content: streamed_content.to_string(), streaming }
+                    }
+                }
+            }
+            SubSection { title: "In a Chat Bubble".to_string(),
+                ChatBubble { align: ChatBubbleAlign::Start, sender: Some("Assistant".to_string()),
+                    Markdown { content: "Run `cargo test` and check the **first** failure:\n\n```bash\ncargo test -- --nocapture\n```".to_string() }
+                }
+            }
+        }
+
         Section { id: "attachment-chip".to_string(), title: "Attachment Chip".to_string(),
             SubSection { title: "Types".to_string(),
                 Row { align: RowAlign::Center,
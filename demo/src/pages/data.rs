@@ -1,16 +1,317 @@
-//! Data page - DiffStats, CodeExecutionResult, ToolCallBadge, CodeBlock, Table, Stat, List, Descriptions, HoverCard, Popover
+//! Data page - DiffStats, DiffViewer, FileTree, LogViewer, JsonTree, DataTable, VirtualTable, VirtualList, TreeTable, CodeExecutionResult, ToolCallBadge, CodeBlock, Table, Stat, Chart, Sparkline, ActivityHeatmap, Timeline, KanbanBoard, List, Descriptions, HoverCard, Popover
+
+use std::collections::HashMap;
 
 use dioxus::prelude::*;
+use jiff::ToSpan;
+use jiff::civil::{Date, Weekday};
 use glade::{
-    Button, CodeBlock, CodeExecutionResult, DescriptionItem, Descriptions, DescriptionsLayout,
-    DiffFileSummary, DiffStats, DiffStatsStyle, ExecutionStatus, Grid, HoverCard,
-    HoverCardPosition, Language, List, ListItem, Popover, PopoverContent, PopoverPosition, Row,
+    ActivityHeatmap, Badge, BadgeSize, BadgeVariant, Button, ButtonSize, ButtonVariant, CardContent, Chart, ChartKind, CodeBlock, CodeExecutionResult, Column, ColumnLayout, ColumnPin, ColumnSort, DataTable, DescriptionItem, Descriptions, DescriptionsLayout,
+    DiffFileStatus, DiffFileSummary, DiffStats, DiffStatsStyle, DiffViewMode, DiffViewer, FileDecoration, FileTree, FileTreeNode, ExecutionStatus, LogViewer, Grid, HoverCard,
+    HoverCardPosition, JsonTree, KanbanBoard, KanbanColumn, KanbanMove, Language, LineAnnotation, List, ListItem, Popover, PopoverContent, PopoverPosition, Row,
     RowAlign, Section, Stack, Stat, StatCard, StatGroup, StatTrend, SubSection, Table, TableBody,
-    TableCell, TableHeader, TableHeaderCell, TableRow, ToolCallBadge, ToolCallStatus,
+    TableCell, TableHeader, TableHeaderCell, TableRow, TableVariant, ToolCallBadge, ToolCallStatus,
+    RowHeight, Series, Sparkline, StepStatus, Timeline, TimelineRow, TimelineSpan, TreeRow, TreeTable, VirtualList, VirtualTable,
 };
 
+const SAMPLE_ANSI_STDOUT: &str = "\x1b[1m\x1b[32m   Compiling\x1b[0m glade v0.1.0\n\
+\x1b[1m\x1b[32m    Finished\x1b[0m `test` profile [unoptimized + debuginfo] target(s) in 3.21s\n\
+\x1b[1m\x1b[32m     Running\x1b[0m unittests src/lib.rs\n\n\
+running 3 tests\n\
+test parser::empty ... \x1b[32mok\x1b[0m\n\
+test parser::nested ... \x1b[32mok\x1b[0m\n\
+test parser::unicode ... \x1b[31mFAILED\x1b[0m\n\n\
+test result: \x1b[31mFAILED\x1b[0m. 2 passed; 1 failed; \x1b[2m0 ignored\x1b[0m\n";
+
+const SAMPLE_ANSI_STDERR: &str = "\x1b[1m\x1b[91merror\x1b[0m\x1b[1m: test failed, to rerun pass `--lib`\x1b[0m\n\
+\x1b[38;5;208mnote\x1b[0m: run with `\x1b[4mRUST_BACKTRACE=1\x1b[24m` for a backtrace\n";
+
+const SAMPLE_DIFF: &str = r#"diff --git a/src/server.rs b/src/server.rs
+index 3f2a1c4..8b9d0e2 100644
+--- a/src/server.rs
++++ b/src/server.rs
+@@ -12,14 +12,15 @@ impl Server {
+     pub fn bind(addr: &str) -> Result<Self> {
+         let listener = TcpListener::bind(addr)?;
+-        listener.set_nonblocking(false)?;
++        listener.set_nonblocking(true)?;
++        tracing::info!(%addr, "listening");
+         Ok(Self {
+             listener,
+             connections: Vec::new(),
+             shutdown: false,
+             max_connections: 128,
+             read_timeout: Duration::from_secs(30),
+             write_timeout: Duration::from_secs(30),
+             keepalive: true,
+         })
+     }
+ 
+     pub fn shutdown(&mut self) {
+diff --git a/src/old_name.rs b/src/new_name.rs
+similarity index 92%
+rename from src/old_name.rs
+rename to src/new_name.rs
+--- a/src/old_name.rs
++++ b/src/new_name.rs
+@@ -1,3 +1,3 @@
+-//! Old module
++//! Renamed module
+ 
+ pub mod inner;
+diff --git a/assets/logo.png b/assets/logo.png
+new file mode 100644
+index 0000000..e69de29
+Binary files /dev/null and b/assets/logo.png differ
+"#;
+
+const SAMPLE_PATHS: &[&str] = &[
+    "Cargo.toml",
+    "README.md",
+    "src/lib.rs",
+    "src/old_tree.rs",
+    "src/components/mod.rs",
+    "src/components/file_tree.rs",
+    "src/components/file_tree.module.scss",
+    "css/base.css",
+    "demo/",
+];
+/// A tool call payload, with an array long enough to be truncated
+fn sample_payload() -> serde_json::Value {
+    serde_json::json!({
+        "id": "toolu_01A09q90qw90lq917835lq9",
+        "name": "search_files",
+        "input": {
+            "pattern": "fn main",
+            "paths": ["src/", "examples/"],
+            "case_sensitive": false,
+            "max_results": null
+        },
+        "result": {
+            "truncated": false,
+            "duration_ms": 12.5,
+            "matches": (1..=250)
+                .map(|i| serde_json::json!({ "path": format!("src/bin/tool_{i}.rs"), "line": i % 40 + 1 }))
+                .collect::<Vec<_>>()
+        },
+        "metadata": { "content-type": "application/json", "retries": 0, "tags": [] }
+    })
+}
+
+/// A fake build log line, so the log viewer has something large to show
+#[derive(Clone, PartialEq)]
+struct Crate {
+    name: &'static str,
+    version: &'static str,
+    downloads: u64,
+    license: &'static str,
+}
+
+fn sample_crates() -> Vec<Crate> {
+    vec![
+        Crate { name: "serde", version: "1.0.210", downloads: 412_000_000, license: "MIT OR Apache-2.0" },
+        Crate { name: "tokio", version: "1.40.0", downloads: 238_000_000, license: "MIT" },
+        Crate { name: "rand", version: "0.8.5", downloads: 395_000_000, license: "MIT OR Apache-2.0" },
+        Crate { name: "regex", version: "1.11.0", downloads: 301_000_000, license: "MIT OR Apache-2.0" },
+        Crate { name: "clap", version: "4.5.20", downloads: 289_000_000, license: "MIT OR Apache-2.0" },
+        Crate { name: "ring", version: "0.17.8", downloads: 221_000_000, license: "ISC AND MIT" },
+        Crate { name: "anyhow", version: "1.0.89", downloads: 257_000_000, license: "MIT OR Apache-2.0" },
+        Crate { name: "dioxus", version: "0.7.0", downloads: 1_200_000, license: "MIT OR Apache-2.0" },
+    ]
+}
+
+fn crate_columns() -> Vec<Column<Crate>> {
+    vec![
+        Column::new("name", "Crate", |c: &Crate| c.name.to_string())
+            .sortable()
+            .filterable()
+            .cell(|c: &Crate| rsx! { code { "{c.name}" } }),
+        Column::new("version", "Version", |c: &Crate| c.version.to_string()),
+        Column::new("license", "License", |c: &Crate| c.license.to_string())
+            .sortable()
+            .filterable(),
+        Column::new("downloads", "Downloads", |c: &Crate| format!("{:.1}M", c.downloads as f64 / 1e6))
+            .sort_by_key(|c: &Crate| c.downloads)
+            .align("right"),
+    ]
+}
+
+const AUDIT_ACTIONS: [&str; 5] = ["login", "deploy", "update role", "rotate key", "delete branch"];
+const AUDIT_USERS: [&str; 4] = ["alice", "bob", "carol", "dave"];
+
+fn sample_file_name(n: usize) -> String {
+    format!("src/generated/module_{n:05}.rs")
+}
+
+#[derive(Clone, PartialEq)]
+struct DiskUsage {
+    name: &'static str,
+    bytes: u64,
+}
+
+fn format_bytes(bytes: u64) -> String {
+    match bytes {
+        b if b >= 1 << 20 => format!("{:.1} MiB", b as f64 / f64::from(1 << 20)),
+        b if b >= 1 << 10 => format!("{:.1} KiB", b as f64 / f64::from(1 << 10)),
+        b => format!("{b} B"),
+    }
+}
+
+fn disk_usage_columns() -> Vec<Column<DiskUsage>> {
+    vec![
+        Column::new("name", "Path", |d: &DiskUsage| d.name.to_string()),
+        Column::new("size", "Size", |d: &DiskUsage| format_bytes(d.bytes))
+            .align("right")
+            .aggregate(|rows: &[&DiskUsage]| format_bytes(rows.iter().map(|d| d.bytes).sum())),
+        Column::new("files", "Files", |_: &DiskUsage| "1".to_string())
+            .align("right")
+            .aggregate(|rows: &[&DiskUsage]| rows.len().to_string()),
+    ]
+}
+
+fn disk_usage(target_loaded: bool) -> Vec<TreeRow<DiskUsage>> {
+    let entry = |name, bytes| DiskUsage { name, bytes };
+    let target = if target_loaded {
+        TreeRow::parent("target", entry("target", 0), vec![
+            TreeRow::leaf("target/debug/glade", entry("debug/glade", 48_211_968)),
+            TreeRow::leaf("target/debug/deps.rlib", entry("debug/deps.rlib", 12_582_912)),
+        ])
+    } else {
+        TreeRow::lazy("target", entry("target", 0))
+    };
+    vec![
+        TreeRow::parent("src", entry("src", 0), vec![
+            TreeRow::parent("src/components", entry("components", 0), vec![
+                TreeRow::leaf("src/components/table.rs", entry("table.rs", 7_340)),
+                TreeRow::leaf("src/components/data_table.rs", entry("data_table.rs", 31_002)),
+            ]),
+            TreeRow::leaf("src/lib.rs", entry("lib.rs", 812)),
+        ]),
+        target,
+        TreeRow::leaf("Cargo.toml", entry("Cargo.toml", 1_420)),
+    ]
+}
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Deterministic, bursty daily counts for the `days` days up to `end`
+fn sample_activity(end: Date, days: i64, scale: u32) -> HashMap<Date, u32> {
+    (0..days)
+        .filter_map(|i| {
+            let date = end.checked_sub(i.days()).ok()?;
+            let noise = (i as u32).wrapping_mul(2_654_435_761) >> 27;
+            let weekend = matches!(date.weekday(), Weekday::Saturday | Weekday::Sunday);
+            let count = if weekend { noise / 12 } else { noise / 3 } * scale;
+            (count > 0).then_some((date, count))
+        })
+        .collect()
+}
+
+fn ci_timeline() -> Vec<TimelineRow> {
+    let s = 1_000.0;
+    vec![
+        TimelineRow::new("checkout", "checkout", vec![TimelineSpan::new("checkout", "git clone", 0.0, 4.2 * s)]),
+        TimelineRow::new("build", "build", vec![
+            TimelineSpan::new("deps", "cargo fetch", 4.5 * s, 21.0 * s),
+            TimelineSpan::new("compile", "cargo build --release", 21.0 * s, 148.0 * s),
+        ]),
+        TimelineRow::new("test", "test (3 shards)", vec![
+            TimelineSpan::new("shard-1", "shard 1/3", 150.0 * s, 201.0 * s),
+            TimelineSpan::new("shard-2", "shard 2/3", 150.0 * s, 236.0 * s).status(ToolCallStatus::Error),
+            TimelineSpan::new("shard-3", "shard 3/3", 151.0 * s, 190.0 * s),
+        ]),
+        TimelineRow::new("lint", "clippy", vec![TimelineSpan::new("clippy", "cargo clippy", 22.0 * s, 95.0 * s)]),
+        TimelineRow::new("deploy", "deploy", vec![
+            TimelineSpan::new("deploy", "deploy preview", 236.0 * s, 236.0 * s).status(ToolCallStatus::Cancelled),
+        ]),
+    ]
+}
+
+fn agent_timeline() -> Vec<TimelineRow> {
+    vec![
+        TimelineRow::new("agent", "agent", vec![
+            TimelineSpan::new("think-1", "plan", 0.0, 820.0).status(StepStatus::Completed),
+            TimelineSpan::running("think-2", "write patch", 2_950.0).status(StepStatus::Current),
+        ]),
+        TimelineRow::new("tools", "tool calls", vec![
+            TimelineSpan::new("read", "read_file", 830.0, 910.0),
+            TimelineSpan::new("grep", "grep", 840.0, 1_400.0),
+            TimelineSpan::new("bash", "cargo check", 1_420.0, 2_940.0).status(ToolCallStatus::Error),
+            TimelineSpan::new("web", "web_fetch", 1_500.0, 2_100.0),
+            TimelineSpan::new("edit", "edit_file", 2_960.0, 3_010.0).status(ToolCallStatus::Pending),
+        ]),
+    ]
+}
+
+#[derive(Clone, PartialEq)]
+struct Task {
+    id: u32,
+    title: &'static str,
+    tag: &'static str,
+}
+
+fn sample_board() -> Vec<KanbanColumn<Task>> {
+    let task = |id, title, tag| Task { id, title, tag };
+    vec![
+        KanbanColumn::new("backlog", "Backlog", vec![
+            task(1, "Dark mode for charts", "design"),
+            task(2, "Export timeline as PNG", "feature"),
+            task(3, "Flaky test in LogViewer", "bug"),
+        ]),
+        KanbanColumn::new("doing", "In progress", vec![
+            task(4, "Kanban keyboard moves", "feature"),
+            task(5, "Calendar range mode", "feature"),
+        ])
+        .wip_limit(2),
+        KanbanColumn::new("review", "Review", vec![task(6, "Heatmap tooltips", "design")]).wip_limit(3),
+        KanbanColumn::new("done", "Done", vec![
+            task(7, "DataTable selection", "feature"),
+            task(8, "Virtualized list", "feature"),
+        ])
+        .collapsed(true),
+    ]
+}
+
+fn sample_log_line(n: usize) -> String {
+    match n % 7 {
+        0 => format!("[{:>6}] INFO  worker-{}: processed batch {} in {}ms", n, n % 4, n / 7, 12 + n % 37),
+        3 => format!("[{:>6}] DEBUG cache: hit for key crate-{:04x}", n, n * 2654435761 % 65536),
+        5 if n % 5 == 0 => format!("[{:>6}] WARN  retrying request to registry (attempt {})", n, n % 3 + 1),
+        6 if n % 11 == 0 => format!("[{:>6}] ERROR failed to fetch artifact #{}: connection reset by peer", n, n),
+        _ => format!("[{:>6}] INFO  compiling unit {} of target glade", n, n / 3),
+    }
+}
+
 #[component]
 pub fn DataPage() -> Element {
+    let mut log_lines = use_signal(|| (0..100_000).map(sample_log_line).collect::<Vec<_>>());
+    let mut target_loaded = use_signal(|| false);
+    let mut jump_to = use_signal(|| None::<usize>);
+    let mut saved_layout = use_signal(String::new);
+    let mut archived = use_signal(String::new);
+    let mut usage_loaded = use_signal(|| false);
+    let activity_end = Date::constant(2026, 10, 17);
+    let mut picked_day = use_signal(|| None::<Date>);
+    let mut clicked_span = use_signal(|| None::<String>);
+    let mut board = use_signal(sample_board);
+    let mut last_move = use_signal(|| None::<KanbanMove>);
+    // A directory whose children are "fetched" the first time it's expanded
+    let lazy_tree = move || {
+        let target = if target_loaded() {
+            FileTreeNode::dir(
+                "target",
+                vec![
+                    FileTreeNode::dir("target/debug", vec![FileTreeNode::file("target/debug/glade")]),
+                    FileTreeNode::file("target/CACHEDIR.TAG"),
+                ],
+            )
+        } else {
+            FileTreeNode::lazy_dir("target")
+        };
+        vec![target, FileTreeNode::file("Cargo.lock")]
+    };
     rsx! {
         Section { id: "diff-stats".to_string(), title: "Diff Stats".to_string(),
             SubSection { title: "Inline".to_string(),
@@ -59,6 +360,179 @@ pub fn DataPage() -> Element {
             }
         }
 
+        Section { id: "diff-viewer".to_string(), title: "Diff Viewer".to_string(),
+            SubSection { title: "Unified".to_string(),
+                DiffViewer { diff: SAMPLE_DIFF.to_string() }
+            }
+            SubSection { title: "Split".to_string(),
+                DiffViewer { diff: SAMPLE_DIFF.to_string(), mode: DiffViewMode::Split }
+            }
+        }
+
+        Section { id: "file-tree".to_string(), title: "File Tree".to_string(),
+            SubSection { title: "From Paths with Decorations".to_string(),
+                div { style: "max-width: 360px;",
+                    FileTree {
+                        paths: SAMPLE_PATHS.iter().map(|p| p.to_string()).collect::<Vec<_>>(),
+                        decorations: [
+                            ("src/components/file_tree.rs".to_string(), FileDecoration::status(DiffFileStatus::Added).with_diff(412, 0)),
+                            ("src/components/mod.rs".to_string(), FileDecoration::status(DiffFileStatus::Modified).with_diff(4, 1)),
+                            ("src/old_tree.rs".to_string(), FileDecoration::status(DiffFileStatus::Deleted)),
+                            ("README.md".to_string(), FileDecoration::diff(2, 0)),
+                        ]
+                        .into_iter()
+                        .collect::<HashMap<_, _>>(),
+                        default_expanded: true,
+                        default_selected: Some("src/components/file_tree.rs".to_string()),
+                    }
+                }
+            }
+            SubSection { title: "Lazily Loaded".to_string(),
+                div { style: "max-width: 360px;",
+                    FileTree {
+                        nodes: lazy_tree(),
+                        on_expand: move |path: String| {
+                            if path == "target" && !target_loaded() {
+                                target_loaded.set(true);
+                            }
+                        },
+                    }
+                }
+            }
+        }
+
+        Section { id: "log-viewer".to_string(), title: "Log Viewer".to_string(),
+            SubSection { title: "100,000 Lines".to_string(),
+                Stack {
+                    LogViewer { lines: log_lines }
+                    Row {
+                        Button {
+                            onclick: move |_| {
+                                let mut lines = log_lines.write();
+                                let start = lines.len();
+                                lines.extend((start..start + 50).map(sample_log_line));
+                            },
+                            "Append 50 lines"
+                        }
+                    }
+                }
+            }
+        }
+
+        Section { id: "json-tree".to_string(), title: "JSON Tree".to_string(),
+            SubSection { title: "Tool Call Payload".to_string(),
+                JsonTree { value: sample_payload(), max_items: 50 }
+            }
+        }
+
+        Section { id: "data-table".to_string(), title: "Data Table".to_string(),
+            SubSection { title: "Sort and Filter".to_string(),
+                DataTable {
+                    rows: sample_crates(),
+                    columns: crate_columns(),
+                    default_sort: vec![ColumnSort::descending("downloads")],
+                    exportable: true,
+                    export_filename: "crates",
+                }
+            }
+            SubSection { title: "Row Selection".to_string(),
+                DataTable {
+                    rows: sample_crates(),
+                    columns: crate_columns(),
+                    selectable: true,
+                    row_key: |c: Crate| c.name.to_string(),
+                    bulk_actions: move |selected: Vec<Crate>| rsx! {
+                        Button {
+                            variant: ButtonVariant::Secondary,
+                            size: ButtonSize::Small,
+                            onclick: move |_| {
+                                let names: Vec<&str> = selected.iter().map(|c| c.name).collect();
+                                archived.set(names.join(", "));
+                            },
+                            "Archive"
+                        }
+                    },
+                }
+                if !archived().is_empty() {
+                    p { "Archived: {archived}" }
+                }
+            }
+            SubSection { title: "Resize, Reorder, Hide and Pin".to_string(),
+                Stack {
+                    DataTable {
+                        rows: sample_crates(),
+                        columns: crate_columns(),
+                        resizable: true,
+                        reorderable: true,
+                        column_menu: true,
+                        default_layout: ColumnLayout {
+                            pinned: [("name".to_string(), ColumnPin::Left)].into(),
+                            ..Default::default()
+                        },
+                        on_layout_change: move |layout: ColumnLayout| {
+                            saved_layout.set(serde_json::to_string(&layout).unwrap_or_default());
+                        },
+                    }
+                    if !saved_layout().is_empty() {
+                        CodeBlock { code: saved_layout(), language: Some(Language::Json), filename: "layout.json" }
+                    }
+                }
+            }
+        }
+
+        Section { id: "tree-table".to_string(), title: "Tree Table".to_string(),
+            SubSection { title: "Disk Usage with Lazy Loading".to_string(),
+                TreeTable {
+                    rows: disk_usage(usage_loaded()),
+                    columns: disk_usage_columns(),
+                    default_expanded: true,
+                    on_expand: move |key: String| {
+                        if key == "target" && !usage_loaded() {
+                            usage_loaded.set(true);
+                        }
+                    },
+                }
+            }
+        }
+
+        Section { id: "virtualized".to_string(), title: "Virtualized Table & List".to_string(),
+            SubSection { title: "10,000 Audit Events".to_string(),
+                VirtualTable {
+                    count: 10_000,
+                    columns: 3,
+                    row_height: RowHeight::Fixed(41.0),
+                    variant: TableVariant::Striped,
+                    height: "20rem",
+                    scroll_to_index: jump_to(),
+                    header: rsx! {
+                        TableHeaderCell { "#" }
+                        TableHeaderCell { "User" }
+                        TableHeaderCell { "Action" }
+                    },
+                    render_row: move |index: usize| rsx! {
+                        TableRow {
+                            TableCell { "{index}" }
+                            TableCell { {AUDIT_USERS[index % AUDIT_USERS.len()]} }
+                            TableCell { {AUDIT_ACTIONS[index * 7 % AUDIT_ACTIONS.len()]} }
+                        }
+                    },
+                }
+                Row {
+                    Button { onclick: move |_| jump_to.set(Some(5_000)), "Jump to #5000" }
+                    Button { onclick: move |_| jump_to.set(Some(0)), "Back to top" }
+                }
+            }
+            SubSection { title: "50,000 Files (Measured Heights)".to_string(),
+                VirtualList {
+                    count: 50_000,
+                    height: "16rem",
+                    render_item: move |index: usize| rsx! {
+                        ListItem { code { {sample_file_name(index)} } }
+                    },
+                }
+            }
+        }
+
         Section { id: "tool-call-badge".to_string(), title: "Tool Call Badge".to_string(),
             SubSection { title: "Statuses".to_string(),
                 Row { align: RowAlign::Center,
@@ -103,6 +577,15 @@ pub fn DataPage() -> Element {
                     stderr: "error[E0308]: mismatched types\n  --> src/main.rs:10:5\n   |\n10 |     foo()\n   |     ^^^^^ expected `i32`, found `()`".to_string(),
                 }
             }
+            SubSection { title: "ANSI Colors".to_string(),
+                CodeExecutionResult {
+                    status: ExecutionStatus::Failure,
+                    exit_code: Some(101),
+                    duration: Some("3.4s".to_string()),
+                    stdout: SAMPLE_ANSI_STDOUT.to_string(),
+                    stderr: SAMPLE_ANSI_STDERR.to_string(),
+                }
+            }
         }
 
         Section { id: "code-block".to_string(), title: "Code Block".to_string(),
@@ -124,6 +607,24 @@ pub fn DataPage() -> Element {
 }"#.to_string(),
                 }
             }
+            SubSection { title: "Language from Filename".to_string(),
+                Stack {
+                    CodeBlock {
+                        filename: Some("Dockerfile".to_string()),
+                        code: r#"FROM rust:1.85 AS build
+WORKDIR /app
+COPY . .
+RUN cargo build --release # optimized build"#.to_string(),
+                    }
+                    CodeBlock {
+                        filename: Some("migrations/001_users.sql".to_string()),
+                        code: r#"CREATE TABLE users (
+    id UUID PRIMARY KEY,
+    name TEXT NOT NULL -- display name
+);"#.to_string(),
+                    }
+                }
+            }
             SubSection { title: "With Line Numbers".to_string(),
                 CodeBlock {
                     language: Some(Language::Python),
@@ -136,6 +637,29 @@ pub fn DataPage() -> Element {
 print(fibonacci(10))"#.to_string(),
                 }
             }
+            SubSection { title: "Highlighted Lines and Annotations".to_string(),
+                CodeBlock {
+                    language: Some(Language::Rust),
+                    filename: Some("src/config.rs".to_string()),
+                    show_line_numbers: true,
+                    start_line: 41,
+                    highlight_lines: vec![43..=44],
+                    annotations: vec![
+                        LineAnnotation::error(44, "mismatched types: expected `u16`, found `&str`"),
+                        LineAnnotation::note(46, "consider using `Duration::from_secs` here"),
+                    ],
+                    code: r#"impl Config {
+    pub fn new() -> Self {
+        let port: u16 = std::env::var("PORT")
+            .unwrap_or("8080");
+        Self {
+            timeout: 30,
+            port,
+        }
+    }
+}"#.to_string(),
+                }
+            }
         }
 
         Section { id: "stat".to_string(), title: "Stat".to_string(),
@@ -153,6 +677,150 @@ print(fibonacci(10))"#.to_string(),
                     StatCard { label: "Errors".to_string(), value: "23".to_string(), trend: StatTrend::Down, change: "-15%".to_string() }
                 }
             }
+            SubSection { title: "With sparklines".to_string(),
+                Grid {
+                    StatCard {
+                        label: "Requests".to_string(),
+                        value: "48.2k".to_string(),
+                        trend: StatTrend::Up,
+                        change: "+6%".to_string(),
+                        chart: rsx! { Sparkline { values: vec![31.0, 34.0, 33.0, 38.0, 41.0, 39.0, 45.0, 48.2], area: true } },
+                    }
+                    StatCard {
+                        label: "p95 Latency".to_string(),
+                        value: "182 ms".to_string(),
+                        trend: StatTrend::Down,
+                        change: "-12%".to_string(),
+                        chart: rsx! { Sparkline { values: vec![240.0, 251.0, 230.0, 212.0, 220.0, 198.0, 190.0, 182.0], color: "var(--color-chart-2)".to_string() } },
+                    }
+                }
+            }
+        }
+
+        Section { id: "chart".to_string(), title: "Chart".to_string(),
+            SubSection { title: "Line".to_string(),
+                Chart {
+                    labels: MONTHS.iter().map(|m| m.to_string()).collect::<Vec<_>>(),
+                    series: vec![
+                        Series::new("Downloads", vec![1200.0, 1850.0, 1700.0, 2400.0, 3100.0, 2900.0, 3600.0, 4200.0, 3900.0, 4800.0, 5300.0, 6100.0]),
+                        Series::new("Stars", vec![300.0, 420.0, 510.0, 640.0, 700.0, 820.0, 990.0, 1100.0, f64::NAN, 1450.0, 1600.0, 1720.0]),
+                    ],
+                    title: "Monthly downloads and stars".to_string(),
+                }
+            }
+            SubSection { title: "Area".to_string(),
+                Chart {
+                    kind: ChartKind::Area,
+                    height: 200.0,
+                    labels: MONTHS.iter().map(|m| m.to_string()).collect::<Vec<_>>(),
+                    series: vec![Series::new("Active users", vec![820.0, 940.0, 1010.0, 980.0, 1150.0, 1320.0, 1280.0, 1460.0, 1590.0, 1540.0, 1720.0, 1880.0])],
+                    legend: false,
+                }
+            }
+            SubSection { title: "Bar".to_string(),
+                Chart {
+                    kind: ChartKind::Bar,
+                    labels: vec!["Q1".to_string(), "Q2".to_string(), "Q3".to_string(), "Q4".to_string()],
+                    series: vec![
+                        Series::new("2024", vec![42.0, 55.0, 61.0, 70.0]),
+                        Series::new("2025", vec![48.0, 63.0, 58.0, 81.0]),
+                    ],
+                    format: |v: f64| format!("${v}k"),
+                }
+            }
+            SubSection { title: "Stacked bar".to_string(),
+                Chart {
+                    kind: ChartKind::StackedBar,
+                    labels: vec!["Mon".to_string(), "Tue".to_string(), "Wed".to_string(), "Thu".to_string(), "Fri".to_string()],
+                    series: vec![
+                        Series::new("Passed", vec![182.0, 176.0, 190.0, 201.0, 195.0]),
+                        Series::new("Flaky", vec![6.0, 9.0, 4.0, 7.0, 3.0]),
+                        Series::new("Failed", vec![3.0, 12.0, 2.0, 5.0, 1.0]).color("var(--color-danger)"),
+                    ],
+                }
+            }
+        }
+
+        Section { id: "timeline".to_string(), title: "Timeline".to_string(),
+            SubSection { title: "CI jobs".to_string(),
+                Timeline {
+                    rows: ci_timeline(),
+                    on_span_click: move |id| clicked_span.set(Some(id)),
+                }
+                p {
+                    match clicked_span() {
+                        Some(id) => format!("Clicked span: {id}"),
+                        None => "Click a span; Ctrl/Cmd + wheel zooms, drag pans".to_string(),
+                    }
+                }
+            }
+            SubSection { title: "Agent tool calls (milliseconds, step statuses)".to_string(),
+                Timeline { rows: agent_timeline(), now: 3_400.0 }
+            }
+        }
+
+        Section { id: "kanban-board".to_string(), title: "Kanban Board".to_string(),
+            SubSection { title: "Drag cards, or focus one and press Space".to_string(),
+                KanbanBoard {
+                    columns: board(),
+                    card_key: |task: Task| task.id.to_string(),
+                    render_card: |task: Task| rsx! {
+                        CardContent {
+                            Stack {
+                                span { "{task.title}" }
+                                Badge {
+                                    size: BadgeSize::Small,
+                                    variant: if task.tag == "bug" { BadgeVariant::Error } else { BadgeVariant::Default },
+                                    "{task.tag}"
+                                }
+                            }
+                        }
+                    },
+                    on_move: move |mv: KanbanMove| {
+                        mv.apply(&mut board.write());
+                        last_move.set(Some(mv));
+                    },
+                }
+                p {
+                    match last_move() {
+                        Some(mv) => format!(
+                            "Moved column {} #{} to column {} #{}",
+                            mv.from_column, mv.from_index, mv.to_column, mv.to_index
+                        ),
+                        None => "No moves yet".to_string(),
+                    }
+                }
+            }
+        }
+
+        Section { id: "activity-heatmap".to_string(), title: "Activity Heatmap".to_string(),
+            SubSection { title: "Commit activity".to_string(),
+                ActivityHeatmap {
+                    counts: sample_activity(activity_end, 371, 1),
+                    end: activity_end,
+                    unit: "commit".to_string(),
+                }
+            }
+            SubSection { title: "Agent runs, custom thresholds and color".to_string(),
+                Stack {
+                    ActivityHeatmap {
+                        counts: sample_activity(activity_end, 120, 7),
+                        start: activity_end.checked_sub(16.weeks()).unwrap_or(activity_end),
+                        end: activity_end,
+                        week_start: Weekday::Monday,
+                        thresholds: vec![1, 20, 50, 100],
+                        unit: "agent run".to_string(),
+                        color: "var(--color-chart-2)".to_string(),
+                        on_select: move |date| picked_day.set(Some(date)),
+                    }
+                    p {
+                        match picked_day() {
+                            Some(date) => format!("Selected {date}"),
+                            None => "Click a day to select it".to_string(),
+                        }
+                    }
+                }
+            }
         }
 
         Section { id: "list".to_string(), title: "List".to_string(),
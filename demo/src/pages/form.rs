@@ -1,11 +1,82 @@
-//! Form page - Input, Textarea, Select, Checkbox, Radio, Toggle, Slider, FileInput, Rating, Label, Calendar
+//! Form page - Input, Textarea, Select, Checkbox, Radio, Toggle, Slider, FileInput, Rating, Label, use_form, Combobox, MultiSelect, Calendar, DatePicker
 
 use dioxus::prelude::*;
 use glade::{
-    Calendar, CalendarSize, Checkbox, FileInput, FormField, Grid, Input, Label, Radio, RadioGroup,
-    Rating, RatingSize, Row, RowAlign, Section, SegmentedInput, Select, Slider, SliderSize, Stack,
-    SubSection, Toggle, ToggleSize, Textarea,
+    Button, ButtonVariant, Calendar, CalendarSize, Checkbox, Combobox, ComboboxOption, DatePicker,
+    FileInput, FormField, Grid, Input, Label, MultiSelect, Radio, RadioGroup, RangePreset, Rating,
+    RatingSize, Row, RowAlign, Section, SegmentedInput, Select, Slider, SliderSize, Stack,
+    SubSection, Toggle, ToggleSize, Textarea, use_form,
 };
+use jiff::ToSpan;
+use jiff::civil::Date;
+
+#[derive(Clone, PartialEq, Default)]
+struct Signup {
+    username: String,
+    email: String,
+    role: String,
+    bio: String,
+    password: String,
+    confirm: String,
+}
+
+const TAKEN_USERNAMES: &[&str] = &["admin", "root", "amos"];
+
+fn framework_options() -> Vec<ComboboxOption> {
+    vec![
+        ComboboxOption::new("dioxus", "Dioxus").description("Fullstack apps with RSX"),
+        ComboboxOption::new("leptos", "Leptos").description("Fine-grained reactivity"),
+        ComboboxOption::new("yew", "Yew").description("Component-based, inspired by React"),
+        ComboboxOption::new("sycamore", "Sycamore"),
+        ComboboxOption::new("iced", "Iced").description("Desktop only").disabled(true),
+        ComboboxOption::new("egui", "egui").description("Immediate mode"),
+    ]
+}
+
+fn label_options() -> Vec<ComboboxOption> {
+    vec![
+        ComboboxOption::new("bug", "bug").group("Type"),
+        ComboboxOption::new("feature", "feature").group("Type"),
+        ComboboxOption::new("docs", "docs").group("Type"),
+        ComboboxOption::new("p0", "P0").description("Drop everything").group("Priority"),
+        ComboboxOption::new("p1", "P1").group("Priority"),
+        ComboboxOption::new("p2", "P2").group("Priority"),
+        ComboboxOption::new("ui", "ui").group("Area"),
+        ComboboxOption::new("api", "api").group("Area"),
+        ComboboxOption::new("legacy", "legacy").description("Archived").group("Area").disabled(true),
+    ]
+}
+
+fn assignee_options() -> Vec<ComboboxOption> {
+    ["amos", "bea", "carlos", "dana", "eli", "fatima"]
+        .into_iter()
+        .map(|name| ComboboxOption::new(name, format!("@{name}")))
+        .collect()
+}
+
+const REPOS: &[&str] = &[
+    "bearcove/glade",
+    "bearcove/facet",
+    "bearcove/rapace",
+    "dioxuslabs/dioxus",
+    "dioxuslabs/blitz",
+    "rust-lang/rust",
+    "rust-lang/cargo",
+    "rust-lang/rust-analyzer",
+    "tokio-rs/tokio",
+    "tokio-rs/axum",
+];
+
+/// Stand-in for a server-side search endpoint
+fn search_repos(query: &str) -> Vec<ComboboxOption> {
+    let query = query.to_lowercase();
+    REPOS
+        .iter()
+        .filter(|repo| repo.contains(&query))
+        .take(5)
+        .map(|repo| ComboboxOption::new(*repo, *repo))
+        .collect()
+}
 
 #[component]
 pub fn FormPage() -> Element {
@@ -15,6 +86,56 @@ pub fn FormPage() -> Element {
     let mut slider_value = use_signal(|| 50.0_f64);
     let mut rating_value = use_signal(|| 3);
     let mut otp_value = use_signal(|| String::new());
+    let mut signed_up = use_signal(|| None::<Signup>);
+    let mut framework = use_signal(|| "dioxus".to_string());
+    let mut frameworks = use_signal(framework_options);
+    let mut repo = use_signal(String::new);
+    let mut repo_options = use_signal(|| search_repos(""));
+    let mut repo_query = use_signal(String::new);
+    let mut repo_loading = use_signal(|| false);
+    let mut labels = use_signal(|| vec!["bug".to_string(), "p1".to_string()]);
+    let mut assignees = use_signal(Vec::<String>::new);
+    let today = Date::from(jiff::Zoned::now());
+    let mut due_date = use_signal(|| None::<Date>);
+    let mut start_date = use_signal(|| today.checked_add(1.day()).ok());
+    let mut report_range = use_signal(|| None::<(Date, Date)>);
+    let mut signup = use_form(Signup::default, |rules| {
+        rules
+            .field("username", |s| s.username.clone(), |s, v| s.username = v)
+            .required("Pick a username")
+            .min_length(3, "At least 3 characters")
+            .pattern(r"^[a-z0-9_]*$", "Lowercase letters, digits and underscores only")
+            .custom_async(|s: Signup| async move {
+                if TAKEN_USERNAMES.contains(&s.username.as_str()) {
+                    Err(format!("@{} is already taken", s.username))
+                } else {
+                    Ok(())
+                }
+            });
+        rules
+            .field("email", |s| s.email.clone(), |s, v| s.email = v)
+            .required("Email is required")
+            .pattern(r"^[^@\s]+@[^@\s]+\.[^@\s]+$", "Enter a valid email address");
+        rules
+            .field("role", |s| s.role.clone(), |s, v| s.role = v)
+            .required("Choose a role");
+        rules
+            .field("bio", |s| s.bio.clone(), |s, v| s.bio = v)
+            .max_length(160, "Keep it under 160 characters");
+        rules
+            .field("password", |s| s.password.clone(), |s, v| s.password = v)
+            .required("Choose a password")
+            .min_length(8, "At least 8 characters");
+        rules
+            .field("confirm", |s| s.confirm.clone(), |s, v| s.confirm = v)
+            .custom(|s| {
+                if s.confirm == s.password {
+                    Ok(())
+                } else {
+                    Err("Passwords don't match".to_string())
+                }
+            });
+    });
 
     rsx! {
         Section { id: "input".to_string(), title: "Input".to_string(),
@@ -219,6 +340,195 @@ pub fn FormPage() -> Element {
             }
         }
 
+        Section { id: "use-form".to_string(), title: "use_form".to_string(),
+            SubSection { title: "Validation, touched state and submit".to_string(),
+                form {
+                    novalidate: true,
+                    onsubmit: signup.handle_submit(move |value| async move { signed_up.set(Some(value)) }),
+                    Stack {
+                        Grid {
+                            Input {
+                                label: "Username".to_string(),
+                                placeholder: "try \"admin\"".to_string(),
+                                required: true,
+                                value: signup.text("username"),
+                                error: signup.error("username"),
+                                oninput: signup.oninput("username"),
+                                onblur: signup.onblur("username"),
+                                onmounted: signup.onmounted("username"),
+                            }
+                            Input {
+                                label: "Email".to_string(),
+                                r#type: "email".to_string(),
+                                placeholder: "you@example.com".to_string(),
+                                required: true,
+                                value: signup.text("email"),
+                                error: signup.error("email"),
+                                oninput: signup.oninput("email"),
+                                onblur: signup.onblur("email"),
+                                onmounted: signup.onmounted("email"),
+                            }
+                            Select {
+                                label: "Role".to_string(),
+                                required: true,
+                                value: signup.text("role"),
+                                error: signup.error("role"),
+                                onchange: signup.oninput("role"),
+                                onblur: signup.onblur("role"),
+                                onmounted: signup.onmounted("role"),
+                                option { value: "", "Select a role" }
+                                option { value: "dev", "Developer" }
+                                option { value: "design", "Designer" }
+                                option { value: "ops", "Operations" }
+                            }
+                            Input {
+                                label: "Password".to_string(),
+                                r#type: "password".to_string(),
+                                required: true,
+                                value: signup.text("password"),
+                                error: signup.error("password"),
+                                oninput: signup.oninput("password"),
+                                onblur: signup.onblur("password"),
+                                onmounted: signup.onmounted("password"),
+                            }
+                            Input {
+                                label: "Confirm password".to_string(),
+                                r#type: "password".to_string(),
+                                value: signup.text("confirm"),
+                                error: signup.error("confirm"),
+                                oninput: signup.oninput("confirm"),
+                                onblur: signup.onblur("confirm"),
+                                onmounted: signup.onmounted("confirm"),
+                            }
+                        }
+                        FormField {
+                            label: "Bio".to_string(),
+                            id: "signup-bio".to_string(),
+                            optional: true,
+                            helper: format!("{} / 160", signup.text("bio").chars().count()),
+                            error: signup.field_error("bio"),
+                            Textarea {
+                                rows: 3,
+                                value: signup.text("bio"),
+                                oninput: signup.oninput("bio"),
+                                onblur: signup.onblur("bio"),
+                                onmounted: signup.onmounted("bio"),
+                            }
+                        }
+                        Row { align: RowAlign::Center,
+                            Button { loading: signup.is_submitting(), "Create account" }
+                            if signup.is_validating("username") {
+                                span { "Checking username…" }
+                            } else if signup.is_dirty() {
+                                span { "Unsaved changes" }
+                            }
+                        }
+                    }
+                }
+                Row { align: RowAlign::Center,
+                    Button {
+                        variant: ButtonVariant::Ghost,
+                        disabled: !signup.is_dirty(),
+                        onclick: move |_| {
+                            signup.reset();
+                            signed_up.set(None);
+                        },
+                        "Reset"
+                    }
+                    if let Some(account) = signed_up() {
+                        span { "Created @{account.username} ({account.email}, {account.role})" }
+                    }
+                }
+            }
+        }
+
+        Section { id: "combobox".to_string(), title: "Combobox".to_string(),
+            Grid {
+                Stack {
+                    Combobox {
+                        label: "Framework".to_string(),
+                        placeholder: "Search frameworks...".to_string(),
+                        options: frameworks(),
+                        value: framework(),
+                        onchange: move |option: Option<ComboboxOption>| {
+                            framework.set(option.map(|o| o.value).unwrap_or_default());
+                        },
+                        oncreate: move |name: String| {
+                            let value = name.to_lowercase().replace(' ', "-");
+                            frameworks.write().push(ComboboxOption::new(value.clone(), name).description("Added by you"));
+                            framework.set(value);
+                        },
+                    }
+                    p { "Selected: " {if framework().is_empty() { "none".to_string() } else { framework() }} }
+                }
+                Stack {
+                    Combobox {
+                        label: "Repository".to_string(),
+                        placeholder: "Search repositories...".to_string(),
+                        options: repo_options(),
+                        value: repo(),
+                        loading: repo_loading(),
+                        empty_message: "No matching repositories".to_string(),
+                        onsearch: move |query: String| {
+                            repo_options.set(search_repos(&query));
+                            repo_query.set(query);
+                        },
+                        onchange: move |option: Option<ComboboxOption>| {
+                            repo.set(option.map(|o| o.value).unwrap_or_default());
+                        },
+                    }
+                    Checkbox {
+                        label: "Show loading state".to_string(),
+                        checked: repo_loading(),
+                        onchange: move |_| repo_loading.set(!repo_loading()),
+                    }
+                    p { "Last search: \"{repo_query}\"" }
+                }
+                Combobox {
+                    label: "With error".to_string(),
+                    options: framework_options(),
+                    error: "Pick a framework".to_string(),
+                    required: true,
+                }
+                Combobox {
+                    label: "Disabled".to_string(),
+                    options: framework_options(),
+                    value: "yew".to_string(),
+                    disabled: true,
+                }
+            }
+        }
+
+        Section { id: "multi-select".to_string(), title: "Multi Select".to_string(),
+            Grid {
+                Stack {
+                    MultiSelect {
+                        label: "Labels".to_string(),
+                        placeholder: "Add labels...".to_string(),
+                        options: label_options(),
+                        values: labels(),
+                        select_all: true,
+                        onchange: move |values| labels.set(values),
+                    }
+                    p { "Selected: " {labels().join(", ")} }
+                }
+                MultiSelect {
+                    label: "Assignees (up to 3)".to_string(),
+                    placeholder: "Assign people...".to_string(),
+                    options: assignee_options(),
+                    values: assignees(),
+                    max_selected: 3,
+                    onchange: move |values| assignees.set(values),
+                }
+                MultiSelect {
+                    label: "Disabled".to_string(),
+                    options: label_options(),
+                    values: vec!["docs".to_string(), "ui".to_string()],
+                    disabled: true,
+                }
+            }
+        }
+
         Section { id: "segmented-input".to_string(), title: "Segmented Input".to_string(),
             SubSection { title: "OTP / Verification code".to_string(),
                 Stack {
@@ -245,6 +555,54 @@ pub fn FormPage() -> Element {
                     Calendar { size: CalendarSize::Medium }
                 }
             }
+            SubSection { title: "Range, two months, with presets".to_string(),
+                Stack {
+                    Calendar {
+                        range: true,
+                        months: 2,
+                        presets: RangePreset::defaults(),
+                        selected_range: report_range(),
+                        max_date: today,
+                        on_range_select: move |range| report_range.set(Some(range)),
+                    }
+                    p {
+                        "Range: "
+                        {report_range().map(|(start, end)| format!("{start} to {end}")).unwrap_or_else(|| "none".to_string())}
+                    }
+                }
+            }
+        }
+
+        Section { id: "date-picker".to_string(), title: "Date Picker".to_string(),
+            Grid {
+                Stack {
+                    DatePicker {
+                        label: "Due date".to_string(),
+                        value: due_date(),
+                        onchange: move |date| due_date.set(date),
+                    }
+                    p {
+                        "Value: "
+                        {due_date().map(|d| d.to_string()).unwrap_or_else(|| "none".to_string())}
+                    }
+                    p { "Try \"Oct 31 2026\", \"31.10.2026\" or \"tomorrow\"; ArrowDown opens the calendar." }
+                }
+                DatePicker {
+                    label: "Start date (next 30 days)".to_string(),
+                    placeholder: "Mon DD, YYYY".to_string(),
+                    format: "%b %-d, %Y".to_string(),
+                    value: start_date(),
+                    min_date: today,
+                    max_date: today.checked_add(30.days()).ok(),
+                    required: true,
+                    onchange: move |date| start_date.set(date),
+                }
+                DatePicker {
+                    label: "Disabled".to_string(),
+                    value: today,
+                    disabled: true,
+                }
+            }
         }
     }
 }
@@ -0,0 +1,382 @@
+//! ANSI escape sequence parsing for terminal output
+//!
+//! Turns the output of tools like cargo, pytest or a shell into styled spans.
+//! SGR sequences (colors and text attributes) are interpreted; every other
+//! escape sequence (cursor movement, erase, window titles, hyperlinks...) is
+//! removed so that it doesn't leak into the rendered text.
+
+/// A terminal color
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AnsiColor {
+    /// One of the 16 standard colors (0-7 normal, 8-15 bright), themed via CSS
+    Named(u8),
+    /// A color from the 256-color palette (16-255)
+    Indexed(u8),
+    /// A 24-bit truecolor value
+    Rgb(u8, u8, u8),
+}
+
+impl AnsiColor {
+    /// Map a 256-color palette index to a color, keeping the first 16 themeable
+    #[must_use]
+    pub const fn from_index(index: u8) -> Self {
+        if index < 16 {
+            Self::Named(index)
+        } else {
+            Self::Indexed(index)
+        }
+    }
+
+    /// Get the RGB value of a palette or truecolor color
+    ///
+    /// Returns `None` for named colors, whose value is defined by the theme.
+    #[must_use]
+    pub const fn rgb(&self) -> Option<(u8, u8, u8)> {
+        match *self {
+            Self::Named(_) => None,
+            Self::Rgb(r, g, b) => Some((r, g, b)),
+            Self::Indexed(index) if index >= 232 => {
+                let level = 8 + (index - 232) * 10;
+                Some((level, level, level))
+            }
+            Self::Indexed(index) if index >= 16 => {
+                const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+                let cube = index - 16;
+                Some((
+                    LEVELS[(cube / 36) as usize],
+                    LEVELS[(cube / 6 % 6) as usize],
+                    LEVELS[(cube % 6) as usize],
+                ))
+            }
+            Self::Indexed(_) => None,
+        }
+    }
+}
+
+/// Text attributes in effect for a span of output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct AnsiStyle {
+    /// Foreground color (`None` for the default)
+    pub foreground: Option<AnsiColor>,
+    /// Background color (`None` for the default)
+    pub background: Option<AnsiColor>,
+    /// Bold / increased intensity
+    pub bold: bool,
+    /// Dim / decreased intensity
+    pub dim: bool,
+    /// Italic
+    pub italic: bool,
+    /// Underline
+    pub underline: bool,
+    /// Strikethrough
+    pub strikethrough: bool,
+    /// Swapped foreground and background
+    pub inverse: bool,
+}
+
+impl AnsiStyle {
+    /// Whether this is the terminal's default style
+    #[must_use]
+    pub fn is_plain(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Apply the parameters of one SGR (`ESC [ ... m`) sequence
+    fn apply_sgr(&mut self, params: &str) {
+        let mut groups = params.split(';');
+        while let Some(group) = groups.next() {
+            // `38:2::r:g:b` style sub-parameters carry the whole color in one group
+            if group.contains(':') {
+                let sub: Vec<u16> = group.split(':').map(parse_param).collect();
+                match sub.as_slice() {
+                    [38, rest @ ..] => self.foreground = extended_color_sub(rest),
+                    [48, rest @ ..] => self.background = extended_color_sub(rest),
+                    [4, 0] => self.underline = false,
+                    [4, _] => self.underline = true,
+                    _ => {}
+                }
+                continue;
+            }
+            match parse_param(group) {
+                0 => *self = Self::default(),
+                1 => self.bold = true,
+                2 => self.dim = true,
+                3 => self.italic = true,
+                4 | 21 => self.underline = true,
+                7 => self.inverse = true,
+                9 => self.strikethrough = true,
+                22 => {
+                    self.bold = false;
+                    self.dim = false;
+                }
+                23 => self.italic = false,
+                24 => self.underline = false,
+                27 => self.inverse = false,
+                29 => self.strikethrough = false,
+                code @ 30..=37 => self.foreground = Some(AnsiColor::Named((code - 30) as u8)),
+                38 => self.foreground = extended_color(&mut groups),
+                39 => self.foreground = None,
+                code @ 40..=47 => self.background = Some(AnsiColor::Named((code - 40) as u8)),
+                48 => self.background = extended_color(&mut groups),
+                49 => self.background = None,
+                code @ 90..=97 => self.foreground = Some(AnsiColor::Named((code - 90 + 8) as u8)),
+                code @ 100..=107 => {
+                    self.background = Some(AnsiColor::Named((code - 100 + 8) as u8));
+                }
+                // Blink, hidden, fonts, frames... have no sensible rendering
+                _ => {}
+            }
+        }
+    }
+}
+
+/// A run of text sharing the same style
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnsiSpan {
+    /// Style of the text
+    pub style: AnsiStyle,
+    /// Text with all escape sequences removed
+    pub text: String,
+}
+
+fn parse_param(param: &str) -> u16 {
+    // Empty parameters default to 0, out-of-range ones are ignored by clamping
+    param
+        .parse()
+        .unwrap_or(if param.is_empty() { 0 } else { u16::MAX })
+}
+
+fn channel(value: u16) -> u8 {
+    u8::try_from(value).unwrap_or(u8::MAX)
+}
+
+/// Read the `5;n` or `2;r;g;b` tail of a `38`/`48` parameter
+fn extended_color<'a>(groups: &mut impl Iterator<Item = &'a str>) -> Option<AnsiColor> {
+    match groups.next().map(parse_param)? {
+        5 => Some(AnsiColor::from_index(channel(
+            groups.next().map(parse_param)?,
+        ))),
+        2 => {
+            let mut next = || groups.next().map(parse_param).map(channel);
+            Some(AnsiColor::Rgb(next()?, next()?, next()?))
+        }
+        _ => None,
+    }
+}
+
+/// Read the colon-separated form, where truecolor may carry a color space id
+fn extended_color_sub(sub: &[u16]) -> Option<AnsiColor> {
+    match sub {
+        [5, index, ..] => Some(AnsiColor::from_index(channel(*index))),
+        [2, _, r, g, b, ..] | [2, r, g, b] => {
+            Some(AnsiColor::Rgb(channel(*r), channel(*g), channel(*b)))
+        }
+        _ => None,
+    }
+}
+
+struct Parser {
+    spans: Vec<AnsiSpan>,
+    style: AnsiStyle,
+    text: String,
+}
+
+impl Parser {
+    fn flush(&mut self) {
+        if self.text.is_empty() {
+            return;
+        }
+        let text = std::mem::take(&mut self.text);
+        match self.spans.last_mut() {
+            Some(last) if last.style == self.style => last.text.push_str(&text),
+            _ => self.spans.push(AnsiSpan {
+                style: self.style,
+                text,
+            }),
+        }
+    }
+
+    fn set_style(&mut self, style: AnsiStyle) {
+        if style != self.style {
+            self.flush();
+            self.style = style;
+        }
+    }
+
+    /// Rewind to the start of the current line, the way progress bars redraw
+    fn carriage_return(&mut self) {
+        if let Some(pos) = self.text.rfind('\n') {
+            self.text.truncate(pos + 1);
+            return;
+        }
+        self.text.clear();
+        while let Some(last) = self.spans.last_mut() {
+            if let Some(pos) = last.text.rfind('\n') {
+                last.text.truncate(pos + 1);
+                return;
+            }
+            self.spans.pop();
+        }
+    }
+}
+
+/// Parse terminal output into styled spans
+///
+/// Adjacent spans always differ in style. Carriage returns that aren't part of a
+/// `\r\n` line ending discard the current line, so only the final state of a
+/// progress bar is kept.
+#[must_use]
+pub fn parse(input: &str) -> Vec<AnsiSpan> {
+    let mut parser = Parser {
+        spans: Vec::new(),
+        style: AnsiStyle::default(),
+        text: String::new(),
+    };
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\x1b' => match chars.next() {
+                // CSI: parameters, intermediates, then a final byte in @..~
+                Some('[') => {
+                    let mut params = String::new();
+                    let mut last = None;
+                    for c in chars.by_ref() {
+                        if ('@'..='~').contains(&c) {
+                            last = Some(c);
+                            break;
+                        }
+                        params.push(c);
+                    }
+                    if last == Some('m')
+                        && params
+                            .chars()
+                            .all(|c| c.is_ascii_digit() || c == ';' || c == ':')
+                    {
+                        let mut style = parser.style;
+                        style.apply_sgr(&params);
+                        parser.set_style(style);
+                    }
+                }
+                // OSC, DCS, SOS, PM, APC: strings terminated by BEL or ST
+                Some(']' | 'P' | 'X' | '^' | '_') => {
+                    while let Some(c) = chars.next() {
+                        if c == '\x07' {
+                            break;
+                        }
+                        if c == '\x1b' {
+                            chars.next_if_eq(&'\\');
+                            break;
+                        }
+                    }
+                }
+                // Charset designation and similar three-byte sequences
+                Some('(' | ')' | '*' | '+' | '#' | '%' | ' ') => {
+                    chars.next();
+                }
+                // Two-byte sequences (save/restore cursor, keypad modes...)
+                _ => {}
+            },
+            '\r' => {
+                if chars.peek() != Some(&'\n') {
+                    parser.carriage_return();
+                }
+            }
+            '\n' | '\t' => parser.text.push(c),
+            c if c.is_control() => {}
+            c => parser.text.push(c),
+        }
+    }
+
+    parser.flush();
+    parser.spans
+}
+
+/// Remove all escape sequences from terminal output, keeping only the text
+#[must_use]
+pub fn strip(input: &str) -> String {
+    parse(input).into_iter().map(|span| span.text).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn styled(text: &str, style: AnsiStyle) -> AnsiSpan {
+        AnsiSpan {
+            style,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn plain_text_is_one_span() {
+        assert_eq!(
+            parse("hello\tworld\n"),
+            vec![styled("hello\tworld\n", AnsiStyle::default())]
+        );
+        assert!(parse("").is_empty());
+    }
+
+    #[test]
+    fn sgr_colors_and_reset() {
+        let red = AnsiStyle {
+            foreground: Some(AnsiColor::Named(1)),
+            bold: true,
+            ..AnsiStyle::default()
+        };
+        assert_eq!(
+            parse("\x1b[1;31merror\x1b[0m: oops"),
+            vec![styled("error", red), styled(": oops", AnsiStyle::default())]
+        );
+        // An empty parameter list resets too
+        assert_eq!(parse("\x1b[31mx\x1b[my")[1].style, AnsiStyle::default());
+    }
+
+    #[test]
+    fn extended_colors() {
+        let spans = parse("\x1b[38;5;196ma\x1b[48;2;1;2;3mb\x1b[38:2::10:20:30mc\x1b[38;5;9md");
+        assert_eq!(spans[0].style.foreground, Some(AnsiColor::Indexed(196)));
+        assert_eq!(spans[1].style.background, Some(AnsiColor::Rgb(1, 2, 3)));
+        assert_eq!(spans[2].style.foreground, Some(AnsiColor::Rgb(10, 20, 30)));
+        assert_eq!(spans[3].style.foreground, Some(AnsiColor::Named(9)));
+        // Truncated sequences don't panic
+        assert_eq!(strip("\x1b[38;5m\x1b[38;2;1mok"), "ok");
+    }
+
+    #[test]
+    fn palette_rgb() {
+        assert_eq!(AnsiColor::Indexed(16).rgb(), Some((0, 0, 0)));
+        assert_eq!(AnsiColor::Indexed(231).rgb(), Some((255, 255, 255)));
+        assert_eq!(AnsiColor::Indexed(232).rgb(), Some((8, 8, 8)));
+        assert_eq!(AnsiColor::Named(3).rgb(), None);
+    }
+
+    #[test]
+    fn other_sequences_are_removed() {
+        let input =
+            "\x1b]0;title\x07\x1b]8;;http://x\x1b\\link\x1b]8;;\x1b\\\x1b[2K\x1b(Bdone\x1b7";
+        assert_eq!(strip(input), "linkdone");
+        // An escape at the very end is dropped
+        assert_eq!(strip("text\x1b"), "text");
+        assert_eq!(strip("a\x1b[1"), "a");
+    }
+
+    #[test]
+    fn carriage_returns_redraw_the_line() {
+        assert_eq!(strip("first\n10%\r50%\r100%\nnext"), "first\n100%\nnext");
+        assert_eq!(strip("crlf\r\nline"), "crlf\nline");
+        // Rewinding also drops styled spans on the same line
+        assert_eq!(
+            parse("keep\n\x1b[32mgreen\x1b[0m\rplain"),
+            vec![styled("keep\nplain", AnsiStyle::default())]
+        );
+    }
+
+    #[test]
+    fn adjacent_spans_differ_in_style() {
+        let spans = parse("\x1b[1ma\x1b[1mb\x1b[22m\x1b[1mc\x1b[0md");
+        assert!(spans.windows(2).all(|w| w[0].style != w[1].style));
+        assert_eq!(strip("\x1b[1ma\x1b[1mb\x1b[22m\x1b[1mc\x1b[0md"), "abcd");
+    }
+}
@@ -0,0 +1,392 @@
+//! GitHub-style activity heatmap of daily counts
+
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use jiff::ToSpan;
+use jiff::civil::{Date, Weekday};
+
+stylance::import_style!(style, "activity_heatmap.module.scss");
+
+const MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+const WEEKDAY_NAMES: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+/// Weeks from either edge where tooltips are aligned rather than centered
+const EDGE_WEEKS: usize = 8;
+
+/// Intensity level of a count, from 0 (no activity) to `levels - 1`
+///
+/// With `thresholds`, the level is the number of thresholds the count
+/// reaches; otherwise counts are spread linearly up to `max`.
+fn intensity(count: u32, max: u32, levels: usize, thresholds: Option<&[u32]>) -> usize {
+    let top = levels.max(2) - 1;
+    if count == 0 {
+        return 0;
+    }
+    match thresholds {
+        Some(thresholds) => thresholds.iter().filter(|&&t| count >= t).count().min(top),
+        None => {
+            let ratio = f64::from(count) / f64::from(max.max(1));
+            ((ratio * top as f64).ceil() as usize).clamp(1, top)
+        }
+    }
+}
+
+/// Format a date as e.g. "Mon, Jan 5, 2026"
+fn format_day(date: Date) -> String {
+    format!(
+        "{}, {} {}, {}",
+        WEEKDAY_NAMES[date.weekday().to_sunday_zero_offset() as usize],
+        MONTH_NAMES[(date.month() - 1) as usize],
+        date.day(),
+        date.year()
+    )
+}
+
+/// A day cell of the heatmap
+struct Day {
+    date: Date,
+    count: u32,
+    /// Outside the requested range; drawn as an empty slot
+    outside: bool,
+}
+
+/// Days of the weeks covering `start..=end`, week by week, starting each week on `week_start`
+fn weeks(
+    start: Date,
+    end: Date,
+    week_start: Weekday,
+    counts: &HashMap<Date, u32>,
+) -> Vec<[Day; 7]> {
+    let offset = (start.weekday().to_sunday_zero_offset() - week_start.to_sunday_zero_offset())
+        .rem_euclid(7);
+    let mut date = start.checked_sub(i64::from(offset).days()).unwrap_or(start);
+    let mut out = Vec::new();
+    while date <= end {
+        let week = std::array::from_fn(|_| {
+            let day = Day {
+                date,
+                count: counts.get(&date).copied().unwrap_or(0),
+                outside: date < start || date > end,
+            };
+            date = date.tomorrow().unwrap_or(date);
+            day
+        });
+        out.push(week);
+        if date == Date::MAX {
+            break;
+        }
+    }
+    out
+}
+
+/// Calendar heatmap of daily counts, one column per week
+///
+/// Counts are bucketed into `levels` intensities of the theme's primary color
+/// (or `color`), either spread linearly up to the busiest day or by explicit
+/// `thresholds`. Hovering or focusing a day shows its count; the grid is a
+/// single tab stop, and arrow keys move between days (up and down within a
+/// week, left and right across weeks), with Home and End jumping to the first
+/// and last day.
+#[component]
+pub fn ActivityHeatmap(
+    /// Count per day; missing days count as zero
+    counts: HashMap<Date, u32>,
+    /// First day shown (defaults to 52 weeks before `end`)
+    #[props(optional)]
+    start: Option<Date>,
+    /// Last day shown (defaults to today)
+    #[props(optional)]
+    end: Option<Date>,
+    /// First day of each week column
+    #[props(default = Weekday::Sunday)]
+    week_start: Weekday,
+    /// Number of intensity levels, including the empty one
+    #[props(default = 5)]
+    levels: usize,
+    /// Minimum count for each level above zero, e.g. `[1, 5, 10, 20]`
+    #[props(optional)]
+    thresholds: Option<Vec<u32>>,
+    /// What is counted, in the singular
+    #[props(default = "contribution".to_string())]
+    unit: String,
+    /// Plural of `unit` (defaults to `unit` with an "s")
+    #[props(optional)]
+    unit_plural: Option<String>,
+    /// Base CSS color of the cells (defaults to the primary color)
+    #[props(optional)]
+    color: Option<String>,
+    /// Whether to show the total and the less/more legend
+    #[props(default = true)]
+    show_legend: bool,
+    /// Called when a day is clicked
+    #[props(optional)]
+    on_select: Option<EventHandler<Date>>,
+) -> Element {
+    let mut hovered: Signal<Option<(usize, usize)>> = use_signal(|| None);
+    // Day that keeps keyboard focus (roving tabindex)
+    let mut cursor = use_signal(|| None::<Date>);
+    let mut day_elements: Signal<HashMap<Date, Rc<MountedData>>> = use_signal(HashMap::new);
+    let mut pending_focus = use_signal(|| None::<Date>);
+
+    // Focus the day the cursor moved to, once its button is mounted
+    use_effect(move || {
+        let Some(date) = pending_focus() else { return };
+        if let Some(el) = day_elements.read().get(&date).cloned() {
+            pending_focus.set(None);
+            spawn(async move {
+                let _ = el.set_focus(true).await;
+            });
+        }
+    });
+
+    let today = Date::from(jiff::Zoned::now());
+    let end = end.unwrap_or(today);
+    let start = start.unwrap_or_else(|| {
+        end.checked_sub(52.weeks())
+            .unwrap_or(end)
+            .tomorrow()
+            .unwrap_or(end)
+    });
+    let grid = weeks(start, end, week_start, &counts);
+
+    let in_range = |date: &Date| *date >= start && *date <= end;
+    let max = counts
+        .iter()
+        .filter(|(d, _)| in_range(d))
+        .map(|(_, c)| *c)
+        .max()
+        .unwrap_or(0);
+    let total: u64 = counts
+        .iter()
+        .filter(|(d, _)| in_range(d))
+        .map(|(_, c)| u64::from(*c))
+        .sum();
+    let levels = levels.max(2);
+    let level_style = |level: usize| format!("--level: {}%", level * 100 / (levels - 1));
+    let plural = unit_plural.unwrap_or_else(|| format!("{unit}s"));
+    let units = |count: u64| if count == 1 { &unit } else { &plural };
+    let describe = |count: u32, date: Date| match count {
+        0 => format!("No {plural} on {}", format_day(date)),
+        n => format!("{n} {} on {}", units(u64::from(n)), format_day(date)),
+    };
+    // Label every other weekday row, starting with the second
+    let weekday_labels: Vec<&str> = (0..7)
+        .map(|row| {
+            if row % 2 == 1 {
+                WEEKDAY_NAMES[(week_start.to_sunday_zero_offset() as usize + row) % 7]
+            } else {
+                ""
+            }
+        })
+        .collect();
+
+    // Label a column when its week contains the first of a month
+    let month_labels: Vec<(usize, &str)> = grid
+        .iter()
+        .enumerate()
+        .filter_map(|(col, week)| {
+            let first = week.iter().find(|d| !d.outside && d.date.day() == 1)?;
+            Some((col, MONTH_NAMES[(first.date.month() - 1) as usize]))
+        })
+        .collect();
+
+    let tab_date = cursor().filter(in_range).unwrap_or(end);
+
+    let handle_keydown = move |evt: KeyboardEvent| {
+        let Some(from) = cursor() else { return };
+        let target = match evt.key() {
+            Key::ArrowUp => from.checked_sub(1.day()),
+            Key::ArrowDown => from.checked_add(1.day()),
+            Key::ArrowLeft => from.checked_sub(1.week()),
+            Key::ArrowRight => from.checked_add(1.week()),
+            Key::Home => Ok(start),
+            Key::End => Ok(end),
+            _ => return,
+        };
+        evt.prevent_default();
+        let Ok(date) = target else { return };
+        let date = date.clamp(start, end.max(start));
+        cursor.set(Some(date));
+        pending_focus.set(Some(date));
+    };
+
+    let tooltip = hovered().and_then(|(col, row)| {
+        let day = grid.get(col)?.get(row)?;
+        Some((col, row, describe(day.count, day.date)))
+    });
+
+    rsx! {
+        div {
+            class: style::heatmap,
+            style: color.as_ref().map(|c| format!("--heatmap-color: {c}")),
+            div { class: style::body,
+                div { class: style::weekday_labels,
+                    for (row, name) in weekday_labels.into_iter().enumerate() {
+                        span { key: "{row}", "{name}" }
+                    }
+                }
+                div { class: style::scroll,
+                    div { class: style::months, style: "--weeks: {grid.len()}",
+                        for (col, name) in month_labels {
+                            span { key: "{col}", style: "grid-column: {col + 1}", "{name}" }
+                        }
+                    }
+                    div {
+                        class: style::grid,
+                        style: "--weeks: {grid.len()}",
+                        onmouseleave: move |_| hovered.set(None),
+                        onkeydown: handle_keydown,
+                        for (col, week) in grid.iter().enumerate() {
+                            for (row, day) in week.iter().enumerate() {
+                                if day.outside {
+                                    span { key: "{col}-{row}", class: style::outside }
+                                } else {
+                                    {
+                                        let date = day.date;
+                                        let level = intensity(day.count, max, levels, thresholds.as_deref());
+                                        rsx! {
+                                            button {
+                                                key: "{col}-{row}",
+                                                r#type: "button",
+                                                class: stylance::classes!(style::day, if on_select.is_some() { style::clickable } else { "" }),
+                                                style: level_style(level),
+                                                "data-level": "{level}",
+                                                tabindex: if date == tab_date { "0" } else { "-1" },
+                                                aria_label: describe(day.count, date),
+                                                onmouseenter: move |_| hovered.set(Some((col, row))),
+                                                onfocus: move |_| {
+                                                    cursor.set(Some(date));
+                                                    hovered.set(Some((col, row)));
+                                                },
+                                                onblur: move |_| hovered.set(None),
+                                                onmounted: move |evt: MountedEvent| {
+                                                    day_elements.write().insert(date, evt.data());
+                                                },
+                                                onclick: move |_| {
+                                                    if let Some(handler) = &on_select {
+                                                        handler.call(date);
+                                                    }
+                                                },
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        if let Some((col, row, text)) = tooltip {
+                            div {
+                                class: stylance::classes!(
+                                    style::tooltip,
+                                    if col < EDGE_WEEKS { style::tooltip_start } else { "" },
+                                    if col + EDGE_WEEKS >= grid.len() { style::tooltip_end } else { "" }
+                                ),
+                                style: "--col: {col}; --row: {row}",
+                                role: "tooltip",
+                                "{text}"
+                            }
+                        }
+                    }
+                }
+            }
+            if show_legend {
+                div { class: style::footer,
+                    span {
+                        {format!("{total} {} from {} to {}", units(total), format_day(start), format_day(end))}
+                    }
+                    span { class: style::legend,
+                        "Less"
+                        for level in 0..levels {
+                            span { key: "{level}", class: style::swatch, style: level_style(level) }
+                        }
+                        "More"
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jiff::civil::date;
+
+    #[test]
+    fn intensity_spreads_counts_linearly_up_to_max() {
+        assert_eq!(intensity(0, 10, 5, None), 0);
+        assert_eq!(intensity(1, 10, 5, None), 1);
+        assert_eq!(intensity(5, 10, 5, None), 2);
+        assert_eq!(intensity(6, 10, 5, None), 3);
+        assert_eq!(intensity(10, 10, 5, None), 4);
+        // Counts above `max` and degenerate level counts stay in range
+        assert_eq!(intensity(50, 10, 5, None), 4);
+        assert_eq!(intensity(3, 0, 5, None), 4);
+        assert_eq!(intensity(3, 10, 0, None), 1);
+    }
+
+    #[test]
+    fn intensity_counts_reached_thresholds() {
+        let thresholds = [1, 5, 10, 20];
+        assert_eq!(intensity(0, 100, 5, Some(&thresholds)), 0);
+        assert_eq!(intensity(4, 100, 5, Some(&thresholds)), 1);
+        assert_eq!(intensity(5, 100, 5, Some(&thresholds)), 2);
+        assert_eq!(intensity(19, 100, 5, Some(&thresholds)), 3);
+        assert_eq!(intensity(500, 100, 5, Some(&thresholds)), 4);
+        // More thresholds than levels
+        assert_eq!(intensity(500, 100, 3, Some(&thresholds)), 2);
+    }
+
+    #[test]
+    fn weeks_start_on_the_week_start_and_pad_the_range() {
+        let counts = HashMap::from([(date(2026, 3, 4), 7), (date(2026, 2, 1), 3)]);
+        // Wednesday 2026-03-04 to Tuesday 2026-03-17
+        let grid = weeks(
+            date(2026, 3, 4),
+            date(2026, 3, 17),
+            Weekday::Sunday,
+            &counts,
+        );
+        assert_eq!(grid.len(), 3);
+        assert_eq!(grid[0][0].date, date(2026, 3, 1));
+        assert!(grid[0][..3].iter().all(|day| day.outside));
+        assert_eq!(grid[0][3].date, date(2026, 3, 4));
+        assert_eq!(grid[0][3].count, 7);
+        assert!(!grid[0][3].outside);
+        assert_eq!(grid[2][2].date, date(2026, 3, 17));
+        assert!(!grid[2][2].outside);
+        assert!(grid[2][3..].iter().all(|day| day.outside));
+        for week in &grid {
+            assert_eq!(week[0].date.weekday(), Weekday::Sunday);
+        }
+    }
+
+    #[test]
+    fn weeks_respect_a_monday_start() {
+        let grid = weeks(
+            date(2026, 3, 1),
+            date(2026, 3, 1),
+            Weekday::Monday,
+            &HashMap::new(),
+        );
+        assert_eq!(grid.len(), 1);
+        assert_eq!(grid[0][0].date, date(2026, 2, 23));
+        assert_eq!(grid[0][6].date, date(2026, 3, 1));
+        assert!(!grid[0][6].outside);
+        assert_eq!(grid[0][6].count, 0);
+    }
+
+    #[test]
+    fn weeks_are_empty_for_an_inverted_range() {
+        let grid = weeks(
+            date(2026, 3, 10),
+            date(2026, 3, 1),
+            Weekday::Sunday,
+            &HashMap::new(),
+        );
+        assert!(grid.is_empty());
+    }
+}
@@ -0,0 +1,110 @@
+//! ANSI-colored terminal output component
+
+use dioxus::prelude::*;
+
+use crate::ansi::{self, AnsiColor, AnsiStyle};
+
+stylance::import_style!(style, "ansi_text.module.scss");
+
+#[rustfmt::skip]
+const FOREGROUND: [&str; 16] = [
+    style::fg_0, style::fg_1, style::fg_2, style::fg_3,
+    style::fg_4, style::fg_5, style::fg_6, style::fg_7,
+    style::fg_8, style::fg_9, style::fg_10, style::fg_11,
+    style::fg_12, style::fg_13, style::fg_14, style::fg_15,
+];
+
+#[rustfmt::skip]
+const BACKGROUND: [&str; 16] = [
+    style::bg_0, style::bg_1, style::bg_2, style::bg_3,
+    style::bg_4, style::bg_5, style::bg_6, style::bg_7,
+    style::bg_8, style::bg_9, style::bg_10, style::bg_11,
+    style::bg_12, style::bg_13, style::bg_14, style::bg_15,
+];
+
+/// Add a color either as a themed class or as an inline declaration
+fn push_color(
+    color: AnsiColor,
+    palette: &[&'static str; 16],
+    property: &str,
+    classes: &mut Vec<&'static str>,
+    css: &mut String,
+) {
+    match color {
+        AnsiColor::Named(index) => classes.push(palette[usize::from(index & 15)]),
+        other => {
+            if let Some((r, g, b)) = other.rgb() {
+                css.push_str(&format!("{property}: rgb({r}, {g}, {b});"));
+            }
+        }
+    }
+}
+
+/// Class list and inline style for a span
+fn span_attributes(span_style: &AnsiStyle) -> (String, String) {
+    let mut classes = Vec::new();
+    let mut css = String::new();
+
+    let (foreground, background) = if span_style.inverse {
+        (span_style.background, span_style.foreground)
+    } else {
+        (span_style.foreground, span_style.background)
+    };
+
+    match foreground {
+        Some(color) => push_color(color, &FOREGROUND, "color", &mut classes, &mut css),
+        None if span_style.inverse => classes.push(style::inverse_fg),
+        None => {}
+    }
+    match background {
+        Some(color) => push_color(color, &BACKGROUND, "background", &mut classes, &mut css),
+        None if span_style.inverse => classes.push(style::inverse_bg),
+        None => {}
+    }
+
+    for (enabled, class) in [
+        (span_style.bold, style::bold),
+        (span_style.dim, style::dim),
+        (span_style.italic, style::italic),
+        (span_style.underline, style::underline),
+        (span_style.strikethrough, style::strikethrough),
+    ] {
+        if enabled {
+            classes.push(class);
+        }
+    }
+
+    (classes.join(" "), css)
+}
+
+/// Terminal output with ANSI colors and text attributes rendered as styled spans
+///
+/// Renders inline, so place it inside a `pre` (or another element preserving
+/// whitespace). Non-SGR escape sequences are stripped.
+#[component]
+pub fn AnsiText(
+    /// Raw terminal output, possibly containing escape sequences
+    text: String,
+    /// Additional CSS class
+    #[props(default)]
+    class: Option<String>,
+) -> Element {
+    let spans = ansi::parse(&text);
+
+    rsx! {
+        span { class: stylance::classes!(style::ansi_text, class.as_deref().unwrap_or("")),
+            for (idx, span) in spans.iter().enumerate() {
+                if span.style.is_plain() {
+                    span { key: "{idx}", "{span.text}" }
+                } else {
+                    {
+                        let (classes, css) = span_attributes(&span.style);
+                        rsx! {
+                            span { key: "{idx}", class: classes, style: css, "{span.text}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,6 +1,10 @@
-//! Calendar component for date selection
+//! Calendar component for date and date range selection
+
+use std::collections::HashMap;
+use std::rc::Rc;
 
 use dioxus::prelude::*;
+use jiff::ToSpan;
 use jiff::civil::{Date, Weekday};
 
 stylance::import_style!(style, "calendar.module.scss");
@@ -17,7 +21,70 @@ pub enum CalendarSize {
     Large,
 }
 
-/// Calendar component for selecting dates
+/// A named date range offered as a shortcut by a range [`Calendar`]
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct RangePreset {
+    /// Button label
+    pub label: String,
+    /// First day of the range
+    pub start: Date,
+    /// Last day of the range (inclusive)
+    pub end: Date,
+}
+
+impl RangePreset {
+    /// Create a preset from an inclusive range
+    pub fn new(label: impl Into<String>, start: Date, end: Date) -> Self {
+        Self {
+            label: label.into(),
+            start: start.min(end),
+            end: start.max(end),
+        }
+    }
+
+    /// The last `days` days, ending today
+    pub fn last_days(days: i64) -> Self {
+        let end = today();
+        let start = end.checked_sub((days - 1).max(0).days()).unwrap_or(end);
+        Self::new(format!("Last {days} days"), start, end)
+    }
+
+    /// The current calendar month
+    pub fn this_month() -> Self {
+        let today = today();
+        Self::new("This month", today.first_of_month(), today.last_of_month())
+    }
+
+    /// The previous calendar month
+    pub fn last_month() -> Self {
+        let first = today().first_of_month();
+        let start = first.checked_sub(1.month()).unwrap_or(first);
+        Self::new("Last month", start, start.last_of_month())
+    }
+
+    /// Last 7 days, last 30 days, this month and last month
+    pub fn defaults() -> Vec<Self> {
+        vec![
+            Self::last_days(7),
+            Self::last_days(30),
+            Self::this_month(),
+            Self::last_month(),
+        ]
+    }
+}
+
+fn today() -> Date {
+    Date::try_from(jiff::Zoned::now()).unwrap_or_else(|_| Date::constant(2024, 1, 1))
+}
+
+/// Calendar component for selecting dates.
+///
+/// Days are keyboard navigable: arrows move by day and week, PageUp/PageDown
+/// by month (with Shift, by year), Home/End to the start and end of the week.
+///
+/// With `range`, the first click sets an anchor and the second completes the
+/// range, previewing it under the pointer (or keyboard cursor) in between;
+/// Escape drops the anchor.
 #[component]
 pub fn Calendar(
     /// Currently selected date
@@ -26,6 +93,21 @@ pub fn Calendar(
     /// Callback when date is selected
     #[props(optional)]
     on_select: Option<EventHandler<Date>>,
+    /// Select a start/end range instead of a single date
+    #[props(default = false)]
+    range: bool,
+    /// Currently selected range, in range mode
+    #[props(optional)]
+    selected_range: Option<(Date, Date)>,
+    /// Callback with the ordered `(start, end)` range once both ends are picked
+    #[props(optional)]
+    on_range_select: Option<EventHandler<(Date, Date)>>,
+    /// Shortcut ranges listed beside the months, in range mode
+    #[props(default)]
+    presets: Vec<RangePreset>,
+    /// Number of months shown side by side
+    #[props(default = 1)]
+    months: usize,
     /// Initial month/year to display (defaults to today)
     #[props(optional)]
     initial_date: Option<Date>,
@@ -41,13 +123,39 @@ pub fn Calendar(
     /// Whether the calendar is disabled
     #[props(default = false)]
     disabled: bool,
+    /// Focus the selected (or initial) day when mounted
+    #[props(default = false)]
+    autofocus: bool,
 ) -> Element {
     // Default to today if no initial date provided
-    let today = Date::try_from(jiff::Zoned::now()).unwrap_or_else(|_| Date::constant(2024, 1, 1));
-    let initial = initial_date.or(selected).unwrap_or(today);
+    let today = today();
+    let initial = initial_date
+        .or(selected)
+        .or(selected_range.map(|(start, _)| start))
+        .unwrap_or(today);
+    let months = months.max(1);
+    let extra_months = i64::try_from(months - 1).unwrap_or(0);
 
     let mut view_year = use_signal(|| initial.year());
     let mut view_month = use_signal(|| initial.month());
+    // Day that keeps keyboard focus (roving tabindex)
+    let mut cursor = use_signal(|| None::<Date>);
+    let mut day_elements: Signal<HashMap<Date, Rc<MountedData>>> = use_signal(HashMap::new);
+    let mut pending_focus = use_signal(|| autofocus.then_some(initial));
+    // First end of a range being picked, and the day previewed as its other end
+    let mut anchor = use_signal(|| None::<Date>);
+    let mut hovered = use_signal(|| None::<Date>);
+
+    // Focus the day the cursor moved to, once its button is mounted
+    use_effect(move || {
+        let Some(date) = pending_focus() else { return };
+        if let Some(el) = day_elements.read().get(&date).cloned() {
+            pending_focus.set(None);
+            spawn(async move {
+                let _ = el.set_focus(true).await;
+            });
+        }
+    });
 
     let size_class = match size {
         CalendarSize::Small => style::small,
@@ -83,6 +191,22 @@ pub fn Calendar(
         }
     };
 
+    // Scroll the shown months just enough to include `date`
+    let mut reveal = move |date: Date| {
+        let first = Date::new(view_year(), view_month(), 1).unwrap_or(date);
+        let last = first.checked_add(extra_months.months()).unwrap_or(first);
+        let target = date.first_of_month();
+        let new_first = if target < first {
+            target
+        } else if target > last {
+            target.checked_sub(extra_months.months()).unwrap_or(target)
+        } else {
+            return;
+        };
+        view_year.set(new_first.year());
+        view_month.set(new_first.month());
+    };
+
     let is_date_disabled = |date: Date| -> bool {
         if disabled {
             return true;
@@ -100,124 +224,296 @@ pub fn Calendar(
         false
     };
 
+    let handle_keydown = move |evt: KeyboardEvent| {
+        if evt.key() == Key::Escape && anchor().is_some() {
+            evt.prevent_default();
+            evt.stop_propagation();
+            anchor.set(None);
+            return;
+        }
+        let Some(from) = cursor() else { return };
+        if disabled {
+            return;
+        }
+        let shift = evt.modifiers().shift();
+        let weekday = i64::from(from.weekday().to_sunday_zero_offset());
+        let target = match evt.key() {
+            Key::ArrowLeft => from.checked_sub(1.day()),
+            Key::ArrowRight => from.checked_add(1.day()),
+            Key::ArrowUp => from.checked_sub(1.week()),
+            Key::ArrowDown => from.checked_add(1.week()),
+            Key::PageUp if shift => from.checked_sub(1.year()),
+            Key::PageUp => from.checked_sub(1.month()),
+            Key::PageDown if shift => from.checked_add(1.year()),
+            Key::PageDown => from.checked_add(1.month()),
+            Key::Home => from.checked_sub(weekday.days()),
+            Key::End => from.checked_add((6 - weekday).days()),
+            _ => return,
+        };
+        evt.prevent_default();
+        let Ok(mut date) = target else { return };
+        if let Some(min) = min_date {
+            date = date.max(min);
+        }
+        if let Some(max) = max_date {
+            date = date.min(max);
+        }
+        reveal(date);
+        cursor.set(Some(date));
+        pending_focus.set(Some(date));
+    };
+
+    let mut pick = move |date: Date| {
+        if !range {
+            if let Some(handler) = &on_select {
+                handler.call(date);
+            }
+            return;
+        }
+        match anchor() {
+            None => {
+                anchor.set(Some(date));
+                hovered.set(Some(date));
+            }
+            Some(start) => {
+                anchor.set(None);
+                if let Some(handler) = &on_range_select {
+                    handler.call((start.min(date), start.max(date)));
+                }
+            }
+        }
+    };
+
+    let mut apply_preset = move |preset: &RangePreset| {
+        anchor.set(None);
+        reveal(preset.end);
+        reveal(preset.start);
+        if let Some(handler) = &on_range_select {
+            handler.call((preset.start, preset.end));
+        }
+    };
+
     let year = view_year();
     let month = view_month();
 
     // Use jiff to get first day of month and days in month
     let first_of_month = Date::new(year, month, 1).unwrap_or_else(|_| Date::constant(2024, 1, 1));
-    let num_days = first_of_month.days_in_month();
-
-    // Get weekday of first day (Sunday = 0)
-    let first_weekday = match first_of_month.weekday() {
-        Weekday::Sunday => 0,
-        Weekday::Monday => 1,
-        Weekday::Tuesday => 2,
-        Weekday::Wednesday => 3,
-        Weekday::Thursday => 4,
-        Weekday::Friday => 5,
-        Weekday::Saturday => 6,
-    };
+    let last_shown = first_of_month
+        .checked_add(extra_months.months())
+        .unwrap_or(first_of_month)
+        .last_of_month();
 
-    // Build calendar grid
-    let mut days: Vec<Option<i8>> = Vec::new();
-    // Add empty cells for days before first of month
-    for _ in 0..first_weekday {
-        days.push(None);
-    }
-    // Add days of month
-    for d in 1..=num_days {
-        days.push(Some(d));
-    }
-    // Pad to complete last week
-    while days.len() % 7 != 0 {
-        days.push(None);
-    }
+    // Build one grid per shown month
+    let panels: Vec<(Date, Vec<Option<Date>>)> = (0..=extra_months)
+        .map(|offset| {
+            let first = first_of_month
+                .checked_add(offset.months())
+                .unwrap_or(first_of_month);
+            // Get weekday of first day (Sunday = 0)
+            let first_weekday = match first.weekday() {
+                Weekday::Sunday => 0,
+                Weekday::Monday => 1,
+                Weekday::Tuesday => 2,
+                Weekday::Wednesday => 3,
+                Weekday::Thursday => 4,
+                Weekday::Friday => 5,
+                Weekday::Saturday => 6,
+            };
+
+            let mut days: Vec<Option<Date>> = Vec::new();
+            // Add empty cells for days before first of month
+            for _ in 0..first_weekday {
+                days.push(None);
+            }
+            // Add days of month
+            for d in 1..=first.days_in_month() {
+                days.push(Date::new(first.year(), first.month(), d).ok());
+            }
+            // Pad to complete last week
+            while days.len() % 7 != 0 {
+                days.push(None);
+            }
+            (first, days)
+        })
+        .collect();
+
+    let in_view = |date: &Date| *date >= first_of_month && *date <= last_shown;
+    let tab_date = cursor()
+        .filter(in_view)
+        .or(selected.filter(in_view))
+        .or(selected_range.map(|(start, _)| start).filter(in_view))
+        .or(Some(today).filter(in_view))
+        .unwrap_or(first_of_month);
+
+    // While picking, preview from the anchor to the hovered day
+    let preview = anchor().map(|start| {
+        let end = hovered().unwrap_or(start);
+        (start.min(end), start.max(end))
+    });
+    let shown_range = if range {
+        preview.or(selected_range)
+    } else {
+        None
+    };
 
     rsx! {
         div {
-            class: stylance::classes!(style::calendar, size_class, if disabled { style::disabled } else { "" }),
-
-            // Header with month/year and navigation
-            div { class: style::header,
-                button {
-                    class: style::nav_button,
-                    onclick: go_prev_month,
-                    disabled,
-                    aria_label: "Previous month",
-                    svg {
-                        width: "16",
-                        height: "16",
-                        view_box: "0 0 24 24",
-                        fill: "none",
-                        stroke: "currentColor",
-                        stroke_width: "2",
-                        stroke_linecap: "round",
-                        stroke_linejoin: "round",
-                        polyline { points: "15 18 9 12 15 6" }
-                    }
-                }
+            class: stylance::classes!(
+                style::calendar,
+                size_class,
+                if range { style::range } else { "" },
+                if disabled { style::disabled } else { "" }
+            ),
 
-                span { class: style::month_year,
-                    "{month_names[(month - 1) as usize]} {year}"
-                }
-
-                button {
-                    class: style::nav_button,
-                    onclick: go_next_month,
-                    disabled,
-                    aria_label: "Next month",
-                    svg {
-                        width: "16",
-                        height: "16",
-                        view_box: "0 0 24 24",
-                        fill: "none",
-                        stroke: "currentColor",
-                        stroke_width: "2",
-                        stroke_linecap: "round",
-                        stroke_linejoin: "round",
-                        polyline { points: "9 18 15 12 9 6" }
+            if range && !presets.is_empty() {
+                div { class: style::presets,
+                    for preset in presets {
+                        {
+                            let active = selected_range == Some((preset.start, preset.end));
+                            let unavailable = disabled
+                                || min_date.is_some_and(|min| preset.start < min)
+                                || max_date.is_some_and(|max| preset.end > max);
+                            rsx! {
+                                button {
+                                    key: "{preset.label}",
+                                    class: stylance::classes!(
+                                        style::preset,
+                                        if active { style::active } else { "" }
+                                    ),
+                                    disabled: unavailable,
+                                    aria_pressed: "{active}",
+                                    onclick: move |_| apply_preset(&preset),
+                                    "{preset.label}"
+                                }
+                            }
+                        }
                     }
                 }
             }
 
-            // Day names header
-            div { class: style::weekdays,
-                for day_name in day_names {
-                    span { class: style::weekday, "{day_name}" }
-                }
-            }
+            div { class: style::months,
+                for (index, (first, days)) in panels.into_iter().enumerate() {
+                    div { key: "{first}", class: style::month,
+                        // Header with month/year and navigation
+                        div { class: style::header,
+                            if index == 0 {
+                                button {
+                                    class: style::nav_button,
+                                    onclick: go_prev_month,
+                                    disabled,
+                                    aria_label: "Previous month",
+                                    svg {
+                                        width: "16",
+                                        height: "16",
+                                        view_box: "0 0 24 24",
+                                        fill: "none",
+                                        stroke: "currentColor",
+                                        stroke_width: "2",
+                                        stroke_linecap: "round",
+                                        stroke_linejoin: "round",
+                                        polyline { points: "15 18 9 12 15 6" }
+                                    }
+                                }
+                            } else {
+                                span { class: style::nav_spacer }
+                            }
 
-            // Calendar grid
-            div { class: style::grid,
-                for (idx, day_opt) in days.iter().enumerate() {
-                    match day_opt {
-                        Some(day) => {
-                            let date = Date::new(year, month, *day).unwrap_or(first_of_month);
-                            let is_selected = selected.map(|s| s == date).unwrap_or(false);
-                            let is_disabled = is_date_disabled(date);
-                            let is_today = date == today;
+                            span { class: style::month_year, aria_live: "polite",
+                                "{month_names[(first.month() - 1) as usize]} {first.year()}"
+                            }
 
-                            rsx! {
+                            if index == months - 1 {
                                 button {
-                                    key: "day-{idx}",
-                                    class: stylance::classes!(
-                                        style::day,
-                                        if is_selected { style::selected } else { "" },
-                                        if is_today && !is_selected { style::today } else { "" }
-                                    ),
-                                    disabled: is_disabled,
-                                    onclick: move |_| {
-                                        if let Some(handler) = &on_select {
-                                            handler.call(date);
-                                        }
-                                    },
-                                    "{day}"
+                                    class: style::nav_button,
+                                    onclick: go_next_month,
+                                    disabled,
+                                    aria_label: "Next month",
+                                    svg {
+                                        width: "16",
+                                        height: "16",
+                                        view_box: "0 0 24 24",
+                                        fill: "none",
+                                        stroke: "currentColor",
+                                        stroke_width: "2",
+                                        stroke_linecap: "round",
+                                        stroke_linejoin: "round",
+                                        polyline { points: "9 18 15 12 9 6" }
+                                    }
                                 }
+                            } else {
+                                span { class: style::nav_spacer }
                             }
                         }
-                        None => {
-                            rsx! {
-                                span { key: "empty-{idx}", class: style::day_empty }
+
+                        // Day names header
+                        div { class: style::weekdays,
+                            for day_name in day_names {
+                                span { class: style::weekday, "{day_name}" }
+                            }
+                        }
+
+                        // Calendar grid
+                        div {
+                            class: style::grid,
+                            onkeydown: handle_keydown,
+                            onmouseleave: move |_| hovered.set(None),
+                            for (idx, day_opt) in days.into_iter().enumerate() {
+                                match day_opt {
+                                    Some(date) => {
+                                        let is_start = shown_range.is_some_and(|(start, _)| start == date);
+                                        let is_end = shown_range.is_some_and(|(_, end)| end == date);
+                                        let in_range = shown_range
+                                            .is_some_and(|(start, end)| date > start && date < end);
+                                        let is_selected = if range {
+                                            is_start || is_end
+                                        } else {
+                                            selected.map(|s| s == date).unwrap_or(false)
+                                        };
+                                        let is_disabled = is_date_disabled(date);
+                                        let is_today = date == today;
+
+                                        rsx! {
+                                            button {
+                                                key: "{date}",
+                                                class: stylance::classes!(
+                                                    style::day,
+                                                    if is_selected { style::selected } else { "" },
+                                                    if is_today && !is_selected { style::today } else { "" },
+                                                    if is_start { style::range_start } else { "" },
+                                                    if is_end { style::range_end } else { "" },
+                                                    if in_range { style::in_range } else { "" },
+                                                    if preview.is_some() { style::preview } else { "" }
+                                                ),
+                                                disabled: is_disabled,
+                                                tabindex: if date == tab_date { "0" } else { "-1" },
+                                                aria_label: date.strftime("%A, %B %-d, %Y").to_string(),
+                                                aria_pressed: "{is_selected || in_range}",
+                                                aria_current: if is_today { "date" } else { "false" },
+                                                onfocus: move |_| {
+                                                    cursor.set(Some(date));
+                                                    if anchor().is_some() {
+                                                        hovered.set(Some(date));
+                                                    }
+                                                },
+                                                onmouseenter: move |_| {
+                                                    if anchor().is_some() {
+                                                        hovered.set(Some(date));
+                                                    }
+                                                },
+                                                onmounted: move |evt: MountedEvent| {
+                                                    day_elements.write().insert(date, evt.data());
+                                                },
+                                                onclick: move |_| pick(date),
+                                                "{date.day()}"
+                                            }
+                                        }
+                                    }
+                                    None => {
+                                        rsx! {
+                                            span { key: "empty-{idx}", class: style::day_empty }
+                                        }
+                                    }
+                                }
                             }
                         }
                     }
@@ -0,0 +1,667 @@
+//! SVG charts (line, area, bar, stacked bar) and `Sparkline`
+//!
+//! Everything is computed in Rust and rendered as plain SVG, so charts
+//! render on the server and hydrate without a JavaScript charting library.
+
+use dioxus::prelude::*;
+
+stylance::import_style!(style, "chart.module.scss");
+
+/// Number of colors in the `--color-chart-N` palette of `css/base.css`
+const PALETTE_SIZE: usize = 6;
+
+/// Space around the plot area for axis labels
+const PAD_LEFT: f64 = 48.0;
+const PAD_RIGHT: f64 = 16.0;
+const PAD_TOP: f64 = 12.0;
+const PAD_BOTTOM: f64 = 28.0;
+
+/// Minimum horizontal space per x-axis label before labels are skipped
+const MIN_LABEL_SPACING: f64 = 56.0;
+
+/// Kind of chart to draw
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChartKind {
+    /// Lines through the points of each series (default)
+    #[default]
+    Line,
+    /// Lines with the area below them filled
+    Area,
+    /// Bars side by side for each label
+    Bar,
+    /// Bars stacked on top of each other for each label
+    StackedBar,
+}
+
+impl ChartKind {
+    /// Get the kind as a lowercase string
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Line => "line",
+            Self::Area => "area",
+            Self::Bar => "bar",
+            Self::StackedBar => "stacked-bar",
+        }
+    }
+
+    const fn is_bar(self) -> bool {
+        matches!(self, Self::Bar | Self::StackedBar)
+    }
+}
+
+/// A named series of values, one per chart label
+#[derive(Debug, Clone, PartialEq)]
+pub struct Series {
+    /// Name shown in the legend and tooltip
+    pub name: String,
+    /// Values by label index; `NaN` leaves a gap
+    pub values: Vec<f64>,
+    /// CSS color; defaults to the theme palette
+    pub color: Option<String>,
+}
+
+impl Series {
+    /// Create a series with a theme color
+    #[must_use]
+    pub fn new(name: impl Into<String>, values: Vec<f64>) -> Self {
+        Self {
+            name: name.into(),
+            values,
+            color: None,
+        }
+    }
+
+    /// Use a specific CSS color
+    #[must_use]
+    pub fn color(mut self, color: impl Into<String>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    fn value(&self, index: usize) -> Option<f64> {
+        self.values.get(index).copied().filter(|v| v.is_finite())
+    }
+
+    fn css_color(&self, index: usize) -> String {
+        self.color.clone().unwrap_or_else(|| palette_color(index))
+    }
+}
+
+/// Theme color for the series at `index`
+fn palette_color(index: usize) -> String {
+    format!("var(--color-chart-{})", index % PALETTE_SIZE + 1)
+}
+
+/// Format a number compactly, e.g. `1.2k` or `3.5M`
+#[must_use]
+pub fn format_compact(value: f64) -> String {
+    fn trim(text: String) -> String {
+        if text.contains('.') {
+            text.trim_end_matches('0').trim_end_matches('.').to_string()
+        } else {
+            text
+        }
+    }
+    let abs = value.abs();
+    let (scaled, suffix) = match abs {
+        a if a >= 1e9 => (value / 1e9, "B"),
+        a if a >= 1e6 => (value / 1e6, "M"),
+        a if a >= 1e3 => (value / 1e3, "k"),
+        _ => (value, ""),
+    };
+    let digits = if suffix.is_empty() && scaled.fract() != 0.0 {
+        2
+    } else {
+        1
+    };
+    format!("{}{suffix}", trim(format!("{scaled:.digits$}")))
+}
+
+/// Round tick positions covering `min..=max`, about `count` of them
+fn nice_ticks(min: f64, max: f64, count: usize) -> Vec<f64> {
+    let (min, max) = if min == max {
+        if min == 0.0 {
+            (0.0, 1.0)
+        } else {
+            (min.min(0.0), max.max(0.0))
+        }
+    } else {
+        (min, max)
+    };
+    let raw = (max - min) / count.max(1) as f64;
+    let magnitude = 10f64.powf(raw.log10().floor());
+    let step = magnitude
+        * match raw / magnitude {
+            n if n <= 1.0 => 1.0,
+            n if n <= 2.0 => 2.0,
+            n if n <= 5.0 => 5.0,
+            _ => 10.0,
+        };
+    let first = (min / step).floor() as i64;
+    let last = (max / step).ceil() as i64;
+    (first..=last).map(|i| i as f64 * step).collect()
+}
+
+/// Plot area and value range of a chart
+#[derive(Clone, Copy)]
+struct Frame {
+    left: f64,
+    top: f64,
+    width: f64,
+    height: f64,
+    lo: f64,
+    hi: f64,
+    count: usize,
+    banded: bool,
+}
+
+impl Frame {
+    fn y(&self, value: f64) -> f64 {
+        let span = (self.hi - self.lo).max(f64::EPSILON);
+        self.top + self.height - (value - self.lo) / span * self.height
+    }
+
+    fn bottom(&self) -> f64 {
+        self.top + self.height
+    }
+
+    /// Width of the slot of one label
+    fn band(&self) -> f64 {
+        if self.banded {
+            self.width / self.count.max(1) as f64
+        } else if self.count > 1 {
+            self.width / (self.count - 1) as f64
+        } else {
+            self.width
+        }
+    }
+
+    /// Horizontal center of the label at `index`
+    fn x(&self, index: usize) -> f64 {
+        if self.banded {
+            self.left + (index as f64 + 0.5) * self.band()
+        } else if self.count > 1 {
+            self.left + index as f64 * self.band()
+        } else {
+            self.left + self.width / 2.0
+        }
+    }
+
+    /// Hover area of the label at `index`, clipped to the plot
+    fn slot(&self, index: usize) -> (f64, f64) {
+        let half = self.band() / 2.0;
+        let start = (self.x(index) - half).max(self.left);
+        let end = (self.x(index) + half).min(self.left + self.width);
+        (start, end - start)
+    }
+}
+
+/// SVG path through the points of a series, broken at missing values
+fn line_path(frame: &Frame, series: &Series) -> String {
+    let mut path = String::new();
+    let mut pen_down = false;
+    for i in 0..frame.count {
+        match series.value(i) {
+            Some(v) => {
+                let cmd = if pen_down { 'L' } else { 'M' };
+                path.push_str(&format!("{cmd}{:.2},{:.2} ", frame.x(i), frame.y(v)));
+                pen_down = true;
+            }
+            None => pen_down = false,
+        }
+    }
+    path
+}
+
+/// SVG path filling the area between a series and the baseline
+fn area_path(frame: &Frame, series: &Series) -> String {
+    let base = frame.y(0f64.clamp(frame.lo, frame.hi));
+    let mut path = String::new();
+    let mut run: Vec<(f64, f64)> = Vec::new();
+    let close = |run: &mut Vec<(f64, f64)>, path: &mut String| {
+        if let (Some(first), Some(last)) = (run.first(), run.last()) {
+            path.push_str(&format!("M{:.2},{base:.2} ", first.0));
+            for (x, y) in run.iter() {
+                path.push_str(&format!("L{x:.2},{y:.2} "));
+            }
+            path.push_str(&format!("L{:.2},{base:.2} Z ", last.0));
+        }
+        run.clear();
+    };
+    for i in 0..frame.count {
+        match series.value(i) {
+            Some(v) => run.push((frame.x(i), frame.y(v))),
+            None => close(&mut run, &mut path),
+        }
+    }
+    close(&mut run, &mut path);
+    path
+}
+
+/// A bar to draw
+struct Bar {
+    series: usize,
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+}
+
+fn bars(frame: &Frame, kind: ChartKind, series: &[Series]) -> Vec<Bar> {
+    let band = frame.band();
+    let inner = band * 0.7;
+    let mut out = Vec::new();
+    for i in 0..frame.count {
+        let start = frame.x(i) - inner / 2.0;
+        let (mut up, mut down) = (0.0, 0.0);
+        for (s, entry) in series.iter().enumerate() {
+            let Some(v) = entry.value(i) else { continue };
+            let (from, to, x, width) = if kind == ChartKind::StackedBar {
+                let acc: &mut f64 = if v >= 0.0 { &mut up } else { &mut down };
+                let from = *acc;
+                *acc += v;
+                (from, *acc, start, inner)
+            } else {
+                let width = inner / series.len() as f64;
+                (0.0, v, start + s as f64 * width, width)
+            };
+            let (y0, y1) = (frame.y(from), frame.y(to));
+            out.push(Bar {
+                series: s,
+                x,
+                y: y0.min(y1),
+                width: (width - 1.0).max(1.0),
+                height: (y0 - y1).abs(),
+            });
+        }
+    }
+    out
+}
+
+/// Value range to plot
+fn value_range(kind: ChartKind, series: &[Series], count: usize) -> (f64, f64) {
+    let mut lo = f64::INFINITY;
+    let mut hi = f64::NEG_INFINITY;
+    for i in 0..count {
+        if kind == ChartKind::StackedBar {
+            let values = series.iter().filter_map(|s| s.value(i));
+            let (up, down) = values.fold((0.0, 0.0), |(up, down), v| {
+                if v >= 0.0 {
+                    (up + v, down)
+                } else {
+                    (up, down + v)
+                }
+            });
+            lo = lo.min(down);
+            hi = hi.max(up);
+        } else {
+            for v in series.iter().filter_map(|s| s.value(i)) {
+                lo = lo.min(v);
+                hi = hi.max(v);
+            }
+        }
+    }
+    if !lo.is_finite() {
+        return (0.0, 1.0);
+    }
+    // Bars and areas are measured from zero
+    if kind != ChartKind::Line {
+        lo = lo.min(0.0);
+        hi = hi.max(0.0);
+    }
+    (lo, hi)
+}
+
+/// Position of a box inside the drawing, in percent, as inline CSS
+fn percent_box(x: f64, y: f64, w: f64, h: f64, width: f64, height: f64) -> String {
+    format!(
+        "left: {:.3}%; top: {:.3}%; width: {:.3}%; height: {:.3}%",
+        x / width * 100.0,
+        y / height * 100.0,
+        w / width * 100.0,
+        h / height * 100.0
+    )
+}
+
+/// Chart with axes, tooltips and a legend
+///
+/// `labels` name the x-axis positions and each series has one value per
+/// label. Colors come from the `--color-chart-N` palette unless a series sets
+/// its own. Tooltips are plain HTML shown on hover or focus with CSS, so they
+/// work on server-rendered pages before hydration.
+#[component]
+pub fn Chart(
+    /// X-axis labels
+    labels: Vec<String>,
+    /// Data series
+    series: Vec<Series>,
+    /// Kind of chart
+    #[props(default)]
+    kind: ChartKind,
+    /// Width of the drawing in SVG units; the chart scales to its container
+    #[props(default = 640.0)]
+    width: f64,
+    /// Height of the drawing in SVG units
+    #[props(default = 240.0)]
+    height: f64,
+    /// Approximate number of y-axis ticks
+    #[props(default = 5)]
+    ticks: usize,
+    /// Format values on the y-axis and in tooltips (defaults to compact numbers)
+    #[props(default)]
+    format: Option<Callback<f64, String>>,
+    /// Whether to show the legend
+    #[props(default = true)]
+    legend: bool,
+    /// Accessible title of the chart
+    #[props(default)]
+    title: Option<String>,
+    /// Additional CSS class
+    #[props(default)]
+    class: Option<String>,
+) -> Element {
+    let fmt = move |v: f64| match &format {
+        Some(format) => format.call(v),
+        None => format_compact(v),
+    };
+
+    let count = labels.len();
+    let (lo, hi) = value_range(kind, &series, count);
+    let tick_values = nice_ticks(lo, hi, ticks);
+    let frame = Frame {
+        left: PAD_LEFT,
+        top: PAD_TOP,
+        width: (width - PAD_LEFT - PAD_RIGHT).max(1.0),
+        height: (height - PAD_TOP - PAD_BOTTOM).max(1.0),
+        lo: tick_values.first().copied().unwrap_or(lo),
+        hi: tick_values.last().copied().unwrap_or(hi),
+        count,
+        banded: kind.is_bar(),
+    };
+    let label_every = (count as f64 * MIN_LABEL_SPACING / frame.width)
+        .ceil()
+        .max(1.0) as usize;
+    let baseline = if frame.lo < 0.0 && frame.hi > 0.0 {
+        frame.y(0.0)
+    } else {
+        frame.bottom()
+    };
+    let bar_list = if kind.is_bar() {
+        bars(&frame, kind, &series)
+    } else {
+        Vec::new()
+    };
+
+    rsx! {
+        figure { class: stylance::classes!(style::chart, class.as_deref().unwrap_or("")),
+            div { class: style::plot,
+                svg {
+                    class: style::svg,
+                    view_box: "0 0 {width} {height}",
+                    role: "img",
+                    "aria-label": title.clone().unwrap_or_else(|| "Chart".to_string()),
+                    g { class: style::grid,
+                        for tick in tick_values.iter().copied() {
+                            g { key: "{tick}",
+                                line {
+                                    x1: "{frame.left}",
+                                    x2: "{frame.left + frame.width}",
+                                    y1: "{frame.y(tick):.2}",
+                                    y2: "{frame.y(tick):.2}",
+                                }
+                                text {
+                                    class: style::tick_label,
+                                    x: "{frame.left - 8.0}",
+                                    y: "{frame.y(tick):.2}",
+                                    text_anchor: "end",
+                                    dominant_baseline: "middle",
+                                    "{fmt(tick)}"
+                                }
+                            }
+                        }
+                    }
+                    line {
+                        class: style::axis,
+                        x1: "{frame.left}",
+                        x2: "{frame.left + frame.width}",
+                        y1: "{baseline:.2}",
+                        y2: "{baseline:.2}",
+                    }
+                    for (i, label) in labels.iter().enumerate() {
+                        if i % label_every == 0 {
+                            text {
+                                key: "{i}",
+                                class: style::tick_label,
+                                x: "{frame.x(i):.2}",
+                                y: "{frame.bottom() + 18.0}",
+                                text_anchor: "middle",
+                                "{label}"
+                            }
+                        }
+                    }
+                    if kind.is_bar() {
+                        for (n, bar) in bar_list.iter().enumerate() {
+                            rect {
+                                key: "{n}",
+                                class: style::bar,
+                                x: "{bar.x:.2}",
+                                y: "{bar.y:.2}",
+                                width: "{bar.width:.2}",
+                                height: "{bar.height:.2}",
+                                style: "fill: {series[bar.series].css_color(bar.series)}",
+                            }
+                        }
+                    } else {
+                        for (s, entry) in series.iter().enumerate() {
+                            g { key: "{s}", style: "color: {entry.css_color(s)}",
+                                if kind == ChartKind::Area {
+                                    path { class: style::area, d: area_path(&frame, entry) }
+                                }
+                                path { class: style::line, d: line_path(&frame, entry) }
+                            }
+                        }
+                    }
+                }
+                // One hover slot per label, laid over the drawing
+                for (i, label) in labels.iter().enumerate() {
+                    {
+                        let (slot_x, slot_w) = frame.slot(i);
+                        let flip = frame.x(i) > frame.left + frame.width / 2.0;
+                        let center = (frame.x(i) - slot_x) / slot_w * 100.0;
+                        rsx! {
+                            div {
+                                key: "{i}",
+                                class: stylance::classes!(style::slot, if kind.is_bar() { style::banded } else { "" }),
+                                style: percent_box(slot_x, frame.top, slot_w, frame.height, width, height),
+                                tabindex: "0",
+                                div { class: style::guide, style: "left: {center:.3}%" }
+                                if !kind.is_bar() {
+                                    for (s, entry) in series.iter().enumerate() {
+                                        if let Some(v) = entry.value(i) {
+                                            span {
+                                                key: "{s}",
+                                                class: style::point,
+                                                style: "left: {center:.3}%; top: {(frame.y(v) - frame.top) / frame.height * 100.0:.3}%; color: {entry.css_color(s)}",
+                                            }
+                                        }
+                                    }
+                                }
+                                div {
+                                    class: stylance::classes!(style::tooltip, if flip { style::flip } else { "" }),
+                                    role: "tooltip",
+                                    div { class: style::tooltip_label, "{label}" }
+                                    for (s, entry) in series.iter().enumerate() {
+                                        div { key: "{s}", class: style::tooltip_row,
+                                            span { class: style::swatch, style: "background: {entry.css_color(s)}" }
+                                            span { class: style::tooltip_name, "{entry.name}" }
+                                            span { class: style::tooltip_value,
+                                                {entry.value(i).map(fmt).unwrap_or_else(|| "–".to_string())}
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            if legend && !series.is_empty() {
+                figcaption { class: style::legend,
+                    for (s, entry) in series.iter().enumerate() {
+                        span { key: "{s}", class: style::legend_item,
+                            span { class: style::swatch, style: "background: {entry.css_color(s)}" }
+                            "{entry.name}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Small inline line chart without axes, e.g. for a `StatCard`
+#[component]
+pub fn Sparkline(
+    /// Values, oldest first
+    values: Vec<f64>,
+    /// Width in pixels
+    #[props(default = 96.0)]
+    width: f64,
+    /// Height in pixels
+    #[props(default = 28.0)]
+    height: f64,
+    /// CSS color (defaults to the first palette color)
+    #[props(default)]
+    color: Option<String>,
+    /// Whether to fill the area below the line
+    #[props(default = false)]
+    area: bool,
+    /// Whether to mark the last value with a dot
+    #[props(default = true)]
+    dot: bool,
+    /// Accessible description, e.g. "Revenue over the last 30 days"
+    #[props(default)]
+    label: Option<String>,
+    /// Additional CSS class
+    #[props(default)]
+    class: Option<String>,
+) -> Element {
+    // Keep the stroke and dot inside the drawing
+    let inset = 3.0;
+    let series = Series::new("", values);
+    let (lo, hi) = value_range(
+        ChartKind::Line,
+        std::slice::from_ref(&series),
+        series.values.len(),
+    );
+    let frame = Frame {
+        left: inset,
+        top: inset,
+        width: (width - inset * 2.0).max(1.0),
+        height: (height - inset * 2.0).max(1.0),
+        lo,
+        hi: if hi > lo { hi } else { lo + 1.0 },
+        count: series.values.len(),
+        banded: false,
+    };
+    let last = series
+        .values
+        .iter()
+        .rposition(|v| v.is_finite())
+        .map(|i| (frame.x(i), frame.y(series.values[i])));
+    let color = color.unwrap_or_else(|| palette_color(0));
+
+    rsx! {
+        svg {
+            class: stylance::classes!(style::sparkline, class.as_deref().unwrap_or("")),
+            width: "{width}",
+            height: "{height}",
+            view_box: "0 0 {width} {height}",
+            style: "color: {color}",
+            role: "img",
+            "aria-label": label.unwrap_or_else(|| "Trend".to_string()),
+            if area {
+                path { class: style::area, d: area_path(&frame, &series) }
+            }
+            path { class: style::line, d: line_path(&frame, &series) }
+            if dot && let Some((x, y)) = last {
+                circle { class: style::end_dot, cx: "{x:.2}", cy: "{y:.2}", r: "2.5" }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_compact_uses_suffixes() {
+        assert_eq!(format_compact(0.0), "0");
+        assert_eq!(format_compact(42.0), "42");
+        assert_eq!(format_compact(999.0), "999");
+        assert_eq!(format_compact(1_000.0), "1k");
+        assert_eq!(format_compact(1_250.0), "1.2k");
+        assert_eq!(format_compact(3_500_000.0), "3.5M");
+        assert_eq!(format_compact(2_000_000_000.0), "2B");
+        assert_eq!(format_compact(-1_500.0), "-1.5k");
+    }
+
+    #[test]
+    fn format_compact_keeps_small_fractions() {
+        assert_eq!(format_compact(0.25), "0.25");
+        assert_eq!(format_compact(1.5), "1.5");
+        assert_eq!(format_compact(0.001), "0");
+    }
+
+    #[test]
+    fn nice_ticks_cover_the_range_with_round_steps() {
+        assert_eq!(
+            nice_ticks(0.0, 100.0, 5),
+            [0.0, 20.0, 40.0, 60.0, 80.0, 100.0]
+        );
+        assert_eq!(nice_ticks(3.0, 97.0, 4), [0.0, 50.0, 100.0]);
+        assert_eq!(
+            nice_ticks(-7.0, 12.0, 4),
+            [-10.0, -5.0, 0.0, 5.0, 10.0, 15.0]
+        );
+    }
+
+    #[test]
+    fn nice_ticks_steps_are_one_two_or_five_times_a_power_of_ten() {
+        for (min, max, count) in [
+            (0.0, 1.0, 5),
+            (0.0, 7.3, 4),
+            (120.0, 4560.0, 6),
+            (-0.03, 0.08, 5),
+        ] {
+            let ticks = nice_ticks(min, max, count);
+            assert!(
+                ticks[0] <= min && *ticks.last().unwrap() >= max,
+                "{ticks:?}"
+            );
+            let step = ticks[1] - ticks[0];
+            let mantissa = step / 10f64.powf(step.log10().floor());
+            assert!(
+                [1.0, 2.0, 5.0, 10.0]
+                    .iter()
+                    .any(|m| (mantissa - m).abs() < 1e-9),
+                "{min}..{max}: step {step}"
+            );
+        }
+    }
+
+    #[test]
+    fn nice_ticks_handle_flat_ranges() {
+        assert_eq!(nice_ticks(0.0, 0.0, 4), [0.0, 0.5, 1.0]);
+        let ticks = nice_ticks(5.0, 5.0, 5);
+        assert_eq!(ticks.first(), Some(&0.0));
+        assert!(*ticks.last().unwrap() >= 5.0);
+        let ticks = nice_ticks(-3.0, -3.0, 3);
+        assert!(
+            ticks[0] <= -3.0 && *ticks.last().unwrap() == 0.0,
+            "{ticks:?}"
+        );
+    }
+}
@@ -22,6 +22,12 @@ pub fn Checkbox(
     #[props(default = false)] checked: bool,
     #[props(default)] label: String,
     #[props(default = false)] disabled: bool,
+    /// Show a dash instead of a check, for "some but not all" states
+    #[props(default = false)]
+    indeterminate: bool,
+    /// Accessible label when there is no visible label
+    #[props(default)]
+    aria_label: String,
     onchange: Option<EventHandler<FormEvent>>,
 ) -> Element {
     let size_class = match size {
@@ -35,9 +41,11 @@ pub fn Checkbox(
     rsx! {
         label { class: stylance::classes!(style::wrapper, disabled_class),
             input {
-                class: stylance::classes!(style::checkbox, size_class),
+                class: stylance::classes!(style::checkbox, size_class, if indeterminate { style::indeterminate } else { "" }),
                 r#type: "checkbox",
                 checked,
+                aria_checked: if indeterminate { "mixed" } else if checked { "true" } else { "false" },
+                aria_label: (!aria_label.is_empty()).then_some(aria_label),
                 disabled,
                 onchange: move |evt| {
                     if let Some(handler) = &onchange {
@@ -2,7 +2,9 @@
 
 use dioxus::prelude::*;
 
-use crate::{IconCheck, IconX, Spinner, SpinnerSize, Tab, TabList, TabPanel, TabPanels, Tabs};
+use crate::{
+    AnsiText, IconCheck, IconX, Spinner, SpinnerSize, Tab, TabList, TabPanel, TabPanels, Tabs,
+};
 
 stylance::import_style!(style, "code_execution_result.module.scss");
 
@@ -28,10 +30,10 @@ pub fn CodeExecutionResult(
     exit_code: Option<i32>,
     /// Duration string (e.g., "1.2s")
     duration: Option<String>,
-    /// Stdout content
+    /// Stdout content (ANSI escape sequences are rendered)
     #[props(default)]
     stdout: String,
-    /// Stderr content
+    /// Stderr content (ANSI escape sequences are rendered)
     #[props(default)]
     stderr: String,
 ) -> Element {
@@ -81,11 +83,13 @@ pub fn CodeExecutionResult(
                     }
                     TabPanels {
                         TabPanel {
-                            pre { class: style::output, "{stdout}" }
+                            pre { class: style::output, AnsiText { text: stdout } }
                         }
                         if has_stderr {
                             TabPanel {
-                                pre { class: stylance::classes!(style::output, style::stderr), "{stderr}" }
+                                pre { class: stylance::classes!(style::output, style::stderr),
+                                    AnsiText { text: stderr }
+                                }
                             }
                         }
                     }
@@ -0,0 +1,238 @@
+//! Column layout state (order, widths, visibility, pinning) for tables
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
+
+/// Edge a column is pinned to while the table scrolls horizontally
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ColumnPin {
+    /// Pinned to the left edge
+    Left,
+    /// Pinned to the right edge
+    Right,
+}
+
+impl ColumnPin {
+    /// Get the pin as a lowercase string
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Left => "left",
+            Self::Right => "right",
+        }
+    }
+}
+
+/// User-adjustable column layout of a table
+///
+/// Only what the user changed is stored, keyed by column id, so a saved layout
+/// keeps working when columns are added or removed: unknown ids are ignored
+/// and new columns appear after the ordered ones, in definition order.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ColumnLayout {
+    /// Column ids in display order
+    pub order: Vec<String>,
+    /// Column widths in pixels
+    pub widths: BTreeMap<String, f64>,
+    /// Ids of hidden columns
+    pub hidden: BTreeSet<String>,
+    /// Columns pinned to an edge
+    pub pinned: BTreeMap<String, ColumnPin>,
+}
+
+impl ColumnLayout {
+    /// Column ids in user order, ignoring pins
+    fn ordered(&self, ids: &[&str]) -> Vec<String> {
+        let mut ordered: Vec<String> = self
+            .order
+            .iter()
+            .filter(|id| ids.contains(&id.as_str()))
+            .cloned()
+            .collect();
+        for id in ids {
+            if !ordered.iter().any(|o| o == id) {
+                ordered.push((*id).to_string());
+            }
+        }
+        ordered
+    }
+
+    /// All column ids in display order: left-pinned, unpinned, right-pinned
+    ///
+    /// `ids` are the table's column ids in definition order.
+    #[must_use]
+    pub fn arrange(&self, ids: &[&str]) -> Vec<String> {
+        let ordered = self.ordered(ids);
+        let group = |pin: Option<ColumnPin>| {
+            ordered
+                .iter()
+                .filter(move |id| self.pin(id) == pin)
+                .cloned()
+        };
+        group(Some(ColumnPin::Left))
+            .chain(group(None))
+            .chain(group(Some(ColumnPin::Right)))
+            .collect()
+    }
+
+    /// Visible column ids in display order
+    #[must_use]
+    pub fn visible(&self, ids: &[&str]) -> Vec<String> {
+        let mut arranged = self.arrange(ids);
+        arranged.retain(|id| !self.is_hidden(id));
+        arranged
+    }
+
+    /// Whether a column is hidden
+    #[must_use]
+    pub fn is_hidden(&self, id: &str) -> bool {
+        self.hidden.contains(id)
+    }
+
+    /// Hide or show a column
+    pub fn set_hidden(&mut self, id: &str, hidden: bool) {
+        if hidden {
+            self.hidden.insert(id.to_string());
+        } else {
+            self.hidden.remove(id);
+        }
+    }
+
+    /// Width of a column, if the user resized it
+    #[must_use]
+    pub fn width(&self, id: &str) -> Option<f64> {
+        self.widths.get(id).copied()
+    }
+
+    /// Set the width of a column in pixels
+    pub fn set_width(&mut self, id: &str, width: f64) {
+        self.widths.insert(id.to_string(), width);
+    }
+
+    /// Edge a column is pinned to
+    #[must_use]
+    pub fn pin(&self, id: &str) -> Option<ColumnPin> {
+        self.pinned.get(id).copied()
+    }
+
+    /// Pin a column to an edge, or unpin it with `None`
+    pub fn set_pin(&mut self, id: &str, pin: Option<ColumnPin>) {
+        match pin {
+            Some(pin) => {
+                self.pinned.insert(id.to_string(), pin);
+            }
+            None => {
+                self.pinned.remove(id);
+            }
+        }
+    }
+
+    /// Move a column to the displayed position of `target`
+    ///
+    /// The column takes the target's pin, so dragging a column onto a pinned
+    /// one pins it too.
+    pub fn move_column(&mut self, ids: &[&str], id: &str, target: &str) {
+        if id == target {
+            return;
+        }
+        let mut order = self.arrange(ids);
+        let (Some(from), Some(to)) = (
+            order.iter().position(|o| o == id),
+            order.iter().position(|o| o == target),
+        ) else {
+            return;
+        };
+        let moved = order.remove(from);
+        order.insert(to, moved);
+        self.order = order;
+        self.set_pin(id, self.pin(target));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const IDS: &[&str] = &["a", "b", "c", "d"];
+
+    #[test]
+    fn default_layout_keeps_definition_order() {
+        let layout = ColumnLayout::default();
+        assert_eq!(layout.arrange(IDS), IDS);
+        assert_eq!(layout.visible(IDS), IDS);
+    }
+
+    #[test]
+    fn saved_order_survives_added_and_removed_columns() {
+        let layout = ColumnLayout {
+            order: vec!["c".into(), "gone".into(), "a".into()],
+            ..ColumnLayout::default()
+        };
+        assert_eq!(layout.arrange(IDS), ["c", "a", "b", "d"]);
+    }
+
+    #[test]
+    fn pinned_columns_go_to_their_edge() {
+        let mut layout = ColumnLayout::default();
+        layout.set_pin("c", Some(ColumnPin::Left));
+        layout.set_pin("a", Some(ColumnPin::Right));
+        assert_eq!(layout.arrange(IDS), ["c", "b", "d", "a"]);
+        layout.set_pin("a", None);
+        assert_eq!(layout.arrange(IDS), ["c", "a", "b", "d"]);
+    }
+
+    #[test]
+    fn hidden_columns_are_arranged_but_not_visible() {
+        let mut layout = ColumnLayout::default();
+        layout.set_hidden("b", true);
+        assert_eq!(layout.arrange(IDS), IDS);
+        assert_eq!(layout.visible(IDS), ["a", "c", "d"]);
+        layout.set_hidden("b", false);
+        assert_eq!(layout.visible(IDS), IDS);
+    }
+
+    #[test]
+    fn move_column_takes_the_target_position_and_pin() {
+        let mut layout = ColumnLayout::default();
+        layout.move_column(IDS, "d", "b");
+        assert_eq!(layout.arrange(IDS), ["a", "d", "b", "c"]);
+        layout.move_column(IDS, "a", "c");
+        assert_eq!(layout.arrange(IDS), ["d", "b", "c", "a"]);
+
+        layout.set_pin("d", Some(ColumnPin::Left));
+        layout.move_column(IDS, "c", "d");
+        assert_eq!(layout.pin("c"), Some(ColumnPin::Left));
+        assert_eq!(layout.arrange(IDS), ["c", "d", "b", "a"]);
+    }
+
+    #[test]
+    fn move_column_ignores_unknown_ids() {
+        let mut layout = ColumnLayout::default();
+        layout.move_column(IDS, "a", "missing");
+        layout.move_column(IDS, "b", "b");
+        assert_eq!(layout, ColumnLayout::default());
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let mut layout = ColumnLayout::default();
+        layout.move_column(IDS, "c", "a");
+        layout.set_width("b", 220.0);
+        layout.set_hidden("d", true);
+        layout.set_pin("a", Some(ColumnPin::Right));
+        let json = serde_json::to_string(&layout).unwrap();
+        assert!(json.contains(r#""a":"right""#));
+        assert_eq!(serde_json::from_str::<ColumnLayout>(&json).unwrap(), layout);
+        assert_eq!(layout.width("b"), Some(220.0));
+        assert_eq!(layout.width("a"), None);
+    }
+
+    #[test]
+    fn missing_fields_use_defaults() {
+        let layout: ColumnLayout = serde_json::from_str(r#"{"hidden":["b"]}"#).unwrap();
+        assert_eq!(layout.visible(IDS), ["a", "c", "d"]);
+    }
+}
@@ -0,0 +1,511 @@
+//! Combobox: a text input with a filterable, keyboard-navigable listbox
+
+use std::collections::HashMap;
+use std::ops::Range;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use dioxus::prelude::*;
+
+use crate::{IconCheck, IconChevronDown, IconPlus, IconX, Spinner, SpinnerSize};
+
+stylance::import_style!(style, "combobox.module.scss");
+
+/// Element key of the "Create" entry, which can't collide with an option value
+const CREATE_KEY: &str = "\0create";
+
+/// An option in a [`Combobox`] or [`MultiSelect`](crate::MultiSelect)
+#[derive(Clone, PartialEq)]
+pub struct ComboboxOption {
+    /// Value passed back through `onchange`
+    pub value: String,
+    /// Text shown in the list and in the input once selected
+    pub label: String,
+    /// Secondary text shown under the label
+    pub description: Option<String>,
+    /// Heading the option is listed under
+    pub group: Option<String>,
+    /// Shown but not selectable
+    pub disabled: bool,
+}
+
+impl ComboboxOption {
+    /// Create an option
+    pub fn new(value: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+            label: label.into(),
+            description: None,
+            group: None,
+            disabled: false,
+        }
+    }
+
+    /// Set the secondary text
+    #[must_use]
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// List the option under a group heading
+    #[must_use]
+    pub fn group(mut self, group: impl Into<String>) -> Self {
+        self.group = Some(group.into());
+        self
+    }
+
+    /// Set whether the option can be selected
+    #[must_use]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Case-insensitive match on the label or description; `query` must be lowercase
+    pub(crate) fn matches(&self, query: &str) -> bool {
+        self.label.to_lowercase().contains(query)
+            || self
+                .description
+                .as_ref()
+                .is_some_and(|d| d.to_lowercase().contains(query))
+    }
+}
+
+/// Gather options of the same group together, groups in order of first appearance
+pub(crate) fn group_options(options: Vec<ComboboxOption>) -> Vec<ComboboxOption> {
+    let mut groups: Vec<Option<String>> = Vec::new();
+    for option in &options {
+        if !groups.contains(&option.group) {
+            groups.push(option.group.clone());
+        }
+    }
+    groups
+        .iter()
+        .flat_map(|group| options.iter().filter(move |o| &o.group == group).cloned())
+        .collect()
+}
+
+/// Index ranges of consecutive options sharing a group
+pub(crate) fn group_runs(options: &[ComboboxOption]) -> Vec<(Option<String>, Range<usize>)> {
+    let mut runs: Vec<(Option<String>, Range<usize>)> = Vec::new();
+    for (index, option) in options.iter().enumerate() {
+        match runs.last_mut() {
+            Some((group, range)) if *group == option.group => range.end = index + 1,
+            _ => runs.push((option.group.clone(), index..index + 1)),
+        }
+    }
+    runs
+}
+
+/// Next selectable index from `from`, moving forward or backward and
+/// stopping at the ends
+pub(crate) fn step_active(enabled: &[bool], from: Option<usize>, forward: bool) -> Option<usize> {
+    let found = if forward {
+        let start = from.map_or(0, |i| i + 1);
+        (start..enabled.len()).find(|&i| enabled[i])
+    } else {
+        let end = from.unwrap_or(enabled.len());
+        (0..end).rev().find(|&i| enabled[i])
+    };
+    found.or(from)
+}
+
+/// Searchable single-value select.
+///
+/// Typing filters `options` by label and description. To load options from a
+/// server instead, handle `onsearch`: it receives the query once the user has
+/// stopped typing for `debounce` ms, and `options` are then shown as given.
+/// Set `loading` while a request is in flight. With `oncreate`, a "Create"
+/// entry is offered for queries that match no option exactly.
+#[component]
+pub fn Combobox(
+    /// Options to choose from
+    options: Vec<ComboboxOption>,
+    /// Value of the selected option, empty for none
+    #[props(default)]
+    value: String,
+    #[props(default)] label: String,
+    #[props(default = "Search...".to_string())] placeholder: String,
+    #[props(default)] error: String,
+    #[props(default = false)] disabled: bool,
+    #[props(default = false)] required: bool,
+    /// Show the loading state instead of options
+    #[props(default = false)]
+    loading: bool,
+    /// Shown when no option matches
+    #[props(default = "No results".to_string())]
+    empty_message: String,
+    /// Called with the chosen option, or `None` when the selection is cleared
+    onchange: Option<EventHandler<Option<ComboboxOption>>>,
+    /// Called with the query after typing pauses; disables local filtering
+    onsearch: Option<EventHandler<String>>,
+    /// Debounce delay for `onsearch`, in milliseconds
+    #[props(default = 250)]
+    debounce: u32,
+    /// Called with the query when the "Create" entry is chosen
+    oncreate: Option<EventHandler<String>>,
+    /// Called when the input loses focus
+    onblur: Option<EventHandler<FocusEvent>>,
+    /// Called with the input element once mounted, e.g. to focus it
+    onmounted: Option<EventHandler<MountedEvent>>,
+) -> Element {
+    static COUNTER: AtomicU32 = AtomicU32::new(0);
+    let id = use_hook(|| format!("combobox-{}", COUNTER.fetch_add(1, Ordering::Relaxed)));
+
+    let mut open = use_signal(|| false);
+    // Whether the user has typed since the list opened; until then all options show
+    let mut editing = use_signal(|| false);
+    let mut query = use_signal(String::new);
+    // Key of the highlighted option, so it stays put when `options` is replaced
+    let mut active = use_signal(|| None::<String>);
+    // Last chosen option, so its label still shows if async results move on
+    let mut chosen = use_signal(|| None::<ComboboxOption>);
+    let mut search_generation = use_signal(|| 0_u64);
+    let mut option_elements = use_signal(HashMap::<String, Rc<MountedData>>::new);
+
+    let selected = options
+        .iter()
+        .find(|o| o.value == value)
+        .cloned()
+        .or_else(|| chosen().filter(|o| o.value == value));
+    let selected_label = match &selected {
+        Some(option) => option.label.clone(),
+        None => value.clone(),
+    };
+
+    let trimmed = query().trim().to_lowercase();
+    let filtered: Vec<ComboboxOption> = if onsearch.is_none() && editing() && !trimmed.is_empty() {
+        options
+            .iter()
+            .filter(|o| o.matches(&trimmed))
+            .cloned()
+            .collect()
+    } else {
+        options.clone()
+    };
+    let create = (oncreate.is_some()
+        && editing()
+        && !trimmed.is_empty()
+        && !options.iter().any(|o| o.label.to_lowercase() == trimmed))
+    .then(|| query().trim().to_string());
+    let shown = if loading {
+        Vec::new()
+    } else {
+        group_options(filtered)
+    };
+    let create = create.filter(|_| !loading);
+    let keys: Vec<String> = shown
+        .iter()
+        .map(|o| o.value.clone())
+        .chain(create.iter().map(|_| CREATE_KEY.to_string()))
+        .collect();
+    let enabled: Vec<bool> = shown
+        .iter()
+        .map(|o| !o.disabled)
+        .chain(create.iter().map(|_| true))
+        .collect();
+    let active_index = active().and_then(|key| keys.iter().position(|k| *k == key));
+    let is_open = open() && !disabled;
+
+    let listbox_id = format!("{id}-listbox");
+    let option_id = {
+        let id = id.clone();
+        move |index: usize| format!("{id}-option-{index}")
+    };
+    let active_descendant = match active_index {
+        Some(index) if is_open => option_id(index),
+        _ => String::new(),
+    };
+
+    let mut close = move || {
+        open.set(false);
+        editing.set(false);
+        query.set(String::new());
+        active.set(None);
+    };
+
+    let mut choose = move |option: ComboboxOption| {
+        close();
+        chosen.set(Some(option.clone()));
+        if let Some(handler) = &onchange {
+            handler.call(Some(option));
+        }
+    };
+
+    let mut create_option = move |text: String| {
+        close();
+        if let Some(handler) = &oncreate {
+            handler.call(text);
+        }
+    };
+
+    let mut set_active = move |key: Option<String>| {
+        active.set(key.clone());
+        let element = key.and_then(|key| option_elements.peek().get(&key).cloned());
+        if let Some(element) = element {
+            spawn(async move {
+                let _ = element
+                    .scroll_to_with_options(ScrollToOptions {
+                        behavior: ScrollBehavior::Instant,
+                        vertical: ScrollLogicalPosition::Nearest,
+                        horizontal: ScrollLogicalPosition::Nearest,
+                    })
+                    .await;
+            });
+        }
+    };
+
+    let mut search = move |text: String| {
+        let Some(handler) = onsearch else {
+            return;
+        };
+        *search_generation.write() += 1;
+        let generation = *search_generation.peek();
+        spawn(async move {
+            #[cfg(target_arch = "wasm32")]
+            gloo_timers::future::TimeoutFuture::new(debounce).await;
+            #[cfg(not(target_arch = "wasm32"))]
+            let _ = debounce;
+            // A later keystroke has scheduled its own search
+            if *search_generation.peek() == generation {
+                handler.call(text);
+            }
+        });
+    };
+
+    let keydown_shown = shown.clone();
+    let keydown_create = create.clone();
+    let keydown_keys = keys;
+    let keydown_enabled = enabled;
+    let handle_keydown = move |evt: KeyboardEvent| {
+        let key_at = |index: Option<usize>| index.and_then(|i| keydown_keys.get(i).cloned());
+        match evt.key() {
+            Key::ArrowDown => {
+                evt.prevent_default();
+                if !is_open {
+                    open.set(true);
+                    if evt.modifiers().alt() {
+                        return;
+                    }
+                }
+                let next = step_active(&keydown_enabled, active_index, true);
+                set_active(key_at(next));
+            }
+            Key::ArrowUp => {
+                evt.prevent_default();
+                if !is_open {
+                    open.set(true);
+                }
+                let next = step_active(&keydown_enabled, active_index, false);
+                set_active(key_at(next));
+            }
+            Key::Home if is_open => {
+                evt.prevent_default();
+                let next = step_active(&keydown_enabled, None, true);
+                set_active(key_at(next));
+            }
+            Key::End if is_open => {
+                evt.prevent_default();
+                let next = step_active(&keydown_enabled, None, false);
+                set_active(key_at(next));
+            }
+            Key::Enter if is_open => {
+                // Keep Enter from submitting a surrounding form while choosing
+                evt.prevent_default();
+                match active_index {
+                    Some(index) if index < keydown_shown.len() && keydown_enabled[index] => {
+                        choose(keydown_shown[index].clone())
+                    }
+                    Some(index) if index == keydown_shown.len() => {
+                        if let Some(text) = keydown_create.clone() {
+                            create_option(text);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Key::Escape if is_open => {
+                evt.prevent_default();
+                close();
+            }
+            _ => {}
+        }
+    };
+
+    let create_index = shown.len();
+    let input_value = if editing() { query() } else { selected_label };
+    let has_value = !value.is_empty();
+    let error_class = if !error.is_empty() { style::error } else { "" };
+
+    rsx! {
+        div { class: style::wrapper,
+            if !label.is_empty() {
+                label { class: style::label, r#for: "{id}",
+                    "{label}"
+                    if required {
+                        span { class: style::required, " *" }
+                    }
+                }
+            }
+            div { class: stylance::classes!(style::control, error_class, if is_open { style::open } else { "" }),
+                input {
+                    id: "{id}",
+                    class: style::input,
+                    r#type: "text",
+                    role: "combobox",
+                    autocomplete: "off",
+                    aria_autocomplete: "list",
+                    aria_expanded: "{is_open}",
+                    aria_controls: "{listbox_id}",
+                    aria_activedescendant: "{active_descendant}",
+                    aria_invalid: if !error.is_empty() { "true" } else { "false" },
+                    placeholder,
+                    value: "{input_value}",
+                    disabled,
+                    required,
+                    oninput: move |evt| {
+                        let text = evt.value();
+                        query.set(text.clone());
+                        editing.set(true);
+                        open.set(true);
+                        active.set(None);
+                        search(text);
+                    },
+                    onclick: move |_| {
+                        if !disabled {
+                            open.set(true);
+                        }
+                    },
+                    onkeydown: handle_keydown,
+                    onblur: move |evt| {
+                        close();
+                        if let Some(handler) = &onblur {
+                            handler.call(evt);
+                        }
+                    },
+                    onmounted: move |evt| {
+                        if let Some(handler) = &onmounted {
+                            handler.call(evt);
+                        }
+                    },
+                }
+                if has_value && !disabled {
+                    button {
+                        r#type: "button",
+                        class: style::icon_button,
+                        tabindex: "-1",
+                        aria_label: "Clear selection",
+                        onmousedown: move |evt| evt.prevent_default(),
+                        onclick: move |_| {
+                            close();
+                            chosen.set(None);
+                            if let Some(handler) = &onchange {
+                                handler.call(None);
+                            }
+                        },
+                        IconX {}
+                    }
+                }
+                span { class: style::chevron, aria_hidden: "true", IconChevronDown {} }
+            }
+            if is_open {
+                div {
+                    id: "{listbox_id}",
+                    class: style::listbox,
+                    role: "listbox",
+                    aria_label: if label.is_empty() { placeholder.clone() } else { label.clone() },
+                    // Keep focus in the input while clicking options
+                    onmousedown: move |evt| evt.prevent_default(),
+                    if loading {
+                        div { class: style::status, role: "status",
+                            Spinner { size: SpinnerSize::Small }
+                            "Loading..."
+                        }
+                    } else if shown.is_empty() && create.is_none() {
+                        div { class: style::status, role: "status", "{empty_message}" }
+                    }
+                    for (group, range) in group_runs(&shown) {
+                        div {
+                            key: "{group:?}",
+                            class: style::group,
+                            role: if group.is_some() { "group" } else { "presentation" },
+                            aria_label: group.clone(),
+                            if let Some(name) = &group {
+                                div { class: style::group_label, aria_hidden: "true", "{name}" }
+                            }
+                            for (index, option) in range.clone().zip(shown[range].iter().cloned()) {
+                                div {
+                                    key: "{option.value}",
+                                    id: option_id(index),
+                                    class: stylance::classes!(
+                                        style::option,
+                                        if active_index == Some(index) { style::active } else { "" },
+                                        if option.disabled { style::disabled } else { "" }
+                                    ),
+                                    role: "option",
+                                    aria_selected: "{option.value == value}",
+                                    aria_disabled: "{option.disabled}",
+                                    onmounted: {
+                                        let key = option.value.clone();
+                                        move |evt: MountedEvent| {
+                                            option_elements.write().insert(key.clone(), evt.data());
+                                        }
+                                    },
+                                    onmouseenter: {
+                                        let key = option.value.clone();
+                                        move |_| {
+                                            if !option.disabled {
+                                                active.set(Some(key.clone()));
+                                            }
+                                        }
+                                    },
+                                    onclick: {
+                                        let option = option.clone();
+                                        move |_| {
+                                            if !option.disabled {
+                                                choose(option.clone());
+                                            }
+                                        }
+                                    },
+                                    span { class: style::option_text,
+                                        span { class: style::option_label, "{option.label}" }
+                                        if let Some(description) = &option.description {
+                                            span { class: style::option_description, "{description}" }
+                                        }
+                                    }
+                                    if option.value == value {
+                                        span { class: style::check, IconCheck {} }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    if let Some(text) = create.clone() {
+                        div {
+                            key: "{CREATE_KEY}",
+                            id: option_id(create_index),
+                            class: stylance::classes!(
+                                style::option,
+                                style::create,
+                                if active_index == Some(create_index) { style::active } else { "" }
+                            ),
+                            role: "option",
+                            aria_selected: "false",
+                            onmounted: move |evt: MountedEvent| {
+                                option_elements.write().insert(CREATE_KEY.to_string(), evt.data());
+                            },
+                            onmouseenter: move |_| active.set(Some(CREATE_KEY.to_string())),
+                            onclick: move |_| create_option(text.clone()),
+                            IconPlus {}
+                            span { "Create \"{text}\"" }
+                        }
+                    }
+                }
+            }
+            if !error.is_empty() {
+                span { class: style::error_text, "{error}" }
+            }
+        }
+    }
+}
@@ -2,6 +2,9 @@
 
 use dioxus::prelude::*;
 
+#[cfg(target_arch = "wasm32")]
+use crate::utils::write_clipboard;
+
 stylance::import_style!(style, "copy_input.module.scss");
 
 /// Size variants for the CopyInput
@@ -59,16 +62,13 @@ pub fn CopyInput(
                         move |_| {
                             #[cfg(target_arch = "wasm32")]
                             {
-                                if let Some(window) = web_sys::window() {
-                                    let clipboard = window.navigator().clipboard();
-                                    let _ = clipboard.write_text(&copy_value);
-                                    copied.set(true);
+                                write_clipboard(&copy_value);
+                                copied.set(true);
 
-                                    spawn(async move {
-                                        gloo_timers::future::TimeoutFuture::new(2_000).await;
-                                        copied.set(false);
-                                    });
-                                }
+                                spawn(async move {
+                                    gloo_timers::future::TimeoutFuture::new(2_000).await;
+                                    copied.set(false);
+                                });
                             }
                         }
                     },
@@ -0,0 +1,1174 @@
+//! `DataTable` component: a `Table` that manages sorting and filtering
+
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use crate::{
+    Button, ButtonSize, ButtonVariant, Checkbox, CheckboxSize, ColumnLayout, ColumnPin,
+    IconArrowLeftToLine, IconArrowRightToLine, IconButton, IconButtonSize, IconColumns3, Input,
+    InputSize, Popover, PopoverContent, PopoverPosition, Table, TableBody, TableCell, TableData,
+    TableEmpty, TableExport, TableHeader, TableHeaderCell, TableRow, TableSize, TableVariant,
+};
+
+stylance::import_style!(style, "data_table.module.scss");
+
+/// Direction of a column sort
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    /// Smallest first
+    Ascending,
+    /// Largest first
+    Descending,
+}
+
+impl SortDirection {
+    /// Get the direction as the string used by `TableHeaderCell`
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ascending => "asc",
+            Self::Descending => "desc",
+        }
+    }
+}
+
+/// Sort applied to one column
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnSort {
+    /// Id of the sorted column
+    pub column: String,
+    /// Sort direction
+    pub direction: SortDirection,
+}
+
+impl ColumnSort {
+    /// Sort a column in ascending order
+    pub fn ascending(column: impl Into<String>) -> Self {
+        Self {
+            column: column.into(),
+            direction: SortDirection::Ascending,
+        }
+    }
+
+    /// Sort a column in descending order
+    pub fn descending(column: impl Into<String>) -> Self {
+        Self {
+            column: column.into(),
+            direction: SortDirection::Descending,
+        }
+    }
+}
+
+/// Compare two cell texts, numerically when both parse as numbers
+fn compare_text(a: &str, b: &str) -> Ordering {
+    match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.total_cmp(&b),
+        _ => a.to_lowercase().cmp(&b.to_lowercase()),
+    }
+}
+
+/// Width of columns that don't set one, in pixels
+const DEFAULT_COLUMN_WIDTH: f64 = 160.0;
+
+/// Narrowest a column can be resized to, in pixels
+const MIN_COLUMN_WIDTH: f64 = 48.0;
+
+/// Width of the selection checkbox column, in pixels
+const SELECT_COLUMN_WIDTH: f64 = 40.0;
+
+type Accessor<T> = Rc<dyn Fn(&T) -> String>;
+type CellRenderer<T> = Rc<dyn Fn(&T) -> Element>;
+type Comparator<T> = Rc<dyn Fn(&T, &T) -> Ordering>;
+type FilterPredicate<T> = Rc<dyn Fn(&T, &str) -> bool>;
+type Aggregator<T> = Rc<dyn Fn(&[&T]) -> String>;
+
+/// Definition of a `DataTable` column
+///
+/// The accessor turns a row into the column's text, which is what gets displayed
+/// unless a cell renderer is set, and what default sorting and filtering use.
+///
+/// Columns compare by id, header, alignment, width and which closures are set,
+/// never by the closures themselves. A column whose closures capture changing
+/// state should change its id (or header) when that state changes.
+pub struct Column<T> {
+    id: String,
+    header: String,
+    align: String,
+    width: f64,
+    accessor: Accessor<T>,
+    cell: Option<CellRenderer<T>>,
+    compare: Option<Comparator<T>>,
+    filter: Option<FilterPredicate<T>>,
+    aggregate: Option<Aggregator<T>>,
+}
+
+impl<T: 'static> Column<T> {
+    /// Create a column from an id, a header label and a text accessor
+    pub fn new(
+        id: impl Into<String>,
+        header: impl Into<String>,
+        accessor: impl Fn(&T) -> String + 'static,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            header: header.into(),
+            align: "left".to_string(),
+            width: DEFAULT_COLUMN_WIDTH,
+            accessor: Rc::new(accessor),
+            cell: None,
+            compare: None,
+            filter: None,
+            aggregate: None,
+        }
+    }
+
+    /// Render cells with custom content instead of the accessor text
+    #[must_use]
+    pub fn cell(mut self, render: impl Fn(&T) -> Element + 'static) -> Self {
+        self.cell = Some(Rc::new(render));
+        self
+    }
+
+    /// Set the text alignment of the header and cells
+    #[must_use]
+    pub fn align(mut self, align: impl Into<String>) -> Self {
+        self.align = align.into();
+        self
+    }
+
+    /// Set the initial width in pixels, used once columns are resized or pinned
+    #[must_use]
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Make the column sortable by its text (numbers compare numerically)
+    #[must_use]
+    pub fn sortable(mut self) -> Self {
+        let accessor = self.accessor.clone();
+        self.compare = Some(Rc::new(move |a, b| {
+            compare_text(&accessor(a), &accessor(b))
+        }));
+        self
+    }
+
+    /// Make the column sortable with a custom comparator
+    #[must_use]
+    pub fn sort_by(mut self, compare: impl Fn(&T, &T) -> Ordering + 'static) -> Self {
+        self.compare = Some(Rc::new(compare));
+        self
+    }
+
+    /// Make the column sortable by a key extracted from each row
+    #[must_use]
+    pub fn sort_by_key<K: Ord>(self, key: impl Fn(&T) -> K + 'static) -> Self {
+        self.sort_by(move |a, b| key(a).cmp(&key(b)))
+    }
+
+    /// Summarize child rows on parent rows of a `TreeTable` (sums, counts, ranges)
+    ///
+    /// The function receives the loaded leaf rows under the parent.
+    #[must_use]
+    pub fn aggregate(mut self, aggregate: impl Fn(&[&T]) -> String + 'static) -> Self {
+        self.aggregate = Some(Rc::new(aggregate));
+        self
+    }
+
+    /// Add a filter input matching the column text (case-insensitive)
+    #[must_use]
+    pub fn filterable(mut self) -> Self {
+        let accessor = self.accessor.clone();
+        self.filter = Some(Rc::new(move |row, query| {
+            accessor(row).to_lowercase().contains(&query.to_lowercase())
+        }));
+        self
+    }
+
+    /// Add a filter input with a custom predicate, given the row and the input text
+    #[must_use]
+    pub fn filter_by(mut self, predicate: impl Fn(&T, &str) -> bool + 'static) -> Self {
+        self.filter = Some(Rc::new(predicate));
+        self
+    }
+
+    /// Column id
+    #[must_use]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Header label
+    #[must_use]
+    pub fn header(&self) -> &str {
+        &self.header
+    }
+
+    /// Text of this column for a row
+    #[must_use]
+    pub fn text(&self, row: &T) -> String {
+        (self.accessor)(row)
+    }
+
+    /// Whether the column can be sorted
+    #[must_use]
+    pub fn is_sortable(&self) -> bool {
+        self.compare.is_some()
+    }
+
+    /// Whether the column has a filter input
+    #[must_use]
+    pub fn is_filterable(&self) -> bool {
+        self.filter.is_some()
+    }
+
+    /// Text alignment of the column
+    pub(crate) fn alignment(&self) -> &str {
+        &self.align
+    }
+
+    /// Aggregate value for a parent row, if the column aggregates
+    pub(crate) fn aggregate_text(&self, leaves: &[&T]) -> Option<String> {
+        self.aggregate.as_ref().map(|aggregate| aggregate(leaves))
+    }
+
+    pub(crate) fn render(&self, row: &T) -> Element {
+        match &self.cell {
+            Some(render) => render(row),
+            None => {
+                let text = (self.accessor)(row);
+                rsx! { "{text}" }
+            }
+        }
+    }
+}
+
+impl<T> Clone for Column<T> {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id.clone(),
+            header: self.header.clone(),
+            align: self.align.clone(),
+            width: self.width,
+            accessor: self.accessor.clone(),
+            cell: self.cell.clone(),
+            compare: self.compare.clone(),
+            filter: self.filter.clone(),
+            aggregate: self.aggregate.clone(),
+        }
+    }
+}
+
+// Columns are usually built inline in `rsx!`, so comparing closures by pointer
+// would make every render look like a change and rerun the sort and filter memos
+impl<T> PartialEq for Column<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.header == other.header
+            && self.align == other.align
+            && self.width == other.width
+            && self.cell.is_some() == other.cell.is_some()
+            && self.compare.is_some() == other.compare.is_some()
+            && self.filter.is_some() == other.filter.is_some()
+            && self.aggregate.is_some() == other.aggregate.is_some()
+    }
+}
+
+/// Indices of the rows that pass every filter, in sort order
+///
+/// Sorting is stable, so rows that compare equal keep their original order.
+pub fn visible_rows<T>(
+    rows: &[T],
+    columns: &[Column<T>],
+    sort: &[ColumnSort],
+    filters: &HashMap<String, String>,
+) -> Vec<usize> {
+    let active_filters: Vec<_> = columns
+        .iter()
+        .filter_map(|col| {
+            let query = filters.get(&col.id)?.trim();
+            let predicate = col.filter.as_ref()?;
+            (!query.is_empty()).then_some((predicate, query))
+        })
+        .collect();
+    let mut indices: Vec<usize> = (0..rows.len())
+        .filter(|&i| {
+            active_filters
+                .iter()
+                .all(|(predicate, query)| predicate(&rows[i], query))
+        })
+        .collect();
+
+    let comparators: Vec<_> = sort
+        .iter()
+        .filter_map(|s| {
+            let col = columns.iter().find(|col| col.id == s.column)?;
+            Some((col.compare.as_ref()?, s.direction))
+        })
+        .collect();
+    if !comparators.is_empty() {
+        indices.sort_by(|&a, &b| {
+            comparators
+                .iter()
+                .map(|(compare, direction)| {
+                    let ordering = compare(&rows[a], &rows[b]);
+                    match direction {
+                        SortDirection::Ascending => ordering,
+                        SortDirection::Descending => ordering.reverse(),
+                    }
+                })
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or(Ordering::Equal)
+        });
+    }
+    indices
+}
+
+/// Update a sort after a header click
+///
+/// A plain click sorts by that column alone, cycling ascending, descending and
+/// unsorted. With `additive` (shift-click) the column is added to, toggled in
+/// or removed from the existing sort instead.
+fn toggle_sort(sort: &mut Vec<ColumnSort>, column: &str, additive: bool) {
+    let position = sort.iter().position(|s| s.column == column);
+    let current = position.map(|i| sort[i].direction);
+    if !additive {
+        let keep = current.is_some() && sort.len() > 1;
+        sort.clear();
+        match current {
+            // Collapsing a multi-column sort onto this column keeps its direction
+            Some(direction) if keep => sort.push(ColumnSort {
+                column: column.to_string(),
+                direction,
+            }),
+            None => sort.push(ColumnSort::ascending(column)),
+            Some(SortDirection::Ascending) => sort.push(ColumnSort::descending(column)),
+            Some(SortDirection::Descending) => {}
+        }
+        return;
+    }
+    match (position, current) {
+        (None, _) => sort.push(ColumnSort::ascending(column)),
+        (Some(i), Some(SortDirection::Ascending)) => sort[i].direction = SortDirection::Descending,
+        (Some(i), _) => {
+            sort.remove(i);
+        }
+    }
+}
+
+/// Select or deselect the row `key`
+///
+/// With `extend`, every row between the anchor (the previously toggled row)
+/// and `key` gets the same state, as with shift-click in a file manager.
+/// `keys` are the keys of the displayed rows, in display order.
+fn apply_selection(
+    selection: &mut HashSet<String>,
+    keys: &[String],
+    anchor: Option<&str>,
+    key: &str,
+    select: bool,
+    extend: bool,
+) {
+    let range = extend
+        .then(|| {
+            let from = keys.iter().position(|k| Some(k.as_str()) == anchor)?;
+            let to = keys.iter().position(|k| k == key)?;
+            Some(from.min(to)..=from.max(to))
+        })
+        .flatten();
+    let single = [key.to_string()];
+    let targets = match range {
+        Some(range) => &keys[range],
+        None => &single[..],
+    };
+    for target in targets {
+        if select {
+            selection.insert(target.clone());
+        } else {
+            selection.remove(target);
+        }
+    }
+}
+
+/// Text of the displayed columns for the displayed rows, for export
+fn table_data<T: 'static>(rows: &[T], columns: &[&Column<T>], indices: &[usize]) -> TableData {
+    TableData {
+        headers: columns.iter().map(|col| col.header.clone()).collect(),
+        alignments: columns.iter().map(|col| col.align.clone()).collect(),
+        rows: indices
+            .iter()
+            .map(|&i| columns.iter().map(|col| col.text(&rows[i])).collect())
+            .collect(),
+    }
+}
+
+/// Column being resized, with where the drag started
+#[derive(Clone, PartialEq)]
+struct Resize {
+    column: String,
+    start_x: f64,
+    start_width: f64,
+}
+
+/// Width and pinning of a displayed column
+struct Placement {
+    width: Option<f64>,
+    pin: Option<ColumnPin>,
+    offset: f64,
+}
+
+/// Widths and pin offsets of the displayed columns
+///
+/// Widths are only set when `sized`, since fixed widths turn off the browser's
+/// automatic column sizing.
+fn place_columns<T>(
+    columns: &[&Column<T>],
+    layout: &ColumnLayout,
+    sized: bool,
+    left_start: f64,
+) -> Vec<Placement> {
+    let widths: Vec<f64> = columns
+        .iter()
+        .map(|col| layout.width(&col.id).unwrap_or(col.width))
+        .collect();
+    let mut placements: Vec<Placement> = columns
+        .iter()
+        .zip(&widths)
+        .map(|(col, &width)| Placement {
+            width: sized.then_some(width),
+            pin: layout.pin(&col.id),
+            offset: 0.0,
+        })
+        .collect();
+    let mut left = left_start;
+    for (placement, width) in placements.iter_mut().zip(&widths) {
+        if placement.pin == Some(ColumnPin::Left) {
+            placement.offset = left;
+            left += width;
+        }
+    }
+    let mut right = 0.0;
+    for (placement, width) in placements.iter_mut().zip(&widths).rev() {
+        if placement.pin == Some(ColumnPin::Right) {
+            placement.offset = right;
+            right += width;
+        }
+    }
+    placements
+}
+
+/// Table that sorts and filters its rows from column definitions
+///
+/// Click a sortable header to sort by that column; shift-click to sort by
+/// several columns, in the order they were clicked. Filterable columns get an
+/// input under their header and rows must match every filter.
+///
+/// Columns can also be resized, reordered by dragging their header, hidden
+/// and pinned from the column menu. These changes make up a [`ColumnLayout`],
+/// reported through `on_layout_change` so it can be saved and passed back as
+/// `default_layout`.
+#[component]
+pub fn DataTable<T: Clone + PartialEq + 'static>(
+    /// Rows to display
+    rows: ReadSignal<Vec<T>>,
+    /// Column definitions
+    columns: Vec<Column<T>>,
+    /// Initial sort
+    #[props(default)]
+    default_sort: Vec<ColumnSort>,
+    /// Called when the sort changes
+    on_sort_change: Option<EventHandler<Vec<ColumnSort>>>,
+    /// Initial column layout
+    #[props(default)]
+    default_layout: ColumnLayout,
+    /// Called when the column layout changes
+    on_layout_change: Option<EventHandler<ColumnLayout>>,
+    /// Whether columns can be resized by dragging the edge of their header
+    #[props(default = false)]
+    resizable: bool,
+    /// Whether columns can be reordered by dragging their header
+    #[props(default = false)]
+    reorderable: bool,
+    /// Show a menu to hide, show and pin columns
+    #[props(default = false)]
+    column_menu: bool,
+    /// Show a menu to copy or download the displayed rows as CSV, TSV or Markdown
+    #[props(default = false)]
+    exportable: bool,
+    /// File name for downloads, without extension
+    #[props(default = "table".to_string())]
+    export_filename: String,
+    /// Add a checkbox column to select rows
+    #[props(default = false)]
+    selectable: bool,
+    /// Stable key of a row, so the selection survives sorting and row updates
+    /// (defaults to the row's index)
+    row_key: Option<Callback<T, String>>,
+    /// Called with the selected rows when the selection changes
+    on_selection_change: Option<EventHandler<Vec<T>>>,
+    /// Actions shown in the toolbar while rows are selected, given the selected rows
+    bulk_actions: Option<Callback<Vec<T>, Element>>,
+    /// Called when a row is clicked
+    on_row_click: Option<EventHandler<T>>,
+    /// Size variant
+    #[props(default)]
+    size: TableSize,
+    /// Style variant
+    #[props(default)]
+    variant: TableVariant,
+    /// Whether rows are hoverable
+    #[props(default = true)]
+    hoverable: bool,
+    /// Whether the table has a sticky header
+    #[props(default = false)]
+    sticky_header: bool,
+    /// Message shown when no rows match the filters
+    #[props(default = "No matching rows".to_string())]
+    empty_message: String,
+) -> Element {
+    let mut sort = use_signal(|| default_sort);
+    let mut filters: Signal<HashMap<String, String>> = use_signal(HashMap::new);
+    let mut layout = use_signal(|| default_layout);
+    let mut resizing: Signal<Option<Resize>> = use_signal(|| None);
+    let mut dragging: Signal<Option<String>> = use_signal(|| None);
+    let mut drop_target: Signal<Option<String>> = use_signal(|| None);
+    let mut selection: Signal<HashSet<String>> = use_signal(HashSet::new);
+    let mut anchor: Signal<Option<String>> = use_signal(|| None);
+    let mut cursor: Signal<Option<usize>> = use_signal(|| None);
+    let mut shift_held = use_signal(|| false);
+
+    let visible = use_memo(use_reactive((&columns,), move |(columns,)| {
+        visible_rows(&rows.read(), &columns, &sort.read(), &filters.read())
+    }));
+
+    let key_of = move |index: usize| match row_key {
+        Some(row_key) => row_key.call(rows.peek()[index].clone()),
+        None => index.to_string(),
+    };
+    let visible_keys =
+        move || -> Vec<String> { visible.peek().iter().map(|&i| key_of(i)).collect() };
+    let selected_rows = move || -> Vec<T> {
+        let selection = selection.peek();
+        (0..rows.peek().len())
+            .filter(|&i| selection.contains(&key_of(i)))
+            .map(|i| rows.peek()[i].clone())
+            .collect()
+    };
+    let notify_selection = move || {
+        if let Some(handler) = &on_selection_change {
+            handler.call(selected_rows());
+        }
+    };
+    // Toggle the displayed row at `position`, extending from the anchor with shift
+    let mut toggle_row = move |position: usize, extend: bool| {
+        let keys = visible_keys();
+        let Some(key) = keys.get(position).cloned() else {
+            return;
+        };
+        let select = !selection.peek().contains(&key);
+        let anchor_key = anchor.peek().clone();
+        apply_selection(
+            &mut selection.write(),
+            &keys,
+            anchor_key.as_deref(),
+            &key,
+            select,
+            extend,
+        );
+        anchor.set(Some(key));
+        cursor.set(Some(position));
+        notify_selection();
+    };
+    let mut set_all = move |select: bool| {
+        let keys = visible_keys();
+        let mut selection = selection.write();
+        for key in keys {
+            if select {
+                selection.insert(key);
+            } else {
+                selection.remove(&key);
+            }
+        }
+        drop(selection);
+        notify_selection();
+    };
+
+    let handle_keydown = move |evt: KeyboardEvent| {
+        let count = visible.peek().len();
+        if !selectable || count == 0 {
+            return;
+        }
+        let modifiers = evt.modifiers();
+        let current = *cursor.peek();
+        let step =
+            |delta: isize| current.map_or(0, |c| c.saturating_add_signed(delta).min(count - 1));
+        match evt.key() {
+            Key::ArrowDown | Key::ArrowUp => {
+                evt.prevent_default();
+                let next = step(if evt.key() == Key::ArrowDown { 1 } else { -1 });
+                cursor.set(Some(next));
+                if modifiers.shift() {
+                    let keys = visible_keys();
+                    // Keep the anchor so the range grows and shrinks from it
+                    let anchor_key = anchor
+                        .peek()
+                        .clone()
+                        .or_else(|| current.map(|c| keys[c].clone()));
+                    let mut selection = selection.write();
+                    apply_selection(
+                        &mut selection,
+                        &keys,
+                        anchor_key.as_deref(),
+                        &keys[next],
+                        true,
+                        true,
+                    );
+                    drop(selection);
+                    if anchor.peek().is_none() {
+                        anchor.set(anchor_key);
+                    }
+                    notify_selection();
+                } else {
+                    anchor.set(Some(visible_keys()[next].clone()));
+                }
+            }
+            Key::Character(c) if c == " " => {
+                evt.prevent_default();
+                toggle_row(current.unwrap_or(0), modifiers.shift());
+            }
+            Key::Character(c)
+                if c.eq_ignore_ascii_case("a") && (modifiers.ctrl() || modifiers.meta()) =>
+            {
+                evt.prevent_default();
+                set_all(true);
+            }
+            Key::Escape => {
+                selection.write().clear();
+                notify_selection();
+            }
+            _ => {}
+        }
+    };
+
+    let export_data = use_callback({
+        let columns = columns.clone();
+        move |()| {
+            let ids: Vec<&str> = columns.iter().map(|col| col.id.as_str()).collect();
+            let shown: Vec<&Column<T>> = layout
+                .peek()
+                .visible(&ids)
+                .iter()
+                .filter_map(|id| columns.iter().find(|col| &col.id == id))
+                .collect();
+            table_data(&rows.peek(), &shown, &visible.peek())
+        }
+    });
+
+    let notify_layout = move || {
+        if let Some(handler) = &on_layout_change {
+            handler.call(layout.peek().clone());
+        }
+    };
+
+    let ids: Vec<&str> = columns.iter().map(|col| col.id.as_str()).collect();
+    let layout_read = layout.read();
+    let shown: Vec<&Column<T>> = layout_read
+        .visible(&ids)
+        .iter()
+        .filter_map(|id| columns.iter().find(|col| &col.id == id))
+        .collect();
+    let sized = resizable || !layout_read.pinned.is_empty();
+    let pin_selection = selectable
+        && layout_read
+            .pinned
+            .values()
+            .any(|&pin| pin == ColumnPin::Left);
+    let left_start = if pin_selection {
+        SELECT_COLUMN_WIDTH
+    } else {
+        0.0
+    };
+    let placements = place_columns(&shown, &layout_read, sized, left_start);
+    let menu_columns: Vec<(String, String, bool, Option<ColumnPin>)> = layout_read
+        .arrange(&ids)
+        .into_iter()
+        .filter_map(|id| {
+            let col = columns.iter().find(|col| col.id == id)?;
+            let hidden = layout_read.is_hidden(&id);
+            let pin = layout_read.pin(&id);
+            Some((id, col.header.clone(), hidden, pin))
+        })
+        .collect();
+    drop(layout_read);
+    let all_ids: Vec<String> = columns.iter().map(|col| col.id.clone()).collect();
+
+    let has_filters = shown.iter().any(|col| col.is_filterable());
+    let sort_state = sort.read().clone();
+    let multi_sort = sort_state.len() > 1;
+    let rows_read = rows.read();
+    let column_count = shown.len() as u32 + u32::from(selectable);
+    let row_keys: Vec<String> = if selectable {
+        visible.read().iter().map(|&i| key_of(i)).collect()
+    } else {
+        Vec::new()
+    };
+    let selection_read = selection.read();
+    let visible_selected = row_keys
+        .iter()
+        .filter(|key| selection_read.contains(*key))
+        .count();
+    let all_selected = !row_keys.is_empty() && visible_selected == row_keys.len();
+    let some_selected = visible_selected > 0 && !all_selected;
+    let selected_count = if selectable && !selection_read.is_empty() {
+        selected_rows().len()
+    } else {
+        0
+    };
+    drop(selection_read);
+    let cursor_position = cursor();
+    let select_pin = pin_selection.then_some(ColumnPin::Left);
+    let dragged = dragging();
+    let target = drop_target();
+
+    rsx! {
+        div { class: style::data_table,
+            if column_menu || exportable || selected_count > 0 {
+                div { class: style::toolbar,
+                    if selected_count > 0 {
+                        div { class: style::bulk_bar, role: "toolbar",
+                            span { class: style::selection_count, "{selected_count} selected" }
+                            if let Some(bulk_actions) = bulk_actions {
+                                {bulk_actions.call(selected_rows())}
+                            }
+                            Button {
+                                variant: ButtonVariant::Ghost,
+                                size: ButtonSize::Small,
+                                onclick: move |_| {
+                                    selection.write().clear();
+                                    notify_selection();
+                                },
+                                "Clear selection"
+                            }
+                        }
+                    }
+                    span { class: style::spacer }
+                    if exportable {
+                        TableExport { data: export_data, filename: export_filename }
+                    }
+                    if column_menu {
+                        Popover { position: PopoverPosition::BottomEnd,
+                            trigger: rsx! {
+                                Button { variant: ButtonVariant::Ghost, size: ButtonSize::Small,
+                                    IconColumns3 { class: style::button_icon }
+                                    "Columns"
+                                }
+                            },
+                            PopoverContent {
+                                div { class: style::column_menu,
+                                    for (id, header, hidden, pin) in menu_columns.iter().cloned() {
+                                        div { key: "{id}", class: style::menu_item,
+                                            Checkbox {
+                                                size: CheckboxSize::Small,
+                                                checked: !hidden,
+                                                disabled: !hidden && column_count == 1,
+                                                label: header,
+                                                onchange: {
+                                                    let id = id.clone();
+                                                    move |_| {
+                                                        layout.write().set_hidden(&id, !hidden);
+                                                        notify_layout();
+                                                    }
+                                                },
+                                            }
+                                            span { class: stylance::classes!(style::pin_toggle, if pin == Some(ColumnPin::Left) { style::active } else { "" }),
+                                                IconButton {
+                                                    size: IconButtonSize::Small,
+                                                    aria_label: "Pin left",
+                                                    onclick: {
+                                                        let id = id.clone();
+                                                        move |_| {
+                                                            let next = (pin != Some(ColumnPin::Left)).then_some(ColumnPin::Left);
+                                                            layout.write().set_pin(&id, next);
+                                                            notify_layout();
+                                                        }
+                                                    },
+                                                    IconArrowLeftToLine {}
+                                                }
+                                            }
+                                            span { class: stylance::classes!(style::pin_toggle, if pin == Some(ColumnPin::Right) { style::active } else { "" }),
+                                                IconButton {
+                                                    size: IconButtonSize::Small,
+                                                    aria_label: "Pin right",
+                                                    onclick: move |_| {
+                                                        let next = (pin != Some(ColumnPin::Right)).then_some(ColumnPin::Right);
+                                                        layout.write().set_pin(&id, next);
+                                                        notify_layout();
+                                                    },
+                                                    IconArrowRightToLine {}
+                                                }
+                                            }
+                                        }
+                                    }
+                                    div { class: style::menu_footer,
+                                        Button {
+                                            variant: ButtonVariant::Ghost,
+                                            size: ButtonSize::Small,
+                                            onclick: move |_| {
+                                                layout.set(ColumnLayout::default());
+                                                notify_layout();
+                                            },
+                                            "Reset columns"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            div {
+                class: stylance::classes!(
+                    style::scroll,
+                    if sized { style::sized } else { "" },
+                    if resizing.read().is_some() { style::resizing } else { "" }
+                ),
+                tabindex: selectable.then_some("0"),
+                onkeydown: handle_keydown,
+                onmousemove: move |evt: MouseEvent| {
+                    let Some(resize) = resizing.peek().clone() else {
+                        return;
+                    };
+                    let x = evt.client_coordinates().x;
+                    let width = (resize.start_width + x - resize.start_x).max(MIN_COLUMN_WIDTH).round();
+                    layout.write().set_width(&resize.column, width);
+                },
+                onmouseup: move |_| {
+                    if resizing.take().is_some() {
+                        notify_layout();
+                    }
+                },
+                onmouseleave: move |_| {
+                    if resizing.take().is_some() {
+                        notify_layout();
+                    }
+                },
+                Table { size, variant, hoverable, sticky_header,
+                    TableHeader {
+                        TableRow {
+                            if selectable {
+                                TableHeaderCell { width: SELECT_COLUMN_WIDTH, pin: select_pin,
+                                    Checkbox {
+                                        size: CheckboxSize::Small,
+                                        checked: all_selected,
+                                        indeterminate: some_selected,
+                                        disabled: row_keys.is_empty(),
+                                        aria_label: "Select all rows",
+                                        onchange: move |_| set_all(!all_selected),
+                                    }
+                                }
+                            }
+                            for (col, placement) in shown.iter().zip(&placements) {
+                                {
+                                    let id = col.id.clone();
+                                    let position = sort_state.iter().position(|s| s.column == col.id);
+                                    let direction = position.map(|i| sort_state[i].direction.as_str().to_string());
+                                    let sortable = col.is_sortable();
+                                    let start_width = placement.width.unwrap_or(col.width);
+                                    let is_target = target.as_deref() == Some(id.as_str())
+                                        && dragged.as_deref() != Some(id.as_str());
+                                    rsx! {
+                                        TableHeaderCell {
+                                            key: "{col.id}",
+                                            sortable,
+                                            sort_direction: direction,
+                                            sort_order: position.filter(|_| multi_sort).map(|i| i + 1),
+                                            align: col.align.clone(),
+                                            width: placement.width,
+                                            pin: placement.pin,
+                                            pin_offset: placement.offset,
+                                            draggable: reorderable,
+                                            drop_target: is_target,
+                                            on_sort_click: {
+                                                let id = id.clone();
+                                                move |evt: MouseEvent| {
+                                                    if !sortable {
+                                                        return;
+                                                    }
+                                                    toggle_sort(&mut sort.write(), &id, evt.modifiers().shift());
+                                                    if let Some(handler) = &on_sort_change {
+                                                        handler.call(sort.peek().clone());
+                                                    }
+                                                }
+                                            },
+                                            on_resize_start: resizable.then(|| {
+                                                let id = id.clone();
+                                                EventHandler::new(move |evt: MouseEvent| {
+                                                    resizing.set(Some(Resize {
+                                                        column: id.clone(),
+                                                        start_x: evt.client_coordinates().x,
+                                                        start_width,
+                                                    }));
+                                                })
+                                            }),
+                                            ondragstart: {
+                                                let id = id.clone();
+                                                move |evt: DragEvent| {
+                                                    let _ = evt.data_transfer().set_data("text/plain", &id);
+                                                    evt.data_transfer().set_effect_allowed("move");
+                                                    dragging.set(Some(id.clone()));
+                                                }
+                                            },
+                                            ondragover: {
+                                                let id = id.clone();
+                                                move |evt: DragEvent| {
+                                                    if dragging.peek().is_some() {
+                                                        evt.prevent_default();
+                                                        if drop_target.peek().as_deref() != Some(id.as_str()) {
+                                                            drop_target.set(Some(id.clone()));
+                                                        }
+                                                    }
+                                                }
+                                            },
+                                            ondrop: {
+                                                let all_ids = all_ids.clone();
+                                                move |evt: DragEvent| {
+                                                    evt.prevent_default();
+                                                    drop_target.set(None);
+                                                    let Some(moved) = dragging.take() else {
+                                                        return;
+                                                    };
+                                                    let ids: Vec<&str> = all_ids.iter().map(String::as_str).collect();
+                                                    layout.write().move_column(&ids, &moved, &id);
+                                                    notify_layout();
+                                                }
+                                            },
+                                            ondragend: move |_| {
+                                                dragging.set(None);
+                                                drop_target.set(None);
+                                            },
+                                            "{col.header}"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        if has_filters {
+                            tr {
+                                class: style::filter_row,
+                                // Typing in a filter must not drive keyboard selection
+                                onkeydown: move |evt| evt.stop_propagation(),
+                                if selectable {
+                                    th {
+                                        class: stylance::classes!(style::filter_cell, if pin_selection { style::pinned } else { "" }),
+                                        style: pin_selection.then_some("left: 0px"),
+                                    }
+                                }
+                                for (col, placement) in shown.iter().zip(&placements) {
+                                    th {
+                                        key: "{col.id}",
+                                        class: stylance::classes!(style::filter_cell, if placement.pin.is_some() { style::pinned } else { "" }),
+                                        style: placement.pin.map(|pin| format!("{}: {}px", pin.as_str(), placement.offset)),
+                                        if col.is_filterable() {
+                                            {
+                                                let id = col.id.clone();
+                                                let value = filters.read().get(&col.id).cloned().unwrap_or_default();
+                                                rsx! {
+                                                    Input {
+                                                        size: InputSize::Small,
+                                                        placeholder: "Filter…",
+                                                        value,
+                                                        clearable: true,
+                                                        oninput: {
+                                                            let id = id.clone();
+                                                            move |evt: FormEvent| {
+                                                                filters.write().insert(id.clone(), evt.value());
+                                                            }
+                                                        },
+                                                        onclear: move |_| {
+                                                            filters.write().remove(&id);
+                                                        },
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    TableBody {
+                        if visible.read().is_empty() {
+                            TableEmpty { colspan: column_count, message: empty_message }
+                        }
+                        for (position, &index) in visible.read().iter().enumerate() {
+                            {
+                                let row = rows_read[index].clone();
+                                let selected = row_keys.get(position).is_some_and(|key| selection.read().contains(key));
+                                rsx! {
+                                    TableRow {
+                                        key: "{index}",
+                                        selected,
+                                        focused: selectable && cursor_position == Some(position),
+                                        onclick: on_row_click.map(|handler| {
+                                            let row = row.clone();
+                                            EventHandler::new(move |_| handler.call(row.clone()))
+                                        }),
+                                        if selectable {
+                                            TableCell { pin: select_pin,
+                                                span {
+                                                    class: style::row_select,
+                                                    // Remember shift for the change event, which carries no modifiers
+                                                    onmousedown: move |evt: MouseEvent| shift_held.set(evt.modifiers().shift()),
+                                                    onkeydown: move |evt: KeyboardEvent| {
+                                                        evt.stop_propagation();
+                                                        shift_held.set(evt.modifiers().shift());
+                                                    },
+                                                    onclick: move |evt: MouseEvent| evt.stop_propagation(),
+                                                    Checkbox {
+                                                        size: CheckboxSize::Small,
+                                                        checked: selected,
+                                                        aria_label: "Select row",
+                                                        onchange: move |_| toggle_row(position, *shift_held.peek()),
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        for (col, placement) in shown.iter().zip(&placements) {
+                                            TableCell {
+                                                key: "{col.id}",
+                                                align: col.align.clone(),
+                                                pin: placement.pin,
+                                                pin_offset: placement.offset,
+                                                {col.render(&row)}
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows() -> Vec<(&'static str, u32)> {
+        vec![("bob", 30), ("alice", 9), ("Carol", 30), ("dave", 100)]
+    }
+
+    fn columns() -> Vec<Column<(&'static str, u32)>> {
+        vec![
+            Column::new("name", "Name", |r: &(&str, u32)| r.0.to_string())
+                .sortable()
+                .filterable(),
+            Column::new("age", "Age", |r: &(&str, u32)| r.1.to_string()).sortable(),
+            Column::new("plain", "Plain", |r: &(&str, u32)| r.0.to_string()),
+        ]
+    }
+
+    fn visible(sort: &[ColumnSort], filters: &[(&str, &str)]) -> Vec<usize> {
+        let filters = filters
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        visible_rows(&rows(), &columns(), sort, &filters)
+    }
+
+    #[test]
+    fn unsorted_keeps_row_order() {
+        assert_eq!(visible(&[], &[]), [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn sorts_text_case_insensitively_and_numbers_numerically() {
+        assert_eq!(visible(&[ColumnSort::ascending("name")], &[]), [1, 0, 2, 3]);
+        assert_eq!(
+            visible(&[ColumnSort::descending("name")], &[]),
+            [3, 2, 0, 1]
+        );
+        // "100" sorts after "30" even though it is smaller as text
+        assert_eq!(visible(&[ColumnSort::ascending("age")], &[]), [1, 0, 2, 3]);
+    }
+
+    #[test]
+    fn sort_is_stable_and_uses_later_columns_as_tie_breakers() {
+        assert_eq!(visible(&[ColumnSort::descending("age")], &[]), [3, 0, 2, 1]);
+        let sort = [
+            ColumnSort::descending("age"),
+            ColumnSort::descending("name"),
+        ];
+        assert_eq!(visible(&sort, &[]), [3, 2, 0, 1]);
+    }
+
+    #[test]
+    fn ignores_unknown_and_unsortable_columns() {
+        let sort = [
+            ColumnSort::ascending("missing"),
+            ColumnSort::ascending("plain"),
+        ];
+        assert_eq!(visible(&sort, &[]), [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn filters_case_insensitively() {
+        assert_eq!(visible(&[], &[("name", "A")]), [1, 2, 3]);
+        assert_eq!(
+            visible(&[ColumnSort::ascending("name")], &[("name", "a")]),
+            [1, 2, 3]
+        );
+        assert_eq!(visible(&[], &[("name", "zzz")]), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn blank_and_unfilterable_filters_match_everything() {
+        assert_eq!(visible(&[], &[("name", "  ")]), [0, 1, 2, 3]);
+        assert_eq!(visible(&[], &[("plain", "zzz")]), [0, 1, 2, 3]);
+    }
+
+    fn clicks(sort: &mut Vec<ColumnSort>, column: &str, additive: bool) -> Vec<ColumnSort> {
+        toggle_sort(sort, column, additive);
+        sort.clone()
+    }
+
+    #[test]
+    fn plain_click_cycles_one_column() {
+        let mut sort = Vec::new();
+        assert_eq!(clicks(&mut sort, "a", false), [ColumnSort::ascending("a")]);
+        assert_eq!(clicks(&mut sort, "a", false), [ColumnSort::descending("a")]);
+        assert_eq!(clicks(&mut sort, "a", false), []);
+        clicks(&mut sort, "a", false);
+        assert_eq!(clicks(&mut sort, "b", false), [ColumnSort::ascending("b")]);
+    }
+
+    #[test]
+    fn shift_click_builds_a_multi_column_sort() {
+        let mut sort = vec![ColumnSort::ascending("a")];
+        assert_eq!(
+            clicks(&mut sort, "b", true),
+            [ColumnSort::ascending("a"), ColumnSort::ascending("b")]
+        );
+        assert_eq!(
+            clicks(&mut sort, "b", true),
+            [ColumnSort::ascending("a"), ColumnSort::descending("b")]
+        );
+        assert_eq!(clicks(&mut sort, "b", true), [ColumnSort::ascending("a")]);
+        assert_eq!(clicks(&mut sort, "a", true), [ColumnSort::descending("a")]);
+        assert_eq!(clicks(&mut sort, "a", true), []);
+    }
+
+    #[test]
+    fn plain_click_collapses_a_multi_column_sort() {
+        let mut sort = vec![ColumnSort::ascending("a"), ColumnSort::descending("b")];
+        assert_eq!(clicks(&mut sort, "b", false), [ColumnSort::descending("b")]);
+        let mut sort = vec![ColumnSort::ascending("a"), ColumnSort::descending("b")];
+        assert_eq!(clicks(&mut sort, "c", false), [ColumnSort::ascending("c")]);
+    }
+}
@@ -0,0 +1,290 @@
+//! Date picker: a text input with a calendar popover
+
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use jiff::ToSpan;
+use jiff::civil::Date;
+
+use crate::{Calendar, IconCalendar, Input, Popover, PopoverPosition};
+
+stylance::import_style!(style, "date_picker.module.scss");
+
+/// Formats tried, in order, by [`parse_date`]
+const INPUT_FORMATS: &[&str] = &[
+    "%Y-%m-%d",
+    "%Y/%m/%d",
+    "%m/%d/%Y",
+    "%d.%m.%Y",
+    "%B %d, %Y",
+    "%B %d %Y",
+    "%b %d, %Y",
+    "%b %d %Y",
+    "%d %B %Y",
+    "%d %b %Y",
+];
+
+/// Parse a typed date.
+///
+/// Accepts `2026-10-18`, `2026/10/18`, `10/18/2026` (month first),
+/// `18.10.2026`, `October 18, 2026`, `Oct 18 2026`, `18 Oct 2026`, and
+/// `today`, `yesterday` or `tomorrow`.
+pub fn parse_date(input: &str) -> Option<Date> {
+    let input = input.trim();
+    let today = Date::from(jiff::Zoned::now());
+    match input.to_lowercase().as_str() {
+        "today" => return Some(today),
+        "yesterday" => return today.checked_sub(1.day()).ok(),
+        "tomorrow" => return today.checked_add(1.day()).ok(),
+        _ => {}
+    }
+    INPUT_FORMATS
+        .iter()
+        .find_map(|format| Date::strptime(format, input).ok())
+}
+
+/// Date input with a [`Calendar`] popover.
+///
+/// Dates can be typed in any format [`parse_date`] understands (or in
+/// `format`) and are checked when the input loses focus or on Enter.
+/// ArrowDown opens the calendar and moves focus into it; Escape closes it.
+#[component]
+pub fn DatePicker(
+    /// Selected date
+    #[props(optional)]
+    value: Option<Date>,
+    /// Called with the typed or picked date, or `None` when the input is cleared
+    onchange: Option<EventHandler<Option<Date>>>,
+    #[props(default)] label: String,
+    #[props(default = "YYYY-MM-DD".to_string())] placeholder: String,
+    /// Display format, in `strftime` syntax; also accepted when typing
+    #[props(default = "%Y-%m-%d".to_string())]
+    format: String,
+    /// Earliest selectable date
+    #[props(optional)]
+    min_date: Option<Date>,
+    /// Latest selectable date
+    #[props(optional)]
+    max_date: Option<Date>,
+    /// Error message; typing an invalid date shows its own
+    #[props(default)]
+    error: String,
+    #[props(default = false)] disabled: bool,
+    #[props(default = false)] required: bool,
+    /// Called when the input loses focus
+    onblur: Option<EventHandler<FocusEvent>>,
+    /// Called with the input element once mounted, e.g. to focus it
+    onmounted: Option<EventHandler<MountedEvent>>,
+) -> Element {
+    let mut open = use_signal(|| false);
+    let mut text = use_signal(String::new);
+    // Whether the input shows typed text rather than the formatted value
+    let mut editing = use_signal(|| false);
+    let mut parse_error = use_signal(|| None::<String>);
+    // Move focus into the calendar when it opens from the keyboard
+    let mut focus_calendar = use_signal(|| false);
+    let mut input_element: Signal<Option<Rc<MountedData>>> = use_signal(|| None);
+
+    let display = {
+        let format = format.clone();
+        move |date: Date| date.strftime(&format).to_string()
+    };
+
+    let focus_input = move || {
+        if let Some(el) = input_element.cloned() {
+            spawn(async move {
+                let _ = el.set_focus(true).await;
+            });
+        }
+    };
+
+    let mut change = move |date: Option<Date>| {
+        editing.set(false);
+        parse_error.set(None);
+        if let Some(handler) = &onchange {
+            handler.call(date);
+        }
+    };
+
+    let commit = {
+        let display = display.clone();
+        move || {
+            if !editing() {
+                return;
+            }
+            let typed = text();
+            let typed = typed.trim();
+            if typed.is_empty() {
+                change(None);
+                return;
+            }
+            let parsed = Date::strptime(&format, typed)
+                .ok()
+                .or_else(|| parse_date(typed));
+            editing.set(false);
+            match parsed {
+                None => {
+                    let example = display(Date::from(jiff::Zoned::now()));
+                    parse_error.set(Some(format!("Enter a date like {example}")));
+                }
+                Some(date) if min_date.is_some_and(|min| date < min) => {
+                    let min = display(min_date.unwrap_or(date));
+                    parse_error.set(Some(format!("Pick a date on or after {min}")));
+                }
+                Some(date) if max_date.is_some_and(|max| date > max) => {
+                    let max = display(max_date.unwrap_or(date));
+                    parse_error.set(Some(format!("Pick a date on or before {max}")));
+                }
+                Some(date) => change(Some(date)),
+            }
+        }
+    };
+
+    let mut commit_on_enter = commit.clone();
+    let mut commit_on_blur = commit;
+
+    // Keep a mistyped date visible next to its error so it can be fixed
+    let input_value = if editing() || parse_error().is_some() {
+        text()
+    } else {
+        value.map(display).unwrap_or_default()
+    };
+    let shown_error = parse_error().unwrap_or(error);
+
+    rsx! {
+        div {
+            class: style::date_picker,
+            onkeydown: move |evt| {
+                if evt.key() == Key::Escape && open() {
+                    evt.prevent_default();
+                    open.set(false);
+                    focus_input();
+                }
+            },
+            Popover {
+                position: PopoverPosition::BottomStart,
+                open,
+                trigger: rsx! {
+                    div {
+                        onclick: move |evt| {
+                            focus_calendar.set(false);
+                            // Clicking into the input keeps an open calendar open
+                            if open() {
+                                evt.stop_propagation();
+                            }
+                        },
+                        onkeydown: move |evt| match evt.key() {
+                            Key::ArrowDown if !disabled => {
+                                evt.prevent_default();
+                                commit_on_enter();
+                                focus_calendar.set(true);
+                                open.set(true);
+                            }
+                            Key::Enter if editing() => {
+                                evt.prevent_default();
+                                commit_on_enter();
+                            }
+                            _ => {}
+                        },
+                        Input {
+                            label,
+                            placeholder,
+                            value: input_value,
+                            error: shown_error,
+                            disabled,
+                            required,
+                            icon: rsx! { IconCalendar {} },
+                            oninput: move |evt: FormEvent| {
+                                text.set(evt.value());
+                                editing.set(true);
+                            },
+                            onblur: move |evt| {
+                                commit_on_blur();
+                                if let Some(handler) = &onblur {
+                                    handler.call(evt);
+                                }
+                            },
+                            onmounted: move |evt: MountedEvent| {
+                                input_element.set(Some(evt.data()));
+                                if let Some(handler) = &onmounted {
+                                    handler.call(evt);
+                                }
+                            },
+                        }
+                    }
+                },
+                if open() && !disabled {
+                    Calendar {
+                        selected: value,
+                        initial_date: value,
+                        min_date,
+                        max_date,
+                        autofocus: focus_calendar(),
+                        on_select: move |date| {
+                            change(Some(date));
+                            open.set(false);
+                            focus_input();
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jiff::civil::date;
+
+    #[test]
+    fn parses_every_input_format() {
+        let expected = date(2026, 3, 7);
+        for input in [
+            "2026-03-07",
+            "2026/03/07",
+            "03/07/2026",
+            "07.03.2026",
+            "March 07, 2026",
+            "March 7 2026",
+            "Mar 7, 2026",
+            "Mar 07 2026",
+            "7 March 2026",
+            "07 Mar 2026",
+        ] {
+            assert_eq!(parse_date(input), Some(expected), "{input:?}");
+        }
+        assert_eq!(INPUT_FORMATS.len(), 10);
+    }
+
+    #[test]
+    fn trims_and_ignores_month_name_case() {
+        assert_eq!(parse_date("  2026-03-07\n"), Some(date(2026, 3, 7)));
+        assert_eq!(parse_date("mar 7, 2026"), Some(date(2026, 3, 7)));
+    }
+
+    #[test]
+    fn parses_relative_keywords() {
+        let today = Date::from(jiff::Zoned::now());
+        assert_eq!(parse_date("today"), Some(today));
+        assert_eq!(parse_date(" Today "), Some(today));
+        assert_eq!(parse_date("YESTERDAY"), today.yesterday().ok());
+        assert_eq!(parse_date("tomorrow"), today.tomorrow().ok());
+    }
+
+    #[test]
+    fn rejects_garbage_and_impossible_dates() {
+        for input in [
+            "",
+            "soon",
+            "2026-13-01",
+            "2026-02-30",
+            "31/12/2026",
+            "2026-03",
+            "Smarch 7, 2026",
+            "2026-03-07 extra",
+        ] {
+            assert_eq!(parse_date(input), None, "{input:?}");
+        }
+    }
+}
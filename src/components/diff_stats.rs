@@ -91,13 +91,14 @@ pub fn DiffFileSummary(
     /// Number of deletions
     #[props(default = 0)]
     deletions: usize,
-    /// File status (added, modified, deleted, renamed)
+    /// File status (added, modified, deleted, renamed, copied)
     status: Option<String>,
 ) -> Element {
     let status_class = match status.as_deref() {
         Some("added") => style::status_added,
         Some("deleted") => style::status_deleted,
         Some("renamed") => style::status_renamed,
+        Some("copied") => style::status_copied,
         _ => style::status_modified,
     };
 
@@ -105,6 +106,7 @@ pub fn DiffFileSummary(
         Some("added") => "A",
         Some("deleted") => "D",
         Some("renamed") => "R",
+        Some("copied") => "C",
         _ => "M",
     };
 
@@ -0,0 +1,912 @@
+//! Diff viewer for unified diffs, in unified or side-by-side layout
+
+use std::collections::HashSet;
+
+use dioxus::prelude::*;
+
+use super::diff_stats::DiffFileSummary;
+
+stylance::import_style!(style, "diff_viewer.module.scss");
+
+/// Kind of change a file underwent
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DiffFileStatus {
+    /// File was created
+    Added,
+    /// File was removed
+    Deleted,
+    /// File contents changed in place
+    #[default]
+    Modified,
+    /// File was moved (possibly with changes)
+    Renamed,
+    /// File was copied from another path
+    Copied,
+}
+
+impl DiffFileStatus {
+    /// Get status as string (matches the `status` accepted by [`DiffFileSummary`])
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Added => "added",
+            Self::Deleted => "deleted",
+            Self::Modified => "modified",
+            Self::Renamed => "renamed",
+            Self::Copied => "copied",
+        }
+    }
+
+    /// Single-letter status, as shown by `git status --short`
+    #[must_use]
+    pub const fn letter(&self) -> &'static str {
+        match self {
+            Self::Added => "A",
+            Self::Deleted => "D",
+            Self::Modified => "M",
+            Self::Renamed => "R",
+            Self::Copied => "C",
+        }
+    }
+}
+
+/// Kind of a single diff line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffLineKind {
+    /// Unchanged line present on both sides
+    Context,
+    /// Line only present in the new file
+    Addition,
+    /// Line only present in the old file
+    Deletion,
+}
+
+/// A single line inside a hunk
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffLine {
+    /// Whether the line was added, removed or unchanged
+    pub kind: DiffLineKind,
+    /// Line content, without the leading `+`/`-`/space marker
+    pub content: String,
+    /// Line number in the old file (`None` for additions)
+    pub old_line: Option<usize>,
+    /// Line number in the new file (`None` for deletions)
+    pub new_line: Option<usize>,
+    /// Followed by `\ No newline at end of file`
+    pub missing_newline: bool,
+}
+
+/// A contiguous block of changes (`@@ -a,b +c,d @@`)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DiffHunk {
+    /// First line of the hunk in the old file
+    pub old_start: usize,
+    /// Number of old-file lines covered by the hunk
+    pub old_lines: usize,
+    /// First line of the hunk in the new file
+    pub new_start: usize,
+    /// Number of new-file lines covered by the hunk
+    pub new_lines: usize,
+    /// Section heading after the `@@` marker (usually the enclosing function)
+    pub section: String,
+    /// Lines of the hunk
+    pub lines: Vec<DiffLine>,
+}
+
+impl DiffHunk {
+    /// The `@@ -a,b +c,d @@ section` header line
+    #[must_use]
+    pub fn header(&self) -> String {
+        let header = format!(
+            "@@ -{},{} +{},{} @@",
+            self.old_start, self.old_lines, self.new_start, self.new_lines
+        );
+        if self.section.is_empty() {
+            header
+        } else {
+            format!("{header} {}", self.section)
+        }
+    }
+}
+
+/// One file's worth of a unified diff
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DiffFile {
+    /// Path before the change (`None` when the file was added)
+    pub old_path: Option<String>,
+    /// Path after the change (`None` when the file was deleted)
+    pub new_path: Option<String>,
+    /// Kind of change
+    pub status: DiffFileStatus,
+    /// Binary file, contents not shown
+    pub binary: bool,
+    /// Hunks of changes
+    pub hunks: Vec<DiffHunk>,
+}
+
+impl DiffFile {
+    /// The current path of the file (the old path for deletions)
+    #[must_use]
+    pub fn path(&self) -> &str {
+        self.new_path
+            .as_deref()
+            .or(self.old_path.as_deref())
+            .unwrap_or_default()
+    }
+
+    /// Path label for display, `old → new` for renames and copies
+    #[must_use]
+    pub fn display_path(&self) -> String {
+        match (&self.old_path, &self.new_path) {
+            (Some(old), Some(new)) if old != new => format!("{old} → {new}"),
+            _ => self.path().to_string(),
+        }
+    }
+
+    /// Number of added lines
+    #[must_use]
+    pub fn additions(&self) -> usize {
+        self.count(DiffLineKind::Addition)
+    }
+
+    /// Number of deleted lines
+    #[must_use]
+    pub fn deletions(&self) -> usize {
+        self.count(DiffLineKind::Deletion)
+    }
+
+    fn count(&self, kind: DiffLineKind) -> usize {
+        self.hunks
+            .iter()
+            .flat_map(|h| &h.lines)
+            .filter(|l| l.kind == kind)
+            .count()
+    }
+}
+
+/// Parse unified diff text (`git diff`, `diff -u`) into files and hunks.
+///
+/// Handles several files per diff, new/deleted files, renames and copies, and
+/// binary markers. Text outside of file sections (commit messages, etc.) is ignored.
+#[must_use]
+pub fn parse_diff(input: &str) -> Vec<DiffFile> {
+    let mut parser = DiffParser::default();
+    for line in input.lines() {
+        parser.line(line.strip_suffix('\r').unwrap_or(line));
+    }
+    parser.finish()
+}
+
+#[derive(Default)]
+struct DiffParser {
+    files: Vec<DiffFile>,
+    current: Option<DiffFile>,
+    /// Status set explicitly by an extended header line
+    explicit_status: bool,
+    /// Remaining old/new lines in the current hunk, and the next line numbers
+    remaining: (usize, usize),
+    next_line: (usize, usize),
+}
+
+impl DiffParser {
+    fn line(&mut self, line: &str) {
+        if self.remaining != (0, 0) && self.hunk_line(line) {
+            return;
+        }
+        self.remaining = (0, 0);
+
+        if let Some(rest) = line.strip_prefix("diff --git ") {
+            self.start_file();
+            let (old, new) = split_git_paths(rest);
+            let file = self.file();
+            file.old_path = Some(old);
+            file.new_path = Some(new);
+        } else if let Some(rest) = line.strip_prefix("--- ") {
+            // Plain unified diffs have no `diff --git` line, so `---` after hunks starts a new file
+            if self.current.as_ref().is_none_or(|f| !f.hunks.is_empty()) {
+                self.start_file();
+            }
+            self.file().old_path = parse_path(rest, "a/");
+        } else if let Some(rest) = line.strip_prefix("+++ ") {
+            self.file().new_path = parse_path(rest, "b/");
+        } else if let Some(rest) = line.strip_prefix("@@ ") {
+            self.start_hunk(rest);
+        } else if line.starts_with('\\') {
+            self.mark_missing_newline();
+        } else if let Some(file) = self.current.as_mut() {
+            let explicit = if line.starts_with("new file mode") {
+                file.old_path = None;
+                Some(DiffFileStatus::Added)
+            } else if line.starts_with("deleted file mode") {
+                file.new_path = None;
+                Some(DiffFileStatus::Deleted)
+            } else if let Some(path) = line.strip_prefix("rename from ") {
+                file.old_path = Some(unquote(path));
+                Some(DiffFileStatus::Renamed)
+            } else if let Some(path) = line.strip_prefix("rename to ") {
+                file.new_path = Some(unquote(path));
+                Some(DiffFileStatus::Renamed)
+            } else if let Some(path) = line.strip_prefix("copy from ") {
+                file.old_path = Some(unquote(path));
+                Some(DiffFileStatus::Copied)
+            } else if let Some(path) = line.strip_prefix("copy to ") {
+                file.new_path = Some(unquote(path));
+                Some(DiffFileStatus::Copied)
+            } else {
+                if line.starts_with("Binary files ") || line == "GIT binary patch" {
+                    file.binary = true;
+                }
+                None
+            };
+            if let Some(status) = explicit {
+                file.status = status;
+                self.explicit_status = true;
+            }
+        }
+    }
+
+    /// Consume a line belonging to the current hunk. Returns false if the line
+    /// doesn't look like hunk content, which ends the hunk early.
+    fn hunk_line(&mut self, line: &str) -> bool {
+        let (kind, content) = match line.as_bytes().first() {
+            Some(b' ') => (DiffLineKind::Context, &line[1..]),
+            // Some tools strip the trailing space of empty context lines
+            None => (DiffLineKind::Context, ""),
+            Some(b'+') => (DiffLineKind::Addition, &line[1..]),
+            Some(b'-') => (DiffLineKind::Deletion, &line[1..]),
+            Some(b'\\') => {
+                self.mark_missing_newline();
+                return true;
+            }
+            _ => return false,
+        };
+
+        let (old, new) = self.next_line;
+        let (old_line, new_line) = match kind {
+            DiffLineKind::Context => {
+                self.remaining.0 = self.remaining.0.saturating_sub(1);
+                self.remaining.1 = self.remaining.1.saturating_sub(1);
+                self.next_line = (old + 1, new + 1);
+                (Some(old), Some(new))
+            }
+            DiffLineKind::Deletion => {
+                self.remaining.0 = self.remaining.0.saturating_sub(1);
+                self.next_line.0 += 1;
+                (Some(old), None)
+            }
+            DiffLineKind::Addition => {
+                self.remaining.1 = self.remaining.1.saturating_sub(1);
+                self.next_line.1 += 1;
+                (None, Some(new))
+            }
+        };
+
+        if let Some(hunk) = self.file().hunks.last_mut() {
+            hunk.lines.push(DiffLine {
+                kind,
+                content: content.to_string(),
+                old_line,
+                new_line,
+                missing_newline: false,
+            });
+        }
+        true
+    }
+
+    fn start_hunk(&mut self, header: &str) {
+        // -a,b +c,d @@ section
+        let (ranges, section) = header.split_once("@@").unwrap_or((header, ""));
+        let mut ranges = ranges.split_whitespace();
+        let (old_start, old_lines) = parse_range(ranges.next().unwrap_or_default(), '-');
+        let (new_start, new_lines) = parse_range(ranges.next().unwrap_or_default(), '+');
+
+        self.remaining = (old_lines, new_lines);
+        self.next_line = (old_start, new_start);
+        self.file().hunks.push(DiffHunk {
+            old_start,
+            old_lines,
+            new_start,
+            new_lines,
+            section: section.trim().to_string(),
+            lines: Vec::new(),
+        });
+    }
+
+    fn mark_missing_newline(&mut self) {
+        let last = self
+            .current
+            .as_mut()
+            .and_then(|f| f.hunks.last_mut())
+            .and_then(|h| h.lines.last_mut());
+        if let Some(line) = last {
+            line.missing_newline = true;
+        }
+    }
+
+    fn file(&mut self) -> &mut DiffFile {
+        self.current.get_or_insert_with(DiffFile::default)
+    }
+
+    fn start_file(&mut self) {
+        self.flush();
+        self.current = Some(DiffFile::default());
+    }
+
+    fn flush(&mut self) {
+        let Some(mut file) = self.current.take() else {
+            return;
+        };
+        if !self.explicit_status {
+            file.status = match (&file.old_path, &file.new_path) {
+                (None, Some(_)) => DiffFileStatus::Added,
+                (Some(_), None) => DiffFileStatus::Deleted,
+                (Some(old), Some(new)) if old != new => DiffFileStatus::Renamed,
+                _ => DiffFileStatus::Modified,
+            };
+        }
+        self.explicit_status = false;
+        self.files.push(file);
+    }
+
+    fn finish(mut self) -> Vec<DiffFile> {
+        self.flush();
+        self.files
+    }
+}
+
+/// Parse `-a,b` / `+c,d` (the count defaults to 1 when omitted)
+fn parse_range(range: &str, sign: char) -> (usize, usize) {
+    let range = range.trim_start_matches(sign);
+    let (start, count) = range.split_once(',').unwrap_or((range, "1"));
+    (start.parse().unwrap_or(0), count.parse().unwrap_or(0))
+}
+
+/// Parse a `---`/`+++` path, stripping timestamps and the `a/`/`b/` prefix
+fn parse_path(raw: &str, prefix: &str) -> Option<String> {
+    let path = unquote(raw.split('\t').next().unwrap_or(raw).trim_end());
+    if path == "/dev/null" {
+        return None;
+    }
+    Some(
+        path.strip_prefix(prefix)
+            .map(str::to_string)
+            .unwrap_or(path),
+    )
+}
+
+/// Split the `a/old b/new` part of a `diff --git` line
+fn split_git_paths(rest: &str) -> (String, String) {
+    let rest = rest.trim();
+    if let Some(quoted) = rest.strip_prefix('"') {
+        // Quoted paths (with spaces or escapes): `"a/x y" "b/x y"`
+        let mut escaped = false;
+        let close = quoted.find(|c| {
+            let closes = c == '"' && !escaped;
+            escaped = c == '\\' && !escaped;
+            closes
+        });
+        let end = close.map_or(rest.len(), |i| i + 2);
+        let old = unquote(&rest[..end]);
+        let new = unquote(rest[end..].trim());
+        return (strip(&old, "a/"), strip(&new, "b/"));
+    }
+
+    // Prefer the split where both sides name the same file, which is the common case
+    let candidates: Vec<usize> = rest.match_indices(" b/").map(|(i, _)| i).collect();
+    let split = candidates
+        .iter()
+        .copied()
+        .find(|&i| rest[..i].strip_prefix("a/") == Some(&rest[i + 3..]))
+        .or_else(|| candidates.last().copied());
+    match split {
+        Some(i) => (strip(&rest[..i], "a/"), rest[i + 3..].to_string()),
+        None => (rest.to_string(), rest.to_string()),
+    }
+}
+
+fn strip(path: &str, prefix: &str) -> String {
+    path.strip_prefix(prefix).unwrap_or(path).to_string()
+}
+
+/// Decode a path git quoted because of special characters
+///
+/// Git writes such paths in C string syntax, with non-ASCII bytes as octal
+/// escapes (`"caf\303\251.rs"`), so the escapes are decoded to bytes first.
+fn unquote(path: &str) -> String {
+    let path = path.trim();
+    let Some(inner) = path.strip_prefix('"').and_then(|p| p.strip_suffix('"')) else {
+        return path.to_string();
+    };
+    let mut bytes = Vec::with_capacity(inner.len());
+    let mut rest = inner.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        rest = tail;
+        if byte != b'\\' {
+            bytes.push(byte);
+            continue;
+        }
+        let Some((&escape, tail)) = rest.split_first() else {
+            bytes.push(byte);
+            break;
+        };
+        rest = tail;
+        match escape {
+            b'0'..=b'7' => {
+                let digits = rest
+                    .iter()
+                    .take(2)
+                    .take_while(|b| matches!(b, b'0'..=b'7'))
+                    .count();
+                let value = rest[..digits]
+                    .iter()
+                    .fold(u32::from(escape - b'0'), |acc, b| {
+                        acc * 8 + u32::from(b - b'0')
+                    });
+                rest = &rest[digits..];
+                bytes.push(value as u8);
+            }
+            b'a' => bytes.push(0x07),
+            b'b' => bytes.push(0x08),
+            b't' => bytes.push(b'\t'),
+            b'n' => bytes.push(b'\n'),
+            b'v' => bytes.push(0x0b),
+            b'f' => bytes.push(0x0c),
+            b'r' => bytes.push(b'\r'),
+            other => bytes.push(other),
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// Layout of the diff
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DiffViewMode {
+    /// Single column with old and new line numbers
+    #[default]
+    Unified,
+    /// Old file on the left, new file on the right
+    Split,
+}
+
+/// A slice of hunk lines to render, or a folded run of unchanged lines
+enum Segment {
+    Lines(std::ops::Range<usize>),
+    Folded(std::ops::Range<usize>),
+}
+
+/// Split a hunk into visible and folded ranges, keeping `context` unchanged lines
+/// around every change. Runs listed in `expanded` (by start index) stay visible.
+fn segments(lines: &[DiffLine], context: usize, expanded: impl Fn(usize) -> bool) -> Vec<Segment> {
+    let mut out = Vec::new();
+    let mut visible_from = 0;
+    let mut i = 0;
+    while i < lines.len() {
+        if lines[i].kind != DiffLineKind::Context {
+            i += 1;
+            continue;
+        }
+        let run_start = i;
+        while i < lines.len() && lines[i].kind == DiffLineKind::Context {
+            i += 1;
+        }
+        let keep_before = if run_start == 0 { 0 } else { context };
+        let keep_after = if i == lines.len() { 0 } else { context };
+        let fold = (run_start + keep_before)..i.saturating_sub(keep_after);
+
+        // Folding a single line saves nothing over showing it
+        if fold.len() > 1 && !expanded(fold.start) {
+            if visible_from < fold.start {
+                out.push(Segment::Lines(visible_from..fold.start));
+            }
+            visible_from = fold.end;
+            out.push(Segment::Folded(fold));
+        }
+    }
+    if visible_from < lines.len() {
+        out.push(Segment::Lines(visible_from..lines.len()));
+    }
+    out
+}
+
+/// A row of the side-by-side layout
+struct SplitRow<'a> {
+    old: Option<&'a DiffLine>,
+    new: Option<&'a DiffLine>,
+}
+
+/// Pair deletions with the additions that follow them, so changed lines line up
+fn split_rows(lines: &[DiffLine]) -> Vec<SplitRow<'_>> {
+    let mut rows = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        if lines[i].kind == DiffLineKind::Context {
+            rows.push(SplitRow {
+                old: Some(&lines[i]),
+                new: Some(&lines[i]),
+            });
+            i += 1;
+            continue;
+        }
+        let del_start = i;
+        while i < lines.len() && lines[i].kind == DiffLineKind::Deletion {
+            i += 1;
+        }
+        let add_start = i;
+        while i < lines.len() && lines[i].kind == DiffLineKind::Addition {
+            i += 1;
+        }
+        let deletions = &lines[del_start..add_start];
+        let additions = &lines[add_start..i];
+        for row in 0..deletions.len().max(additions.len()) {
+            rows.push(SplitRow {
+                old: deletions.get(row),
+                new: additions.get(row),
+            });
+        }
+    }
+    rows
+}
+
+const fn line_class(kind: DiffLineKind) -> &'static str {
+    match kind {
+        DiffLineKind::Context => style::context,
+        DiffLineKind::Addition => style::addition,
+        DiffLineKind::Deletion => style::deletion,
+    }
+}
+
+const fn line_sign(kind: DiffLineKind) -> &'static str {
+    match kind {
+        DiffLineKind::Context => " ",
+        DiffLineKind::Addition => "+",
+        DiffLineKind::Deletion => "-",
+    }
+}
+
+fn line_number(n: Option<usize>) -> String {
+    n.map(|n| n.to_string()).unwrap_or_default()
+}
+
+/// Renders unified diff text file by file, in unified or split layout
+#[component]
+pub fn DiffViewer(
+    /// Unified diff text, as produced by `git diff` (may contain several files)
+    diff: String,
+    /// Unified or side-by-side layout
+    #[props(default)]
+    mode: DiffViewMode,
+    /// Unchanged lines kept around each change; longer runs are folded
+    #[props(default = 3)]
+    context_lines: usize,
+    /// Start with every file collapsed to its header
+    #[props(default = false)]
+    collapsed: bool,
+    /// Additional CSS class
+    #[props(optional, into)]
+    class: Option<String>,
+) -> Element {
+    let files = parse_diff(&diff);
+    // File indices toggled away from the `collapsed` default
+    let mut toggled_files = use_signal(HashSet::<usize>::new);
+    // Folded runs the user expanded, keyed by (file, hunk, first line)
+    let mut expanded_runs = use_signal(HashSet::<(usize, usize, usize)>::new);
+
+    let mode_class = match mode {
+        DiffViewMode::Unified => style::unified,
+        DiffViewMode::Split => style::split,
+    };
+    let columns = match mode {
+        DiffViewMode::Unified => 3,
+        DiffViewMode::Split => 4,
+    };
+
+    rsx! {
+        div { class: stylance::classes!(style::diff_viewer, mode_class, class.as_deref().unwrap_or("")),
+            for (file_idx, file) in files.iter().enumerate() {
+                {
+                    let is_collapsed = collapsed != toggled_files.read().contains(&file_idx);
+                    rsx! {
+                        div { key: "{file_idx}", class: style::file,
+                            div { class: style::file_header,
+                                button {
+                                    class: stylance::classes!(style::toggle, if is_collapsed { "" } else { style::toggle_open }),
+                                    r#type: "button",
+                                    aria_expanded: !is_collapsed,
+                                    aria_label: if is_collapsed { "Expand file" } else { "Collapse file" },
+                                    onclick: move |_| {
+                                        let mut toggled = toggled_files.write();
+                                        if !toggled.remove(&file_idx) {
+                                            toggled.insert(file_idx);
+                                        }
+                                    },
+                                    svg {
+                                        width: "16",
+                                        height: "16",
+                                        view_box: "0 0 24 24",
+                                        fill: "none",
+                                        stroke: "currentColor",
+                                        stroke_width: "2",
+                                        stroke_linecap: "round",
+                                        stroke_linejoin: "round",
+                                        polyline { points: "9 18 15 12 9 6" }
+                                    }
+                                }
+                                DiffFileSummary {
+                                    filename: file.display_path(),
+                                    additions: file.additions(),
+                                    deletions: file.deletions(),
+                                    status: Some(file.status.as_str().to_string()),
+                                }
+                            }
+                            if !is_collapsed {
+                                if file.binary {
+                                    div { class: style::notice, "Binary file not shown" }
+                                } else if file.hunks.is_empty() {
+                                    div { class: style::notice, "No content changes" }
+                                } else {
+                                    div { class: style::body,
+                                        table { class: style::table,
+                                            for (hunk_idx, hunk) in file.hunks.iter().enumerate() {
+                                                tbody { key: "{hunk_idx}",
+                                                    tr { class: style::hunk_header,
+                                                        td { colspan: "{columns}", "{hunk.header()}" }
+                                                    }
+                                                    for segment in segments(&hunk.lines, context_lines, |start| expanded_runs.read().contains(&(file_idx, hunk_idx, start))) {
+                                                        {
+                                                            match segment {
+                                                                Segment::Folded(range) => {
+                                                                    let start = range.start;
+                                                                    let count = range.len();
+                                                                    rsx! {
+                                                                        tr { class: style::folded,
+                                                                            td { colspan: "{columns}",
+                                                                                button {
+                                                                                    class: style::expand_button,
+                                                                                    r#type: "button",
+                                                                                    onclick: move |_| {
+                                                                                        expanded_runs.write().insert((file_idx, hunk_idx, start));
+                                                                                    },
+                                                                                    "Show {count} unchanged lines"
+                                                                                }
+                                                                            }
+                                                                        }
+                                                                    }
+                                                                }
+                                                                Segment::Lines(range) => match mode {
+                                                                    DiffViewMode::Unified => rsx! {
+                                                                        for line in &hunk.lines[range] {
+                                                                            UnifiedRow { line: line.clone() }
+                                                                        }
+                                                                    },
+                                                                    DiffViewMode::Split => rsx! {
+                                                                        for row in split_rows(&hunk.lines[range]) {
+                                                                            SplitRowView { old: row.old.cloned(), new: row.new.cloned() }
+                                                                        }
+                                                                    },
+                                                                },
+                                                            }
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A row of the unified layout
+#[component]
+fn UnifiedRow(line: DiffLine) -> Element {
+    rsx! {
+        tr { class: stylance::classes!(style::row, line_class(line.kind)),
+            td { class: style::line_number, "{line_number(line.old_line)}" }
+            td { class: style::line_number, "{line_number(line.new_line)}" }
+            td { class: style::code,
+                span { class: style::sign, "{line_sign(line.kind)}" }
+                "{line.content}"
+                if line.missing_newline {
+                    span { class: style::no_newline, title: "No newline at end of file", "⊘" }
+                }
+            }
+        }
+    }
+}
+
+/// A row of the split layout
+#[component]
+fn SplitRowView(old: Option<DiffLine>, new: Option<DiffLine>) -> Element {
+    rsx! {
+        tr { class: style::row,
+            SplitHalf { line: old.clone(), number: old.as_ref().and_then(|l| l.old_line) }
+            SplitHalf { line: new.clone(), number: new.as_ref().and_then(|l| l.new_line) }
+        }
+    }
+}
+
+/// One side of a split row (empty when the line only exists on the other side)
+#[component]
+fn SplitHalf(line: Option<DiffLine>, number: Option<usize>) -> Element {
+    let Some(line) = line else {
+        return rsx! {
+            td { class: stylance::classes!(style::line_number, style::empty) }
+            td { class: stylance::classes!(style::code, style::empty) }
+        };
+    };
+    let kind_class = line_class(line.kind);
+
+    rsx! {
+        td { class: stylance::classes!(style::line_number, kind_class), "{line_number(number)}" }
+        td { class: stylance::classes!(style::code, kind_class),
+            span { class: style::sign, "{line_sign(line.kind)}" }
+            "{line.content}"
+            if line.missing_newline {
+                span { class: style::no_newline, title: "No newline at end of file", "⊘" }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_git_diff_with_several_files() {
+        let diff = "\
+commit abc
+Author: someone
+
+diff --git a/src/lib.rs b/src/lib.rs
+index 1111111..2222222 100644
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -1,3 +1,4 @@ mod foo;
+ use a;
+-use b;
++use c;
++use d;
+ fn x() {}
+diff --git a/new.txt b/new.txt
+new file mode 100644
+--- /dev/null
++++ b/new.txt
+@@ -0,0 +1 @@
++hello
+\\ No newline at end of file
+";
+        let files = parse_diff(diff);
+        assert_eq!(files.len(), 2);
+
+        let lib = &files[0];
+        assert_eq!(lib.status, DiffFileStatus::Modified);
+        assert_eq!(lib.path(), "src/lib.rs");
+        assert_eq!((lib.additions(), lib.deletions()), (2, 1));
+        let hunk = &lib.hunks[0];
+        assert_eq!(
+            (
+                hunk.old_start,
+                hunk.old_lines,
+                hunk.new_start,
+                hunk.new_lines
+            ),
+            (1, 3, 1, 4)
+        );
+        assert_eq!(hunk.section, "mod foo;");
+        let numbers: Vec<_> = hunk
+            .lines
+            .iter()
+            .map(|l| (l.old_line, l.new_line))
+            .collect();
+        assert_eq!(
+            numbers,
+            vec![
+                (Some(1), Some(1)),
+                (Some(2), None),
+                (None, Some(2)),
+                (None, Some(3)),
+                (Some(3), Some(4)),
+            ]
+        );
+
+        let new = &files[1];
+        assert_eq!(new.status, DiffFileStatus::Added);
+        assert_eq!(new.old_path, None);
+        assert_eq!(new.hunks[0].lines[0].content, "hello");
+        assert!(new.hunks[0].lines[0].missing_newline);
+    }
+
+    #[test]
+    fn parses_renames_copies_deletions_and_binaries() {
+        let diff = "\
+diff --git a/old name.rs b/new name.rs
+similarity index 100%
+rename from old name.rs
+rename to new name.rs
+diff --git a/a.rs b/b.rs
+similarity index 90%
+copy from a.rs
+copy to b.rs
+diff --git a/gone.rs b/gone.rs
+deleted file mode 100644
+--- a/gone.rs
++++ /dev/null
+@@ -1 +0,0 @@
+-bye
+diff --git a/logo.png b/logo.png
+Binary files a/logo.png and b/logo.png differ
+";
+        let files = parse_diff(diff);
+        let summary: Vec<_> = files.iter().map(|f| (f.status, f.display_path())).collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    DiffFileStatus::Renamed,
+                    "old name.rs → new name.rs".to_string()
+                ),
+                (DiffFileStatus::Copied, "a.rs → b.rs".to_string()),
+                (DiffFileStatus::Deleted, "gone.rs".to_string()),
+                (DiffFileStatus::Modified, "logo.png".to_string()),
+            ]
+        );
+        assert!(files[3].binary);
+        assert_eq!(files[2].deletions(), 1);
+    }
+
+    #[test]
+    fn parses_plain_unified_diffs() {
+        let diff = "\
+--- a.txt\t2024-01-01 00:00:00
++++ a.txt\t2024-01-02 00:00:00
+@@ -1,2 +1,2 @@
+-one
++uno
+
+--- b.txt
++++ b.txt
+@@ -5 +5 @@
+-x\r
++y\r
+";
+        let files = parse_diff(diff);
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0].path(), "a.txt");
+        // The blank line is context whose leading space was stripped
+        assert_eq!(files[0].hunks[0].lines.len(), 3);
+        assert_eq!(files[1].hunks[0].old_start, 5);
+        assert_eq!(files[1].hunks[0].lines[1].content, "y");
+    }
+
+    #[test]
+    fn parses_quoted_git_paths() {
+        let files = parse_diff("diff --git \"a/with \\\"quote\" \"b/with \\\"quote\"\n");
+        assert_eq!(files[0].path(), "with \"quote");
+    }
+
+    #[test]
+    fn decodes_octal_and_control_escapes_in_quoted_paths() {
+        let files = parse_diff(concat!(
+            "diff --git \"a/caf\\303\\251.rs\" \"b/caf\\303\\251.rs\"\n",
+            "--- \"a/caf\\303\\251.rs\"\n",
+            "+++ \"b/caf\\303\\251.rs\"\n",
+        ));
+        assert_eq!(files[0].path(), "café.rs");
+        assert_eq!(unquote(r#""tab\there\nnew\\line""#), "tab\there\nnew\\line");
+        assert_eq!(unquote(r#""bell\a\b\v\f\r""#), "bell\u{7}\u{8}\u{b}\u{c}\r");
+        assert_eq!(unquote(r#""bad\377""#), "bad\u{fffd}");
+        assert_eq!(unquote("plain/path.rs"), "plain/path.rs");
+    }
+
+    #[test]
+    fn ignores_text_without_files() {
+        assert!(parse_diff("").is_empty());
+        assert!(parse_diff("just a commit message\n").is_empty());
+    }
+}
@@ -2,59 +2,16 @@
 
 use dioxus::prelude::*;
 
+use super::code_block::Language;
 use super::file_icons;
 
 stylance::import_style!(style, "file_path.module.scss");
 
-/// Get the file extension from a path
-fn get_extension(path: &str) -> Option<&str> {
-    path.rsplit('.').next().filter(|ext| !ext.contains('/'))
-}
-
-/// Map file extension to icon type
-fn ext_to_icon_type(ext: &str) -> &'static str {
-    match ext.to_lowercase().as_str() {
-        "rs" => "rust",
-        "ts" | "mts" | "cts" | "tsx" => "typescript",
-        "js" | "mjs" | "cjs" | "jsx" => "javascript",
-        "py" | "pyw" | "pyi" => "python",
-        "c" | "h" => "c",
-        "cpp" | "hpp" | "cc" | "hh" | "cxx" | "hxx" => "cpp",
-        "go" => "go",
-        "java" => "java",
-        "kt" | "kts" => "kotlin",
-        "rb" | "erb" => "ruby",
-        "php" => "php",
-        "swift" => "swift",
-        "cs" => "csharp",
-        "lua" => "lua",
-        "pl" | "pm" => "perl",
-        "r" => "r",
-        "scala" | "sc" => "scala",
-        "ex" | "exs" => "elixir",
-        "hs" | "lhs" => "haskell",
-        "clj" | "cljs" | "cljc" | "edn" => "clojure",
-        "zig" => "zig",
-        "json" => "json",
-        "yaml" | "yml" => "yaml",
-        "toml" => "toml",
-        "xml" | "svg" => "xml",
-        "sql" => "sql",
-        "html" | "htm" => "html",
-        "css" => "css",
-        "scss" | "sass" => "scss",
-        "md" | "mdx" | "markdown" => "markdown",
-        "sh" | "bash" | "zsh" | "fish" => "bash",
-        "gitignore" | "gitattributes" | "gitmodules" => "git",
-        "j2" | "jinja" | "jinja2" => "jinja",
-        _ => "file",
-    }
-}
-
-/// Returns the appropriate icon SVG based on file extension
-fn get_file_icon_svg(ext: &str) -> &'static str {
-    let icon_type = ext_to_icon_type(ext);
-    file_icons::get_icon_svg(icon_type).unwrap_or_else(|| file_icons::get_icon_svg("file").unwrap())
+/// Returns the icon SVG for a path, based on its detected language
+pub(crate) fn file_icon_svg(path: &str) -> &'static str {
+    Language::from_filename(path)
+        .and_then(|language| file_icons::get_icon_svg(language.as_str()))
+        .unwrap_or_else(|| file_icons::get_icon_svg("file").unwrap())
 }
 
 /// Inline file path display with optional line number and file type icon
@@ -97,8 +54,7 @@ pub fn FilePath(
         style::file_path.to_string()
     };
 
-    let ext = get_extension(&path).unwrap_or("").to_string();
-    let icon_svg = get_file_icon_svg(&ext);
+    let icon_svg = file_icon_svg(&path);
 
     rsx! {
         span { class: class, onclick: handle_click,
@@ -0,0 +1,450 @@
+//! `FileTree` component for browsing files and directories
+
+use std::collections::{HashMap, HashSet};
+
+use dioxus::prelude::*;
+
+use super::file_path::file_icon_svg;
+use crate::{
+    DiffFileStatus, DiffStats, IconChevronRight, IconFolder, IconFolderOpen, Spinner, SpinnerSize,
+};
+
+stylance::import_style!(style, "file_tree.module.scss");
+
+/// A file or directory in a [`FileTree`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileTreeNode {
+    /// Display name (last path segment)
+    pub name: String,
+    /// Full path, used for selection, expansion and decorations
+    pub path: String,
+    /// Whether this is a directory
+    pub is_dir: bool,
+    /// Directory contents, or `None` for files and for directories whose
+    /// children haven't been loaded yet
+    pub children: Option<Vec<FileTreeNode>>,
+}
+
+impl FileTreeNode {
+    fn name_of(path: &str) -> String {
+        path.trim_end_matches('/')
+            .rsplit('/')
+            .next()
+            .unwrap_or(path)
+            .to_string()
+    }
+
+    /// Create a file node
+    #[must_use]
+    pub fn file(path: impl Into<String>) -> Self {
+        let path = path.into();
+        Self {
+            name: Self::name_of(&path),
+            path,
+            is_dir: false,
+            children: None,
+        }
+    }
+
+    /// Create a directory node with known children
+    #[must_use]
+    pub fn dir(path: impl Into<String>, children: Vec<FileTreeNode>) -> Self {
+        let path = path.into();
+        Self {
+            name: Self::name_of(&path),
+            path,
+            is_dir: true,
+            children: Some(children),
+        }
+    }
+
+    /// Create a directory node whose children are loaded when it's first expanded
+    #[must_use]
+    pub fn lazy_dir(path: impl Into<String>) -> Self {
+        let path = path.into();
+        Self {
+            name: Self::name_of(&path),
+            path,
+            is_dir: true,
+            children: None,
+        }
+    }
+
+    /// Build a tree from a flat list of paths
+    ///
+    /// Intermediate directories are created as needed, and a path ending with
+    /// `/` is an (empty) directory. Directories sort before files, then by name.
+    #[must_use]
+    pub fn from_paths<S: AsRef<str>>(paths: impl IntoIterator<Item = S>) -> Vec<FileTreeNode> {
+        let mut roots = Vec::new();
+        for path in paths {
+            let path = path.as_ref().trim();
+            let is_dir = path.ends_with('/');
+            let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+            if !segments.is_empty() {
+                insert_path(&mut roots, &segments, 0, is_dir);
+            }
+        }
+        sort_nodes(&mut roots);
+        roots
+    }
+}
+
+fn insert_path(nodes: &mut Vec<FileTreeNode>, segments: &[&str], depth: usize, is_dir: bool) {
+    let path = segments[..=depth].join("/");
+    let is_last = depth + 1 == segments.len();
+    let existing = nodes.iter().position(|node| node.path == path);
+
+    if is_last {
+        if existing.is_none() {
+            nodes.push(if is_dir {
+                FileTreeNode::dir(path, Vec::new())
+            } else {
+                FileTreeNode::file(path)
+            });
+        }
+        return;
+    }
+
+    let idx = existing.unwrap_or_else(|| {
+        nodes.push(FileTreeNode::dir(path, Vec::new()));
+        nodes.len() - 1
+    });
+    let node = &mut nodes[idx];
+    node.is_dir = true;
+    insert_path(
+        node.children.get_or_insert_with(Vec::new),
+        segments,
+        depth + 1,
+        is_dir,
+    );
+}
+
+fn sort_nodes(nodes: &mut [FileTreeNode]) {
+    nodes.sort_by(|a, b| {
+        b.is_dir
+            .cmp(&a.is_dir)
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+    });
+    for node in nodes {
+        if let Some(children) = &mut node.children {
+            sort_nodes(children);
+        }
+    }
+}
+
+fn collect_dirs(nodes: &[FileTreeNode], out: &mut HashSet<String>) {
+    for node in nodes.iter().filter(|node| node.is_dir) {
+        out.insert(node.path.clone());
+        if let Some(children) = &node.children {
+            collect_dirs(children, out);
+        }
+    }
+}
+
+/// Per-file decorations shown at the end of a row
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FileDecoration {
+    /// Status letter (`A`, `M`, `D`...)
+    pub status: Option<DiffFileStatus>,
+    /// Lines added, shown with [`DiffStats`]
+    pub additions: usize,
+    /// Lines deleted, shown with [`DiffStats`]
+    pub deletions: usize,
+}
+
+impl FileDecoration {
+    /// Decoration with a status letter
+    #[must_use]
+    pub const fn status(status: DiffFileStatus) -> Self {
+        Self {
+            status: Some(status),
+            additions: 0,
+            deletions: 0,
+        }
+    }
+
+    /// Decoration with added/deleted line counts
+    #[must_use]
+    pub const fn diff(additions: usize, deletions: usize) -> Self {
+        Self {
+            status: None,
+            additions,
+            deletions,
+        }
+    }
+
+    /// Add line counts to a decoration
+    #[must_use]
+    pub const fn with_diff(mut self, additions: usize, deletions: usize) -> Self {
+        self.additions = additions;
+        self.deletions = deletions;
+        self
+    }
+}
+
+/// A row of the flattened, currently visible tree
+#[derive(Clone, PartialEq)]
+struct VisibleRow {
+    name: String,
+    path: String,
+    depth: usize,
+    is_dir: bool,
+    expanded: bool,
+    /// Expanded, but children not loaded yet
+    loading: bool,
+    parent: Option<String>,
+}
+
+fn visible_rows(
+    nodes: &[FileTreeNode],
+    expanded: &HashSet<String>,
+    depth: usize,
+    parent: Option<&str>,
+    out: &mut Vec<VisibleRow>,
+) {
+    for node in nodes {
+        let is_expanded = node.is_dir && expanded.contains(&node.path);
+        out.push(VisibleRow {
+            name: node.name.clone(),
+            path: node.path.clone(),
+            depth,
+            is_dir: node.is_dir,
+            expanded: is_expanded,
+            loading: is_expanded && node.children.is_none(),
+            parent: parent.map(str::to_string),
+        });
+        if is_expanded && let Some(children) = &node.children {
+            visible_rows(children, expanded, depth + 1, Some(&node.path), out);
+        }
+    }
+}
+
+const fn status_class(status: DiffFileStatus) -> &'static str {
+    match status {
+        DiffFileStatus::Added => style::added,
+        DiffFileStatus::Deleted => style::deleted,
+        DiffFileStatus::Modified => style::modified,
+        DiffFileStatus::Renamed | DiffFileStatus::Copied => style::renamed,
+    }
+}
+
+/// Tree view of files and directories
+///
+/// Pass either a flat list of `paths` or pre-built `nodes`. Directories expand
+/// and collapse on click; the tree supports arrow-key navigation (Up/Down to
+/// move, Right/Left to expand, collapse or jump to the parent, Enter or Space to
+/// select). Directories created with [`FileTreeNode::lazy_dir`] show a loading
+/// row until their children are provided, typically from `on_expand`.
+#[component]
+pub fn FileTree(
+    /// Flat list of paths, used when `nodes` is empty
+    #[props(default)]
+    paths: Vec<String>,
+    /// Pre-built tree
+    #[props(default)]
+    nodes: Vec<FileTreeNode>,
+    /// Decorations by path
+    #[props(default)]
+    decorations: HashMap<String, FileDecoration>,
+    /// Path selected initially
+    #[props(default)]
+    default_selected: Option<String>,
+    /// Whether directories start expanded
+    #[props(default = false)]
+    default_expanded: bool,
+    /// Called with the path of a selected file or directory
+    on_select: Option<EventHandler<String>>,
+    /// Called with the path of a directory when it's expanded
+    on_expand: Option<EventHandler<String>>,
+    /// Additional CSS class
+    #[props(default)]
+    class: Option<String>,
+) -> Element {
+    let roots = if nodes.is_empty() {
+        FileTreeNode::from_paths(&paths)
+    } else {
+        nodes
+    };
+
+    let mut expanded = use_signal(|| {
+        let mut dirs = HashSet::new();
+        if default_expanded {
+            collect_dirs(&roots, &mut dirs);
+        }
+        dirs
+    });
+    let mut selected = use_signal(|| default_selected.clone());
+    let mut focused = use_signal(|| default_selected.clone());
+
+    let mut rows = Vec::new();
+    visible_rows(&roots, &expanded.read(), 0, None, &mut rows);
+
+    let mut set_expanded = move |path: &str, open: bool| {
+        if open {
+            if expanded.write().insert(path.to_string())
+                && let Some(handler) = &on_expand
+            {
+                handler.call(path.to_string());
+            }
+        } else {
+            expanded.write().remove(path);
+        }
+    };
+
+    let mut select = move |path: &str| {
+        selected.set(Some(path.to_string()));
+        focused.set(Some(path.to_string()));
+        if let Some(handler) = &on_select {
+            handler.call(path.to_string());
+        }
+    };
+
+    let keyboard_rows = rows.clone();
+    let handle_keydown = move |evt: KeyboardEvent| {
+        let rows = &keyboard_rows;
+        if rows.is_empty() {
+            return;
+        }
+        let current = focused
+            .peek()
+            .as_ref()
+            .and_then(|path| rows.iter().position(|row| &row.path == path));
+        let idx = current.unwrap_or(0);
+        let row = &rows[idx];
+        let mut focus = |idx: usize| focused.set(Some(rows[idx].path.clone()));
+
+        match evt.key() {
+            Key::ArrowDown => {
+                evt.prevent_default();
+                focus(current.map_or(0, |idx| (idx + 1).min(rows.len() - 1)));
+            }
+            Key::ArrowUp => {
+                evt.prevent_default();
+                focus(idx.saturating_sub(1));
+            }
+            Key::Home => {
+                evt.prevent_default();
+                focus(0);
+            }
+            Key::End => {
+                evt.prevent_default();
+                focus(rows.len() - 1);
+            }
+            Key::ArrowRight if row.is_dir => {
+                evt.prevent_default();
+                if !row.expanded {
+                    set_expanded(&row.path, true);
+                } else if rows.get(idx + 1).is_some_and(|next| next.depth > row.depth) {
+                    focus(idx + 1);
+                }
+            }
+            Key::ArrowLeft => {
+                evt.prevent_default();
+                if row.is_dir && row.expanded {
+                    set_expanded(&row.path, false);
+                } else if let Some(parent) = &row.parent
+                    && let Some(parent_idx) = rows.iter().position(|r| &r.path == parent)
+                {
+                    focus(parent_idx);
+                }
+            }
+            Key::Enter => {
+                evt.prevent_default();
+                select(&row.path);
+                if row.is_dir {
+                    set_expanded(&row.path, !row.expanded);
+                }
+            }
+            Key::Character(ref c) if c == " " => {
+                evt.prevent_default();
+                select(&row.path);
+                if row.is_dir {
+                    set_expanded(&row.path, !row.expanded);
+                }
+            }
+            _ => {}
+        }
+    };
+
+    rsx! {
+        div {
+            class: stylance::classes!(style::file_tree, class.as_deref().unwrap_or("")),
+            role: "tree",
+            tabindex: "0",
+            onkeydown: handle_keydown,
+            for row in rows {
+                {
+                    let is_selected = selected.read().as_ref() == Some(&row.path);
+                    let is_focused = focused.read().as_ref() == Some(&row.path);
+                    let decoration = decorations.get(&row.path).copied();
+                    let indent = format!("--file-tree-depth: {}", row.depth);
+                    let path = row.path.clone();
+                    let (is_dir, is_expanded) = (row.is_dir, row.expanded);
+                    rsx! {
+                        div {
+                            key: "{row.path}",
+                            class: stylance::classes!(
+                                style::row,
+                                if is_selected { style::selected } else { "" },
+                                if is_focused { style::focused } else { "" }
+                            ),
+                            style: "{indent}",
+                            role: "treeitem",
+                            aria_level: "{row.depth + 1}",
+                            aria_selected: "{is_selected}",
+                            aria_expanded: if is_dir { Some(is_expanded.to_string()) } else { None },
+                            onclick: move |_| {
+                                select(&path);
+                                if is_dir {
+                                    set_expanded(&path, !is_expanded);
+                                }
+                            },
+                            span { class: stylance::classes!(style::chevron, if is_expanded { style::open } else { "" }),
+                                if is_dir {
+                                    IconChevronRight {}
+                                }
+                            }
+                            if is_dir {
+                                span { class: stylance::classes!(style::icon, style::folder),
+                                    if is_expanded {
+                                        IconFolderOpen {}
+                                    } else {
+                                        IconFolder {}
+                                    }
+                                }
+                            } else {
+                                span { class: style::icon, dangerous_inner_html: file_icon_svg(&row.name) }
+                            }
+                            span { class: style::name, "{row.name}" }
+                            if let Some(decoration) = decoration {
+                                span { class: style::decorations,
+                                    if decoration.additions + decoration.deletions > 0 {
+                                        DiffStats { additions: decoration.additions, deletions: decoration.deletions }
+                                    }
+                                    if let Some(status) = decoration.status {
+                                        span {
+                                            class: stylance::classes!(style::status, status_class(status)),
+                                            title: status.as_str(),
+                                            "{status.letter()}"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        if row.loading {
+                            div {
+                                key: "{row.path}/loading",
+                                class: stylance::classes!(style::row, style::loading),
+                                style: "--file-tree-depth: {row.depth + 1}",
+                                span { class: style::chevron }
+                                Spinner { size: SpinnerSize::Small }
+                                span { class: style::name, "Loading…" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
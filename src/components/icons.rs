@@ -22,20 +22,30 @@ macro_rules! icon {
 
 // Icons used by glade components
 icon!(IconAlertCircle, "circle-alert.svg");
+icon!(IconArrowDownToLine, "arrow-down-to-line.svg");
+icon!(IconArrowLeftToLine, "arrow-left-to-line.svg");
+icon!(IconArrowRightToLine, "arrow-right-to-line.svg");
+icon!(IconCalendar, "calendar.svg");
 icon!(IconCheck, "check.svg");
 icon!(IconChevronDown, "chevron-down.svg");
 icon!(IconChevronLeft, "chevron-left.svg");
 icon!(IconChevronRight, "chevron-right.svg");
+icon!(IconChevronUp, "chevron-up.svg");
+icon!(IconColumns3, "columns-3.svg");
+icon!(IconDownload, "download.svg");
 icon!(IconCircleCheck, "circle-check.svg");
 icon!(IconCircleX, "circle-x.svg");
 icon!(IconExternalLink, "external-link.svg");
 icon!(IconFilter, "filter.svg");
+icon!(IconFolder, "folder.svg");
+icon!(IconFolderOpen, "folder-open.svg");
 icon!(IconInfo, "info.svg");
 icon!(IconLoader, "loader.svg");
 icon!(IconMenu, "menu.svg");
 icon!(IconMinus, "minus.svg");
 icon!(IconPlus, "plus.svg");
 icon!(IconSearch, "search.svg");
+icon!(IconTextWrap, "text-wrap.svg");
 icon!(IconTriangleAlert, "triangle-alert.svg");
 icon!(IconX, "x.svg");
 
@@ -65,3 +75,5 @@ icon!(IconMic, "mic.svg");
 icon!(IconServer, "server.svg");
 icon!(IconUser, "user.svg");
 icon!(IconYoutube, "youtube.svg");
+icon!(IconZoomIn, "zoom-in.svg");
+icon!(IconZoomOut, "zoom-out.svg");
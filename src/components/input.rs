@@ -35,6 +35,10 @@ pub fn Input(
     oninput: Option<EventHandler<FormEvent>>,
     /// Called when the clear button is clicked
     onclear: Option<EventHandler<()>>,
+    /// Called when the field loses focus
+    onblur: Option<EventHandler<FocusEvent>>,
+    /// Called with the native element once mounted, e.g. to focus it
+    onmounted: Option<EventHandler<MountedEvent>>,
 ) -> Element {
     let size_class = match size {
         InputSize::Small => style::small,
@@ -73,6 +77,16 @@ pub fn Input(
                             handler.call(evt);
                         }
                     },
+                    onblur: move |evt| {
+                        if let Some(handler) = &onblur {
+                            handler.call(evt);
+                        }
+                    },
+                    onmounted: move |evt| {
+                        if let Some(handler) = &onmounted {
+                            handler.call(evt);
+                        }
+                    },
                 }
                 if clearable {
                     button {
@@ -0,0 +1,606 @@
+//! `JsonTree` component for inspecting JSON documents
+
+use std::collections::{HashMap, HashSet};
+
+use dioxus::prelude::*;
+use serde_json::Value;
+
+use crate::utils::write_clipboard;
+use crate::{
+    Button, ButtonSize, ButtonVariant, IconButton, IconButtonSize, IconChevronRight, IconMinus,
+    IconPlus, IconSearch, Input, InputSize,
+};
+
+stylance::import_style!(style, "json_tree.module.scss");
+
+/// Path of a node as a JavaScript-style accessor (`$.items[0]["content-type"]`)
+fn child_path(parent: &str, key: &str) -> String {
+    let plain = key.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if plain {
+        format!("{parent}.{key}")
+    } else {
+        format!("{parent}[{}]", Value::from(key))
+    }
+}
+
+/// JSON pointer of a child node, used to look values up and as a stable id
+fn child_pointer(parent: &str, key: &str) -> String {
+    format!("{parent}/{}", key.replace('~', "~0").replace('/', "~1"))
+}
+
+/// Number of direct children of a container, as shown when it is collapsed
+fn summary(value: &Value) -> String {
+    match value {
+        Value::Object(map) if map.len() == 1 => "1 key".to_string(),
+        Value::Object(map) => format!("{} keys", map.len()),
+        Value::Array(items) if items.len() == 1 => "1 item".to_string(),
+        Value::Array(items) => format!("{} items", items.len()),
+        _ => String::new(),
+    }
+}
+
+/// Nesting depth of the deepest container in a document
+fn max_depth(value: &Value) -> usize {
+    match value {
+        Value::Object(map) => 1 + map.values().map(max_depth).max().unwrap_or(0),
+        Value::Array(items) => 1 + items.iter().map(max_depth).max().unwrap_or(0),
+        _ => 0,
+    }
+}
+
+/// Scalar value as JSON text, with the class used to color it
+fn scalar(value: &Value) -> (String, &'static str) {
+    match value {
+        Value::String(_) => (value.to_string(), style::string),
+        Value::Number(n) => (n.to_string(), style::number),
+        Value::Bool(b) => (b.to_string(), style::boolean),
+        Value::Null => ("null".to_string(), style::null),
+        Value::Array(_) => ("[]".to_string(), style::punctuation),
+        Value::Object(_) => ("{}".to_string(), style::punctuation),
+    }
+}
+
+/// Nodes matching a search, and every container on the way to them
+#[derive(Default, PartialEq)]
+struct SearchResult {
+    /// Pointers of nodes whose key or value contains the query
+    matched: HashSet<String>,
+    /// Pointers of containers with a match somewhere below
+    ancestors: HashSet<String>,
+}
+
+impl SearchResult {
+    /// Search `value` for `query` (already lowercased); returns whether anything matched
+    fn collect(&mut self, value: &Value, key: Option<&str>, pointer: &str, query: &str) -> bool {
+        let mut found = false;
+        if key.is_some_and(|k| k.to_lowercase().contains(query)) {
+            found = true;
+        }
+        match value {
+            Value::Object(map) => {
+                for (k, v) in map {
+                    let child = child_pointer(pointer, k);
+                    if self.collect(v, Some(k), &child, query) {
+                        self.ancestors.insert(pointer.to_string());
+                    }
+                }
+            }
+            Value::Array(items) => {
+                for (i, v) in items.iter().enumerate() {
+                    let child = child_pointer(pointer, &i.to_string());
+                    if self.collect(v, None, &child, query) {
+                        self.ancestors.insert(pointer.to_string());
+                    }
+                }
+            }
+            _ => found |= scalar(value).0.to_lowercase().contains(query),
+        }
+        if found {
+            self.matched.insert(pointer.to_string());
+        }
+        found || self.ancestors.contains(pointer)
+    }
+}
+
+#[derive(Clone, PartialEq)]
+enum RowKind {
+    /// A string, number, boolean, null or empty container
+    Scalar { text: String, class: &'static str },
+    /// The opening line of a non-empty object or array
+    Open {
+        open: &'static str,
+        close: &'static str,
+        summary: String,
+        expanded: bool,
+    },
+    /// The closing bracket of an expanded container
+    Close { close: &'static str },
+    /// Placeholder for the children hidden by truncation
+    More { remaining: usize },
+}
+
+/// How a node is reached from its parent
+#[derive(Clone, PartialEq)]
+enum Key {
+    Name(String),
+    Index(usize),
+}
+
+/// A visible line of the tree
+#[derive(Clone, PartialEq)]
+struct JsonRow {
+    /// JSON pointer of the node (of the parent for `More` rows)
+    pointer: String,
+    /// Accessor path of the node, for copying
+    path: String,
+    depth: usize,
+    /// Object key or array index, absent for the root and closing rows
+    key: Option<Key>,
+    kind: RowKind,
+}
+
+/// Everything that decides which rows are visible
+struct View<'a> {
+    expand_depth: usize,
+    overrides: &'a HashMap<String, bool>,
+    limits: &'a HashMap<String, usize>,
+    max_items: usize,
+    search: Option<&'a SearchResult>,
+}
+
+impl View<'_> {
+    fn is_expanded(&self, pointer: &str, depth: usize) -> bool {
+        if let Some(&expanded) = self.overrides.get(pointer) {
+            return expanded;
+        }
+        match self.search {
+            Some(search) => search.ancestors.contains(pointer),
+            None => depth < self.expand_depth,
+        }
+    }
+
+    /// Whether a child belongs in the tree while searching
+    fn is_relevant(&self, parent: &str, child: &str) -> bool {
+        self.search.is_none_or(|search| {
+            search.matched.contains(parent)
+                || search.matched.contains(child)
+                || search.ancestors.contains(child)
+        })
+    }
+
+    fn flatten(&self, value: &Value) -> Vec<JsonRow> {
+        let mut rows = Vec::new();
+        self.push(&mut rows, value, None, String::new(), "$".to_string(), 0);
+        rows
+    }
+
+    fn push(
+        &self,
+        rows: &mut Vec<JsonRow>,
+        value: &Value,
+        key: Option<Key>,
+        pointer: String,
+        path: String,
+        depth: usize,
+    ) {
+        let (open, close) = match value {
+            Value::Object(map) if !map.is_empty() => ("{", "}"),
+            Value::Array(items) if !items.is_empty() => ("[", "]"),
+            _ => {
+                let (text, class) = scalar(value);
+                rows.push(JsonRow {
+                    pointer,
+                    path,
+                    depth,
+                    key,
+                    kind: RowKind::Scalar { text, class },
+                });
+                return;
+            }
+        };
+
+        let expanded = self.is_expanded(&pointer, depth);
+        rows.push(JsonRow {
+            pointer: pointer.clone(),
+            path: path.clone(),
+            depth,
+            key,
+            kind: RowKind::Open {
+                open,
+                close,
+                summary: summary(value),
+                expanded,
+            },
+        });
+        if !expanded {
+            return;
+        }
+
+        let children: Vec<(Key, &Value)> = match value {
+            Value::Object(map) => map.iter().map(|(k, v)| (Key::Name(k.clone()), v)).collect(),
+            Value::Array(items) => items
+                .iter()
+                .enumerate()
+                .map(|(i, v)| (Key::Index(i), v))
+                .collect(),
+            _ => Vec::new(),
+        };
+        let children: Vec<_> = children
+            .into_iter()
+            .map(|(key, v)| {
+                let child = match &key {
+                    Key::Name(name) => child_pointer(&pointer, name),
+                    Key::Index(i) => child_pointer(&pointer, &i.to_string()),
+                };
+                (child, key, v)
+            })
+            .filter(|(child, ..)| self.is_relevant(&pointer, child))
+            .collect();
+        let total = children.len();
+
+        let limit = self.limits.get(&pointer).copied().unwrap_or(self.max_items);
+        for (child, key, v) in children.into_iter().take(limit) {
+            let child_path = match &key {
+                Key::Name(name) => child_path(&path, name),
+                Key::Index(i) => format!("{path}[{i}]"),
+            };
+            self.push(rows, v, Some(key), child, child_path, depth + 1);
+        }
+        if total > limit {
+            rows.push(JsonRow {
+                pointer: pointer.clone(),
+                path: path.clone(),
+                depth: depth + 1,
+                key: None,
+                kind: RowKind::More {
+                    remaining: total - limit,
+                },
+            });
+        }
+        rows.push(JsonRow {
+            pointer,
+            path,
+            depth,
+            key: None,
+            kind: RowKind::Close { close },
+        });
+    }
+}
+
+/// Split text into runs, flagging those that match `query` (lowercased)
+fn highlight(text: &str, query: &str) -> Vec<(String, bool)> {
+    if query.is_empty() {
+        return vec![(text.to_string(), false)];
+    }
+    // Lowercasing can change a char's byte length, so remember which original
+    // char each lowercased byte came from
+    let mut lower = String::with_capacity(text.len());
+    let mut spans = Vec::with_capacity(text.len());
+    for (at, c) in text.char_indices() {
+        let before = lower.len();
+        lower.extend(c.to_lowercase());
+        spans.extend((before..lower.len()).map(|_| (at, at + c.len_utf8())));
+    }
+    let mut runs = Vec::new();
+    let mut pos = 0;
+    let mut from = 0;
+    while let Some(found) = lower[from..].find(query) {
+        let start = from + found;
+        from = start + query.len();
+        // A match covers every original char it touches
+        let hit_start = spans[start].0.max(pos);
+        let hit_end = spans[from - 1].1;
+        if hit_end <= hit_start {
+            continue;
+        }
+        if hit_start > pos {
+            runs.push((text[pos..hit_start].to_string(), false));
+        }
+        runs.push((text[hit_start..hit_end].to_string(), true));
+        pos = hit_end;
+    }
+    if pos < text.len() {
+        runs.push((text[pos..].to_string(), false));
+    }
+    runs
+}
+
+/// Collapsible tree view of a JSON document
+///
+/// Values are colored by type. Containers deeper than `expand_depth` start
+/// collapsed; the toolbar changes the depth for the whole tree and search
+/// keeps only the branches whose keys or values match. Arrays and objects
+/// with more than `max_items` children are truncated behind a "show more" row.
+/// Hovering a row offers copying its path (`$.items[0].id`) or its value.
+#[component]
+pub fn JsonTree(
+    /// The document to display
+    value: ReadSignal<Value>,
+    /// Number of levels expanded initially
+    #[props(default = 2)]
+    expand_depth: usize,
+    /// Children shown per container before truncating
+    #[props(default = 100)]
+    max_items: usize,
+    /// Show the search and depth toolbar
+    #[props(default = true)]
+    show_toolbar: bool,
+    /// Additional CSS class
+    #[props(default)]
+    class: Option<String>,
+) -> Element {
+    let mut depth = use_signal(|| expand_depth);
+    let mut overrides: Signal<HashMap<String, bool>> = use_signal(HashMap::new);
+    let mut limits: Signal<HashMap<String, usize>> = use_signal(HashMap::new);
+    let mut query = use_signal(String::new);
+    // Needs `mut` for wasm32 (where the reset timer runs) but not server
+    #[allow(unused_mut)]
+    let mut copied: Signal<Option<(String, bool)>> = use_signal(|| None);
+
+    let query_lower = query.read().trim().to_lowercase();
+    let search = use_memo(move || {
+        let query = query.read().trim().to_lowercase();
+        if query.is_empty() {
+            return None;
+        }
+        let mut result = SearchResult::default();
+        result.collect(&value.read(), None, "", &query);
+        Some(result)
+    });
+
+    let rows = {
+        let search = search.read();
+        View {
+            expand_depth: depth(),
+            overrides: &overrides.read(),
+            limits: &limits.read(),
+            max_items: max_items.max(1),
+            search: search.as_ref(),
+        }
+        .flatten(&value.read())
+    };
+    let no_matches = search.read().as_ref().is_some_and(|s| s.matched.is_empty());
+    let depth_label = if depth() == usize::MAX {
+        "All".to_string()
+    } else {
+        depth().to_string()
+    };
+
+    let mut set_depth = move |new_depth: usize| {
+        depth.set(new_depth);
+        overrides.write().clear();
+    };
+
+    let mut copy = move |pointer: String, text: String, is_path: bool| {
+        write_clipboard(&text);
+        copied.set(Some((pointer, is_path)));
+        #[cfg(target_arch = "wasm32")]
+        spawn(async move {
+            gloo_timers::future::TimeoutFuture::new(1_500).await;
+            copied.set(None);
+        });
+    };
+
+    rsx! {
+        div { class: stylance::classes!(style::json_tree, class.as_deref().unwrap_or("")),
+            if show_toolbar {
+                div { class: style::toolbar,
+                    div { class: style::search,
+                        Input {
+                            size: InputSize::Small,
+                            placeholder: "Search keys and values",
+                            value: query(),
+                            clearable: true,
+                            icon: rsx! { IconSearch {} },
+                            oninput: move |evt: FormEvent| {
+                                query.set(evt.value());
+                                overrides.write().clear();
+                            },
+                            onclear: move |_| query.set(String::new()),
+                        }
+                    }
+                    span { class: style::spacer }
+                    span { class: style::depth_label, "Depth {depth_label}" }
+                    IconButton {
+                        size: IconButtonSize::Small,
+                        aria_label: "Collapse one level",
+                        disabled: depth() == 0,
+                        onclick: move |_| {
+                            let current = depth();
+                            let deepest = max_depth(&value.read());
+                            set_depth(current.min(deepest + 1).saturating_sub(1));
+                        },
+                        IconMinus {}
+                    }
+                    IconButton {
+                        size: IconButtonSize::Small,
+                        aria_label: "Expand one level",
+                        disabled: depth() == usize::MAX,
+                        onclick: move |_| set_depth(depth().saturating_add(1)),
+                        IconPlus {}
+                    }
+                    Button {
+                        variant: ButtonVariant::Ghost,
+                        size: ButtonSize::Small,
+                        onclick: move |_| set_depth(usize::MAX),
+                        "Expand all"
+                    }
+                }
+            }
+            div { class: style::rows, role: "tree",
+                if no_matches {
+                    div { class: style::empty, "No matches" }
+                }
+                for (index, row) in rows.into_iter().enumerate() {
+                    {
+                        let matched = search
+                            .read()
+                            .as_ref()
+                            .is_some_and(|s| s.matched.contains(&row.pointer));
+                        let pointer = row.pointer.clone();
+                        let path = row.path.clone();
+                        let copied_here = copied().filter(|(p, _)| *p == row.pointer).map(|(_, is_path)| is_path);
+                        let show_actions = !matches!(row.kind, RowKind::Close { .. } | RowKind::More { .. });
+                        rsx! {
+                            div {
+                                key: "{index}-{row.pointer}",
+                                class: stylance::classes!(style::row, if matched { style::matched } else { "" }),
+                                style: "--json-tree-depth: {row.depth}",
+                                role: "treeitem",
+                                match &row.kind {
+                                    RowKind::Open { expanded, .. } => {
+                                        let pointer = row.pointer.clone();
+                                        let expanded = *expanded;
+                                        rsx! {
+                                            button {
+                                                class: stylance::classes!(style::chevron, if expanded { style::open } else { "" }),
+                                                aria_label: if expanded { "Collapse" } else { "Expand" },
+                                                onclick: move |_| {
+                                                    overrides.write().insert(pointer.clone(), !expanded);
+                                                },
+                                                IconChevronRight {}
+                                            }
+                                        }
+                                    }
+                                    _ => rsx! { span { class: style::chevron_spacer } },
+                                }
+                                match &row.key {
+                                    Some(Key::Name(name)) => rsx! {
+                                        span { class: style::key,
+                                            for (run, hit) in highlight(name, &query_lower) {
+                                                if hit {
+                                                    mark { class: style::mark, "{run}" }
+                                                } else {
+                                                    "{run}"
+                                                }
+                                            }
+                                        }
+                                        span { class: style::punctuation, ": " }
+                                    },
+                                    Some(Key::Index(i)) => rsx! {
+                                        span { class: stylance::classes!(style::key, style::index), "{i}" }
+                                        span { class: style::punctuation, ": " }
+                                    },
+                                    None => rsx! {},
+                                }
+                                match &row.kind {
+                                    RowKind::Scalar { text, class } => rsx! {
+                                        span { class: *class,
+                                            for (run, hit) in highlight(text, &query_lower) {
+                                                if hit {
+                                                    mark { class: style::mark, "{run}" }
+                                                } else {
+                                                    "{run}"
+                                                }
+                                            }
+                                        }
+                                    },
+                                    RowKind::Open { open, close, summary, expanded } => {
+                                        let pointer = row.pointer.clone();
+                                        let expanded = *expanded;
+                                        rsx! {
+                                            span { class: style::punctuation, "{open}" }
+                                            if !expanded {
+                                                button {
+                                                    class: style::collapsed,
+                                                    onclick: move |_| {
+                                                        overrides.write().insert(pointer.clone(), true);
+                                                    },
+                                                    "…"
+                                                }
+                                                span { class: style::punctuation, "{close}" }
+                                                span { class: style::summary, "{summary}" }
+                                            }
+                                        }
+                                    }
+                                    RowKind::Close { close } => rsx! {
+                                        span { class: style::punctuation, "{close}" }
+                                    },
+                                    RowKind::More { remaining } => {
+                                        let pointer = row.pointer.clone();
+                                        let step = max_items.max(1);
+                                        let shown = (*remaining).min(step);
+                                        rsx! {
+                                            button {
+                                                class: style::more,
+                                                onclick: move |_| {
+                                                    let mut limits = limits.write();
+                                                    let limit = limits.entry(pointer.clone()).or_insert(step);
+                                                    *limit += step;
+                                                },
+                                                "Show {shown} more of {remaining}"
+                                            }
+                                        }
+                                    }
+                                }
+                                if show_actions {
+                                    span { class: style::actions,
+                                        button {
+                                            class: style::action,
+                                            onclick: {
+                                                let pointer = pointer.clone();
+                                                move |_| copy(pointer.clone(), path.clone(), true)
+                                            },
+                                            if copied_here == Some(true) { "Copied" } else { "Copy path" }
+                                        }
+                                        button {
+                                            class: style::action,
+                                            onclick: move |_| {
+                                                let text = value
+                                                    .read()
+                                                    .pointer(&pointer)
+                                                    .and_then(|v| serde_json::to_string_pretty(v).ok())
+                                                    .unwrap_or_default();
+                                                copy(pointer.clone(), text, false);
+                                            },
+                                            if copied_here == Some(false) { "Copied" } else { "Copy value" }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hits(text: &str, query: &str) -> Vec<String> {
+        highlight(text, query)
+            .into_iter()
+            .filter(|(_, hit)| *hit)
+            .map(|(run, _)| run)
+            .collect()
+    }
+
+    #[test]
+    fn highlight_is_case_insensitive() {
+        assert_eq!(
+            highlight("Foo bar FOO", "foo"),
+            vec![
+                ("Foo".to_string(), true),
+                (" bar ".to_string(), false),
+                ("FOO".to_string(), true),
+            ]
+        );
+    }
+
+    #[test]
+    fn highlight_non_ascii() {
+        // ẞ shrinks and İ grows when lowercased
+        let runs = highlight("ẞİx", "i");
+        let joined: String = runs.iter().map(|(run, _)| run.as_str()).collect();
+        assert_eq!(joined, "ẞİx");
+        assert_eq!(hits("ẞİx", "i"), vec!["İ"]);
+        assert_eq!(hits("Grüße ÜBER", "über"), vec!["ÜBER"]);
+        assert_eq!(hits("日本語テキスト", "テキ"), vec!["テキ"]);
+    }
+}
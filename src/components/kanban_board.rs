@@ -0,0 +1,473 @@
+//! Kanban board of `Card`s in columns, with drag-and-drop
+
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use crate::{Badge, BadgeSize, BadgeVariant, Card, IconChevronLeft, IconChevronRight};
+
+stylance::import_style!(style, "kanban_board.module.scss");
+
+/// A column of a [`KanbanBoard`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct KanbanColumn<T> {
+    /// Stable id
+    pub id: String,
+    /// Column title
+    pub title: String,
+    /// Cards, top to bottom
+    pub cards: Vec<T>,
+    /// Maximum number of cards in progress; more is flagged in the header
+    pub wip_limit: Option<usize>,
+    /// Whether the column starts collapsed
+    pub collapsed: bool,
+}
+
+impl<T> KanbanColumn<T> {
+    /// Create a column
+    #[must_use]
+    pub fn new(id: impl Into<String>, title: impl Into<String>, cards: Vec<T>) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            cards,
+            wip_limit: None,
+            collapsed: false,
+        }
+    }
+
+    /// Set a work-in-progress limit
+    #[must_use]
+    pub fn wip_limit(mut self, limit: usize) -> Self {
+        self.wip_limit = Some(limit);
+        self
+    }
+
+    /// Start collapsed
+    #[must_use]
+    pub fn collapsed(mut self, collapsed: bool) -> Self {
+        self.collapsed = collapsed;
+        self
+    }
+}
+
+/// A card moved from one place on the board to another
+///
+/// `to_index` is the card's index in the destination column after the move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KanbanMove {
+    /// Index of the source column
+    pub from_column: usize,
+    /// Index of the card in the source column
+    pub from_index: usize,
+    /// Index of the destination column
+    pub to_column: usize,
+    /// Index of the card in the destination column, once moved
+    pub to_index: usize,
+}
+
+impl KanbanMove {
+    /// Whether the card stays where it is
+    #[must_use]
+    pub fn is_noop(&self) -> bool {
+        self.from_column == self.to_column && self.from_index == self.to_index
+    }
+
+    /// Apply the move to the columns it was made on
+    pub fn apply<T>(&self, columns: &mut [KanbanColumn<T>]) {
+        if self.from_column >= columns.len()
+            || self.to_column >= columns.len()
+            || self.from_index >= columns[self.from_column].cards.len()
+        {
+            return;
+        }
+        let card = columns[self.from_column].cards.remove(self.from_index);
+        let cards = &mut columns[self.to_column].cards;
+        let index = self.to_index.min(cards.len());
+        cards.insert(index, card);
+    }
+}
+
+/// Highest `to_index` a card from `from` can take in column `column`
+fn last_index(from: (usize, usize), column: usize, len: usize) -> usize {
+    if from.0 == column {
+        len.saturating_sub(1)
+    } else {
+        len
+    }
+}
+
+/// Board of cards in columns
+///
+/// The board is controlled: it reports moves through `on_move` and the parent
+/// updates `columns`, typically with [`KanbanMove::apply`]. Cards are dragged
+/// with the pointer, or picked up with Space or Enter, moved with the arrow
+/// keys, dropped with Space or Enter and put back with Escape.
+#[component]
+pub fn KanbanBoard<T: Clone + PartialEq + 'static>(
+    /// Columns, left to right
+    columns: Vec<KanbanColumn<T>>,
+    /// Render the content of a card
+    render_card: Callback<T, Element>,
+    /// Stable key of a card
+    card_key: Callback<T, String>,
+    /// Called when a card is dropped in a new place
+    #[props(optional)]
+    on_move: Option<EventHandler<KanbanMove>>,
+    /// Width of an expanded column (CSS value)
+    #[props(default = "18rem".to_string())]
+    column_width: String,
+    /// Text shown in empty columns
+    #[props(default = "No cards".to_string())]
+    empty_message: String,
+) -> Element {
+    let mut collapsed = use_signal(|| {
+        columns
+            .iter()
+            .filter(|c| c.collapsed)
+            .map(|c| c.id.clone())
+            .collect::<HashSet<_>>()
+    });
+    // Card being moved, and where it would go
+    let mut source: Signal<Option<(usize, usize)>> = use_signal(|| None);
+    let mut target: Signal<Option<(usize, usize)>> = use_signal(|| None);
+    let mut keyboard = use_signal(|| false);
+    let mut announcement = use_signal(String::new);
+    let mut cards_mounted: Signal<HashMap<String, Rc<MountedData>>> = use_signal(HashMap::new);
+    let mut pending_focus: Signal<Option<String>> = use_signal(|| None);
+
+    // Keep focus on a card moved with the keyboard
+    use_effect(move || {
+        let Some(key) = pending_focus() else { return };
+        if let Some(el) = cards_mounted.read().get(&key).cloned() {
+            pending_focus.set(None);
+            spawn(async move {
+                let _ = el.set_focus(true).await;
+            });
+        }
+    });
+
+    let lengths: Vec<usize> = columns.iter().map(|c| c.cards.len()).collect();
+    let titles: Vec<String> = columns.iter().map(|c| c.title.clone()).collect();
+
+    let mut cancel = move || {
+        source.set(None);
+        target.set(None);
+        keyboard.set(false);
+    };
+
+    let mut finish = move |focus_key: Option<String>| {
+        let (Some(from), Some(to)) = (source(), target()) else {
+            cancel();
+            return;
+        };
+        cancel();
+        let mv = KanbanMove {
+            from_column: from.0,
+            from_index: from.1,
+            to_column: to.0,
+            to_index: to.1,
+        };
+        if mv.is_noop() {
+            return;
+        }
+        pending_focus.set(focus_key);
+        if let Some(handler) = &on_move {
+            handler.call(mv);
+        }
+    };
+
+    // Where the drop indicator goes, as an index into the unmoved column
+    let indicator = source()
+        .zip(target())
+        .filter(|(from, to)| from != to)
+        .map(|(from, to)| {
+            let slot = if from.0 == to.0 && to.1 >= from.1 {
+                to.1 + 1
+            } else {
+                to.1
+            };
+            (to.0, slot)
+        });
+
+    rsx! {
+        div { class: style::board, style: "--column-width: {column_width}",
+            span { class: style::live, aria_live: "assertive", "{announcement}" }
+            for (c, column) in columns.iter().enumerate() {
+                {
+                    let is_collapsed = collapsed.read().contains(&column.id);
+                    let count = column.cards.len();
+                    let badge_variant = match column.wip_limit {
+                        Some(limit) if count > limit => BadgeVariant::Error,
+                        Some(limit) if count == limit => BadgeVariant::Warning,
+                        _ => BadgeVariant::Default,
+                    };
+                    let badge_text = match column.wip_limit {
+                        Some(limit) => format!("{count} / {limit}"),
+                        None => count.to_string(),
+                    };
+                    let column_id = column.id.clone();
+                    let over_column = indicator.is_some_and(|(col, _)| col == c);
+                    rsx! {
+                        section {
+                            key: "{column.id}",
+                            class: stylance::classes!(
+                                style::column,
+                                if is_collapsed { style::collapsed } else { "" },
+                                if over_column { style::over } else { "" }
+                            ),
+                            aria_label: "{column.title}",
+                            ondragover: move |evt: DragEvent| {
+                                let Some(from) = source() else { return };
+                                evt.prevent_default();
+                                // Over the column itself, rather than a card: append
+                                let end = (c, last_index(from, c, count));
+                                if target() != Some(end) {
+                                    target.set(Some(end));
+                                }
+                            },
+                            ondrop: move |evt: DragEvent| {
+                                evt.prevent_default();
+                                finish(None);
+                            },
+                            header { class: style::header,
+                                button {
+                                    r#type: "button",
+                                    class: style::collapse,
+                                    aria_expanded: "{!is_collapsed}",
+                                    aria_label: if is_collapsed { "Expand column" } else { "Collapse column" },
+                                    onclick: move |_| {
+                                        let mut set = collapsed.write();
+                                        if !set.remove(&column_id) {
+                                            set.insert(column_id.clone());
+                                        }
+                                    },
+                                    if is_collapsed {
+                                        IconChevronRight {}
+                                    } else {
+                                        IconChevronLeft {}
+                                    }
+                                }
+                                h3 { class: style::title, "{column.title}" }
+                                Badge { variant: badge_variant, size: BadgeSize::Small, "{badge_text}" }
+                            }
+                            if !is_collapsed {
+                                div { class: style::cards,
+                                    for (i, card) in column.cards.iter().enumerate() {
+                                        {
+                                            let key = card_key.call(card.clone());
+                                            let is_source = source() == Some((c, i));
+                                            let before = indicator == Some((c, i));
+                                            rsx! {
+                                                div {
+                                                    key: "{key}",
+                                                    class: stylance::classes!(
+                                                        style::slot,
+                                                        if is_source { style::moving } else { "" },
+                                                        if is_source && keyboard() { style::lifted } else { "" }
+                                                    ),
+                                                    draggable: "true",
+                                                    tabindex: "0",
+                                                    role: "button",
+                                                    aria_pressed: if is_source && keyboard() { "true" } else { "false" },
+                                                    aria_roledescription: "draggable card",
+                                                    onmounted: {
+                                                        let key = key.clone();
+                                                        move |evt: MountedEvent| {
+                                                            cards_mounted.write().insert(key.clone(), evt.data());
+                                                        }
+                                                    },
+                                                    ondragstart: {
+                                                        let key = key.clone();
+                                                        move |evt: DragEvent| {
+                                                            let _ = evt.data_transfer().set_data("text/plain", &key);
+                                                            evt.data_transfer().set_effect_allowed("move");
+                                                            keyboard.set(false);
+                                                            source.set(Some((c, i)));
+                                                            target.set(Some((c, i)));
+                                                        }
+                                                    },
+                                                    ondragover: move |evt: DragEvent| {
+                                                        let Some(from) = source() else { return };
+                                                        evt.prevent_default();
+                                                        evt.stop_propagation();
+                                                        // Insert before this card
+                                                        let index = if from.0 == c && i > from.1 { i - 1 } else { i };
+                                                        if target() != Some((c, index)) {
+                                                            target.set(Some((c, index)));
+                                                        }
+                                                    },
+                                                    ondragend: move |_| cancel(),
+                                                    onkeydown: {
+                                                        let key = key.clone();
+                                                        let lengths = lengths.clone();
+                                                        let titles = titles.clone();
+                                                        move |evt: KeyboardEvent| {
+                                                            let held = keyboard() && source() == Some((c, i));
+                                                            let Some((col, index)) = target().filter(|_| held) else {
+                                                                if matches!(evt.key(), Key::Enter) || evt.key() == Key::Character(" ".to_string()) {
+                                                                    evt.prevent_default();
+                                                                    keyboard.set(true);
+                                                                    source.set(Some((c, i)));
+                                                                    target.set(Some((c, i)));
+                                                                    announcement.set(format!(
+                                                                        "Picked up card {} of {} in {}. Use the arrow keys to move it, Space to drop, Escape to cancel.",
+                                                                        i + 1, lengths[c], titles[c]
+                                                                    ));
+                                                                }
+                                                                return;
+                                                            };
+                                                            let next = match evt.key() {
+                                                                Key::ArrowUp => Some((col, index.saturating_sub(1))),
+                                                                Key::ArrowDown => Some((col, (index + 1).min(last_index((c, i), col, lengths[col])))),
+                                                                Key::ArrowLeft if col > 0 => {
+                                                                    Some((col - 1, index.min(last_index((c, i), col - 1, lengths[col - 1]))))
+                                                                }
+                                                                Key::ArrowRight if col + 1 < lengths.len() => {
+                                                                    Some((col + 1, index.min(last_index((c, i), col + 1, lengths[col + 1]))))
+                                                                }
+                                                                Key::Escape => {
+                                                                    evt.prevent_default();
+                                                                    cancel();
+                                                                    announcement.set("Move cancelled.".to_string());
+                                                                    return;
+                                                                }
+                                                                Key::Enter => None,
+                                                                Key::Character(ref s) if s == " " => None,
+                                                                Key::Tab => {
+                                                                    cancel();
+                                                                    return;
+                                                                }
+                                                                _ => return,
+                                                            };
+                                                            evt.prevent_default();
+                                                            match next {
+                                                                Some(next) => {
+                                                                    target.set(Some(next));
+                                                                    announcement.set(format!("Position {} in {}.", next.1 + 1, titles[next.0]));
+                                                                }
+                                                                None => {
+                                                                    announcement.set(format!("Dropped at position {} in {}.", index + 1, titles[col]));
+                                                                    finish(Some(key.clone()));
+                                                                }
+                                                            }
+                                                        }
+                                                    },
+                                                    onblur: move |_| {
+                                                        if keyboard() && source() == Some((c, i)) {
+                                                            cancel();
+                                                        }
+                                                    },
+                                                    if before {
+                                                        div { class: style::indicator }
+                                                    }
+                                                    Card { hoverable: true, {render_card.call(card.clone())} }
+                                                }
+                                            }
+                                        }
+                                    }
+                                    if indicator == Some((c, count)) {
+                                        div { class: stylance::classes!(style::indicator, style::at_end) }
+                                    }
+                                    if count == 0 {
+                                        p { class: style::empty, "{empty_message}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn board() -> Vec<KanbanColumn<&'static str>> {
+        vec![
+            KanbanColumn::new("todo", "To do", vec!["a", "b", "c"]),
+            KanbanColumn::new("doing", "Doing", vec!["d"]),
+            KanbanColumn::new("done", "Done", vec![]),
+        ]
+    }
+
+    fn cards(columns: &[KanbanColumn<&'static str>]) -> Vec<Vec<&'static str>> {
+        columns.iter().map(|column| column.cards.clone()).collect()
+    }
+
+    fn moved(from: (usize, usize), to: (usize, usize)) -> Vec<Vec<&'static str>> {
+        let mut columns = board();
+        KanbanMove {
+            from_column: from.0,
+            from_index: from.1,
+            to_column: to.0,
+            to_index: to.1,
+        }
+        .apply(&mut columns);
+        cards(&columns)
+    }
+
+    #[test]
+    fn moves_within_a_column() {
+        assert_eq!(
+            moved((0, 0), (0, 2)),
+            [vec!["b", "c", "a"], vec!["d"], vec![]]
+        );
+        assert_eq!(
+            moved((0, 2), (0, 0)),
+            [vec!["c", "a", "b"], vec!["d"], vec![]]
+        );
+    }
+
+    #[test]
+    fn moves_across_columns() {
+        assert_eq!(
+            moved((0, 1), (1, 0)),
+            [vec!["a", "c"], vec!["b", "d"], vec![]]
+        );
+        assert_eq!(
+            moved((1, 0), (2, 0)),
+            [vec!["a", "b", "c"], vec![], vec!["d"]]
+        );
+    }
+
+    #[test]
+    fn clamps_the_destination_index() {
+        assert_eq!(
+            moved((0, 0), (1, 9)),
+            [vec!["b", "c"], vec!["d", "a"], vec![]]
+        );
+    }
+
+    #[test]
+    fn ignores_out_of_range_moves() {
+        let unchanged = cards(&board());
+        assert_eq!(moved((3, 0), (0, 0)), unchanged);
+        assert_eq!(moved((0, 0), (3, 0)), unchanged);
+        assert_eq!(moved((2, 0), (0, 0)), unchanged);
+    }
+
+    #[test]
+    fn noop_moves_leave_the_board_alone() {
+        let mv = KanbanMove {
+            from_column: 0,
+            from_index: 1,
+            to_column: 0,
+            to_index: 1,
+        };
+        assert!(mv.is_noop());
+        assert_eq!(moved((0, 1), (0, 1)), cards(&board()));
+        assert!(!KanbanMove { to_column: 1, ..mv }.is_noop());
+    }
+
+    #[test]
+    fn last_index_excludes_the_moving_card_in_its_own_column() {
+        assert_eq!(last_index((0, 1), 0, 3), 2);
+        assert_eq!(last_index((0, 1), 1, 3), 3);
+        assert_eq!(last_index((0, 0), 0, 0), 0);
+    }
+}
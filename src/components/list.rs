@@ -45,6 +45,9 @@ pub fn List(
     /// Additional CSS class
     #[props(optional, into)]
     class: Option<String>,
+    /// Called when the list element is mounted
+    #[props(optional)]
+    onmounted: Option<EventHandler<MountedEvent>>,
 ) -> Element {
     let size_class = match size {
         ListSize::Small => style::small,
@@ -65,15 +68,21 @@ pub fn List(
         class.as_deref().unwrap_or("")
     );
 
+    let handle_mounted = move |evt: MountedEvent| {
+        if let Some(handler) = &onmounted {
+            handler.call(evt);
+        }
+    };
+
     if ordered {
         rsx! {
-            ol { class: classes,
+            ol { class: classes, onmounted: handle_mounted,
                 {children}
             }
         }
     } else {
         rsx! {
-            ul { class: classes,
+            ul { class: classes, onmounted: handle_mounted,
                 {children}
             }
         }
@@ -0,0 +1,452 @@
+//! `LogViewer` component for large, growing logs
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use dioxus::html::geometry::PixelsVector2D;
+use dioxus::prelude::*;
+
+use crate::{
+    IconArrowDownToLine, IconButton, IconButtonSize, IconChevronDown, IconChevronUp, IconSearch,
+    IconTextWrap, Input, InputSize,
+};
+
+stylance::import_style!(style, "log_viewer.module.scss");
+
+/// Height of one unwrapped line in pixels (matches `.line` in the stylesheet)
+const LINE_HEIGHT: f64 = 20.0;
+
+/// Lines rendered above and below the visible window
+const OVERSCAN: usize = 30;
+
+/// Indices of the lines from `from` on containing `query` (already lowercased),
+/// ignoring ASCII case
+fn find_matches<'a>(
+    lines: &'a [String],
+    query: &'a str,
+    from: usize,
+) -> impl Iterator<Item = usize> + 'a {
+    lines
+        .iter()
+        .enumerate()
+        .skip(from)
+        .filter(move |(_, line)| line.to_ascii_lowercase().contains(query))
+        .map(|(idx, _)| idx)
+}
+
+/// Search results kept between updates, so appended lines are scanned alone
+#[derive(Debug, Default)]
+struct MatchCache {
+    query: String,
+    scanned: usize,
+    matches: Vec<usize>,
+    /// First and last scanned lines, to notice when the log is replaced
+    ends: Option<(String, String)>,
+}
+
+impl MatchCache {
+    /// Bring the matches up to date with `lines`
+    ///
+    /// Only lines appended since the last update are scanned. A new query, or a
+    /// log that no longer starts and continues with the scanned lines (a
+    /// different log was loaded), starts over.
+    fn update(&mut self, lines: &[String], query: &str) {
+        let same_log = lines.len() >= self.scanned
+            && self.ends.as_ref().is_none_or(|(first, last)| {
+                lines.first() == Some(first) && lines.get(self.scanned - 1) == Some(last)
+            });
+        if query != self.query || !same_log {
+            self.query = query.to_string();
+            self.scanned = 0;
+            self.matches.clear();
+        }
+        if !query.is_empty() {
+            self.matches
+                .extend(find_matches(lines, query, self.scanned));
+        }
+        self.scanned = lines.len();
+        self.ends = lines
+            .first()
+            .zip(lines.last())
+            .map(|(first, last)| (first.clone(), last.clone()));
+    }
+}
+
+/// Split a line into runs of text, flagging the runs that match `query`
+fn match_segments<'a>(line: &'a str, query: &str) -> Vec<(&'a str, bool)> {
+    // ASCII lowercasing keeps byte offsets identical, so they index into `line`
+    let lower = line.to_ascii_lowercase();
+    let mut segments = Vec::new();
+    let mut pos = 0;
+    while let Some(found) = lower[pos..].find(query) {
+        let start = pos + found;
+        let end = start + query.len();
+        if start > pos {
+            segments.push((&line[pos..start], false));
+        }
+        segments.push((&line[start..end], true));
+        pos = end;
+    }
+    if pos < line.len() {
+        segments.push((&line[pos..], false));
+    }
+    segments
+}
+
+/// Log viewer for very long outputs (build logs, traces)
+///
+/// Only the lines in view (plus a small margin) are rendered, so hundreds of
+/// thousands of lines stay responsive. The view follows the end of the log as
+/// lines are appended, until the user scrolls up; scrolling back to the bottom
+/// or pressing the follow button resumes it. Search highlights matching lines
+/// and Enter / Shift+Enter step through them.
+///
+/// With wrapping on, lines can span several rows, so the window is sized from
+/// the measured average height of the rendered lines, like `VirtualList` with
+/// `RowHeight::Measured`.
+#[component]
+pub fn LogViewer(
+    /// Log lines (a signal, so appending doesn't diff the whole log)
+    lines: ReadSignal<Vec<String>>,
+    /// Follow the end of the log initially
+    #[props(default = true)]
+    follow: bool,
+    /// Wrap long lines initially
+    #[props(default = false)]
+    wrap: bool,
+    /// Show line numbers
+    #[props(default = true)]
+    show_line_numbers: bool,
+    /// Height of the scrolling area (CSS value)
+    #[props(default = "24rem".to_string())]
+    height: String,
+    /// Additional CSS class
+    #[props(default)]
+    class: Option<String>,
+) -> Element {
+    let mut container: Signal<Option<Rc<MountedData>>> = use_signal(|| None);
+    let mut content: Signal<Option<Rc<MountedData>>> = use_signal(|| None);
+    let mut scroll_top = use_signal(|| 0.0);
+    let mut viewport_height = use_signal(|| 480.0);
+    let mut following = use_signal(|| follow);
+    let mut wrapped = use_signal(|| wrap);
+    let mut query = use_signal(String::new);
+    let mut current_match = use_signal(|| 0usize);
+    // Average height of a wrapped line, once measured
+    let measured = use_signal(|| None::<f64>);
+    let last_measured: Rc<Cell<Option<(usize, usize)>>> = use_hook(|| Rc::new(Cell::new(None)));
+    let match_cache = use_hook(|| Rc::new(RefCell::new(MatchCache::default())));
+
+    let matches = use_memo(move || {
+        let query = query.read().to_ascii_lowercase();
+        let mut cache = match_cache.borrow_mut();
+        cache.update(&lines.read(), &query);
+        cache.matches.clone()
+    });
+
+    // Pixel height used for one line
+    let line_px = move || {
+        if *wrapped.peek() {
+            measured.peek().unwrap_or(LINE_HEIGHT)
+        } else {
+            LINE_HEIGHT
+        }
+    };
+
+    let scroll_to = move |top: f64| {
+        if let Some(el) = container.peek().clone() {
+            spawn(async move {
+                let _ = el
+                    .scroll(PixelsVector2D::new(0.0, top), ScrollBehavior::Instant)
+                    .await;
+            });
+        }
+    };
+
+    let mut scroll_to_line = move |line: usize| {
+        following.set(false);
+        scroll_to((line as f64 * line_px() - *viewport_height.peek() / 2.0).max(0.0));
+    };
+
+    let mut go_to_match = move |idx: usize| {
+        let matches = matches.peek();
+        if let Some(&line) = matches.get(idx) {
+            current_match.set(idx);
+            scroll_to_line(line);
+        }
+    };
+
+    // Keep the newest lines in view while following
+    use_effect(move || {
+        let count = lines.read().len();
+        if *following.peek() {
+            scroll_to(count as f64 * line_px());
+        }
+    });
+
+    // Jump to the first match whenever the query changes
+    use_effect(move || {
+        query.read();
+        current_match.set(0);
+        if let Some(&line) = matches.peek().first() {
+            scroll_to_line(line);
+        }
+    });
+
+    let lines_read = lines.read();
+    let total = lines_read.len();
+    let is_wrapped = wrapped();
+    let row_px = if is_wrapped {
+        measured().unwrap_or(LINE_HEIGHT)
+    } else {
+        LINE_HEIGHT
+    };
+    let first = ((scroll_top() / row_px) as usize)
+        .saturating_sub(OVERSCAN)
+        .min(total);
+    let last = (first + (viewport_height() / row_px).ceil() as usize + 2 * OVERSCAN).min(total);
+    let top_spacer = first as f64 * row_px;
+    let bottom_spacer = (total - last) as f64 * row_px;
+
+    // Measure the rendered lines whenever the window moves while wrapping
+    use_effect(use_reactive(
+        (&first, &last, &is_wrapped, &top_spacer, &bottom_spacer),
+        move |(first, last, is_wrapped, top_spacer, bottom_spacer)| {
+            if !is_wrapped {
+                // Widths may change before wrapping is turned back on
+                last_measured.set(None);
+                return;
+            }
+            let rendered = last - first;
+            if rendered == 0 || last_measured.get() == Some((first, last)) {
+                return;
+            }
+            last_measured.set(Some((first, last)));
+            let Some(el) = content.read().clone() else {
+                return;
+            };
+            let mut measured = measured;
+            spawn(async move {
+                let Ok(rect) = el.get_client_rect().await else {
+                    return;
+                };
+                let rows = rect.size.height - top_spacer - bottom_spacer;
+                let average = rows / rendered as f64;
+                let current = measured.peek().unwrap_or(0.0);
+                if average > 0.0 && (average - current).abs() > 0.5 {
+                    measured.set(Some(average));
+                }
+            });
+        },
+    ));
+    let gutter = total.max(1).to_string().len();
+
+    let match_list = matches.read();
+    let match_count = match_list.len();
+    let current = current_match().min(match_count.saturating_sub(1));
+    let current_line = match_list.get(current).copied();
+    let query_lower = query.read().to_ascii_lowercase();
+
+    let handle_keydown = move |evt: KeyboardEvent| {
+        let count = matches.peek().len();
+        match evt.key() {
+            Key::Enter if count > 0 => {
+                evt.prevent_default();
+                let current = *current_match.peek();
+                if evt.modifiers().shift() {
+                    go_to_match((current + count - 1) % count);
+                } else {
+                    go_to_match((current + 1) % count);
+                }
+            }
+            Key::Escape => query.set(String::new()),
+            _ => {}
+        }
+    };
+
+    rsx! {
+        div { class: stylance::classes!(style::log_viewer, class.as_deref().unwrap_or("")),
+            div { class: style::toolbar, onkeydown: handle_keydown,
+                div { class: style::search,
+                    Input {
+                        size: InputSize::Small,
+                        placeholder: "Search log",
+                        value: query(),
+                        clearable: true,
+                        icon: rsx! { IconSearch {} },
+                        oninput: move |evt: FormEvent| query.set(evt.value()),
+                        onclear: move |_| query.set(String::new()),
+                    }
+                }
+                if !query_lower.is_empty() {
+                    span { class: style::match_count,
+                        if match_count == 0 {
+                            "No matches"
+                        } else {
+                            "{current + 1} of {match_count}"
+                        }
+                    }
+                    IconButton {
+                        size: IconButtonSize::Small,
+                        disabled: match_count == 0,
+                        aria_label: "Previous match",
+                        onclick: move |_| go_to_match((current + match_count - 1) % match_count.max(1)),
+                        IconChevronUp {}
+                    }
+                    IconButton {
+                        size: IconButtonSize::Small,
+                        disabled: match_count == 0,
+                        aria_label: "Next match",
+                        onclick: move |_| go_to_match((current + 1) % match_count.max(1)),
+                        IconChevronDown {}
+                    }
+                }
+                span { class: style::spacer }
+                span { class: stylance::classes!(style::toggle, if wrapped() { style::active } else { "" }),
+                    IconButton {
+                        size: IconButtonSize::Small,
+                        aria_label: "Wrap lines",
+                        onclick: move |_| wrapped.toggle(),
+                        IconTextWrap {}
+                    }
+                }
+                span { class: stylance::classes!(style::toggle, if following() { style::active } else { "" }),
+                    IconButton {
+                        size: IconButtonSize::Small,
+                        aria_label: "Follow tail",
+                        onclick: move |_| {
+                            following.set(true);
+                            scroll_to(total as f64 * row_px);
+                        },
+                        IconArrowDownToLine {}
+                    }
+                }
+            }
+            div {
+                class: style::viewport,
+                style: "height: {height}; --log-gutter: {gutter}ch",
+                onmounted: move |evt| {
+                    let el = evt.data();
+                    container.set(Some(el.clone()));
+                    spawn(async move {
+                        if let Ok(rect) = el.get_client_rect().await {
+                            viewport_height.set(rect.size.height);
+                        }
+                    });
+                },
+                onscroll: move |evt: ScrollEvent| {
+                    let top = evt.data().scroll_top();
+                    let client = f64::from(evt.data().client_height());
+                    let height = f64::from(evt.data().scroll_height());
+                    scroll_top.set(top);
+                    if client > 0.0 {
+                        viewport_height.set(client);
+                    }
+                    following.set(top + client >= height - row_px);
+                },
+                if total == 0 {
+                    div { class: style::empty, "No output" }
+                }
+                div {
+                    class: stylance::classes!(style::lines, if is_wrapped { style::wrap } else { "" }),
+                    onmounted: move |evt| content.set(Some(evt.data())),
+                    div { style: "height: {top_spacer}px" }
+                    for idx in first..last {
+                        {
+                            let line = &lines_read[idx];
+                            let is_current = current_line == Some(idx);
+                            let has_match = !query_lower.is_empty()
+                                && match_list.binary_search(&idx).is_ok();
+                            rsx! {
+                                div {
+                                    key: "{idx}",
+                                    class: stylance::classes!(style::line, if is_current { style::current } else { "" }),
+                                    if show_line_numbers {
+                                        span { class: style::line_number, "{idx + 1}" }
+                                    }
+                                    span { class: style::text,
+                                        if has_match {
+                                            for (segment, hit) in match_segments(line, &query_lower) {
+                                                if hit {
+                                                    mark { class: style::mark, "{segment}" }
+                                                } else {
+                                                    "{segment}"
+                                                }
+                                            }
+                                        } else {
+                                            "{line}"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    div { style: "height: {bottom_spacer}px" }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|line| line.to_string()).collect()
+    }
+
+    #[test]
+    fn match_cache_scans_appended_lines_only() {
+        let mut lines = log(&["Error: one", "ok", "error two"]);
+        let mut cache = MatchCache::default();
+        cache.update(&lines, "error");
+        assert_eq!(cache.matches, [0, 2]);
+
+        lines.extend(log(&["fine", "ERROR three"]));
+        cache.update(&lines, "error");
+        assert_eq!(cache.matches, [0, 2, 4]);
+        assert_eq!(cache.scanned, 5);
+
+        // Already scanned lines in the middle are not searched again
+        lines[1] = "error, but late".to_string();
+        lines.push("done".to_string());
+        cache.update(&lines, "error");
+        assert_eq!(cache.matches, [0, 2, 4]);
+    }
+
+    #[test]
+    fn match_cache_restarts_on_new_query_or_shorter_log() {
+        let mut cache = MatchCache::default();
+        cache.update(&log(&["a", "b", "ab"]), "a");
+        assert_eq!(cache.matches, [0, 2]);
+        cache.update(&log(&["a", "b", "ab"]), "b");
+        assert_eq!(cache.matches, [1, 2]);
+        cache.update(&log(&["b"]), "b");
+        assert_eq!(cache.matches, [0]);
+        cache.update(&log(&["b", "b"]), "");
+        assert!(cache.matches.is_empty());
+    }
+
+    #[test]
+    fn match_cache_restarts_when_the_log_is_replaced() {
+        let mut cache = MatchCache::default();
+        cache.update(&log(&["job 1", "error a", "ok"]), "error");
+        assert_eq!(cache.matches, [1]);
+        // Another, longer log: different first line
+        cache.update(&log(&["job 2", "ok", "ok", "error b"]), "error");
+        assert_eq!(cache.matches, [3]);
+        // Same first line, but not a continuation of what was scanned
+        cache.update(&log(&["job 2", "error c", "ok", "fine", "more"]), "error");
+        assert_eq!(cache.matches, [1]);
+    }
+
+    #[test]
+    fn match_segments_keep_original_case() {
+        assert_eq!(
+            match_segments("Error: ERROR", "error"),
+            [("Error", true), (": ", false), ("ERROR", true)]
+        );
+        assert_eq!(match_segments("nothing", "x"), [("nothing", false)]);
+    }
+}
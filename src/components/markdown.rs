@@ -0,0 +1,866 @@
+//! `Markdown` component rendering CommonMark through glade components
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ops::Range;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use pulldown_cmark::{
+    Alignment, BrokenLink, CodeBlockKind, CowStr, Event, LinkType, Options, Parser, Tag,
+};
+
+use crate::{
+    CodeBlock, InlineCode, Language, Link, Separator, Table, TableBody, TableCell, TableHeader,
+    TableHeaderCell, TableRow, TodoItem, TodoList,
+};
+
+stylance::import_style!(style, "markdown.module.scss");
+
+/// Block-level Markdown element
+#[derive(Debug, Clone, PartialEq)]
+enum Block {
+    /// Paragraph
+    Paragraph(Vec<Inline>),
+    /// Inline content not wrapped in a paragraph (tight list items)
+    Plain(Vec<Inline>),
+    /// Heading, level 1 to 6
+    Heading { level: usize, content: Vec<Inline> },
+    /// Fenced or indented code block
+    Code {
+        language: Option<Language>,
+        code: String,
+    },
+    /// Block quote
+    Quote(Vec<Block>),
+    /// Ordered (with its first number) or bullet list
+    List {
+        start: Option<u64>,
+        items: Vec<ListItem>,
+    },
+    /// GFM table
+    Table {
+        alignments: Vec<&'static str>,
+        head: Vec<Vec<Inline>>,
+        rows: Vec<Vec<Vec<Inline>>>,
+    },
+    /// Thematic break
+    Rule,
+    /// Raw HTML block, shown as text
+    Html(String),
+}
+
+/// List item, with its task list state if it has a `[ ]` / `[x]` marker
+#[derive(Debug, Clone, PartialEq)]
+struct ListItem {
+    checked: Option<bool>,
+    blocks: Vec<Block>,
+}
+
+/// Inline Markdown element
+#[derive(Debug, Clone, PartialEq)]
+enum Inline {
+    Text(String),
+    Code(String),
+    Emphasis(Vec<Inline>),
+    Strong(Vec<Inline>),
+    Strikethrough(Vec<Inline>),
+    Link {
+        url: String,
+        content: Vec<Inline>,
+    },
+    Image {
+        url: String,
+        title: String,
+        alt: String,
+    },
+    /// Raw inline HTML, shown as text
+    Html(String),
+    SoftBreak,
+    HardBreak,
+}
+
+fn options() -> Options {
+    Options::ENABLE_TABLES | Options::ENABLE_TASKLISTS | Options::ENABLE_STRIKETHROUGH
+}
+
+/// Turns the pulldown-cmark event stream into a tree of blocks
+struct TreeBuilder<'a, I: Iterator<Item = (Event<'a>, Range<usize>)>> {
+    events: I,
+    /// Task list marker seen in the list item being built
+    task: Option<bool>,
+    /// Source offset of the last event read
+    offset: usize,
+    /// Nesting depth of `blocks` calls
+    depth: usize,
+    /// Source offset where each top-level block starts
+    starts: Vec<usize>,
+    /// Source offset and label of each link or image resolved by a definition
+    references: Vec<(usize, String)>,
+}
+
+impl<'a, I: Iterator<Item = (Event<'a>, Range<usize>)>> TreeBuilder<'a, I> {
+    fn next(&mut self) -> Option<Event<'a>> {
+        let (event, range) = self.events.next()?;
+        self.offset = range.start;
+        Some(event)
+    }
+
+    /// Collect blocks up to (and including) the end of the enclosing container
+    fn blocks(&mut self) -> Vec<Block> {
+        let mut blocks = Vec::new();
+        let mut pending = Vec::new();
+        let top_level = self.depth == 0;
+        self.depth += 1;
+
+        while let Some(event) = self.next() {
+            let start = self.offset;
+            let event = match self.inline(event, &mut pending) {
+                Some(event) => event,
+                None => continue,
+            };
+            if !pending.is_empty() {
+                blocks.push(Block::Plain(std::mem::take(&mut pending)));
+            }
+            match event {
+                Event::End(_) => break,
+                Event::Rule => blocks.push(Block::Rule),
+                Event::Start(Tag::Paragraph) => blocks.push(Block::Paragraph(self.inlines())),
+                Event::Start(Tag::Heading { level, .. }) => blocks.push(Block::Heading {
+                    level: level as usize,
+                    content: self.inlines(),
+                }),
+                Event::Start(Tag::BlockQuote(_)) => blocks.push(Block::Quote(self.blocks())),
+                Event::Start(Tag::CodeBlock(kind)) => {
+                    let language = match kind {
+                        CodeBlockKind::Fenced(info) => {
+                            info.split_whitespace().next().and_then(Language::from_name)
+                        }
+                        CodeBlockKind::Indented => None,
+                    };
+                    let mut code = self.text();
+                    if code.ends_with('\n') {
+                        code.pop();
+                    }
+                    blocks.push(Block::Code { language, code });
+                }
+                Event::Start(Tag::HtmlBlock) => {
+                    blocks.push(Block::Html(self.text().trim_end().to_string()));
+                }
+                Event::Start(Tag::List(start)) => {
+                    let mut items = Vec::new();
+                    while let Some(Event::Start(Tag::Item)) = self.next() {
+                        items.push(self.item());
+                    }
+                    blocks.push(Block::List { start, items });
+                }
+                Event::Start(Tag::Table(alignments)) => blocks.push(self.table(&alignments)),
+                // Footnotes, definition lists and metadata aren't enabled; keep any content
+                Event::Start(_) => blocks.extend(self.blocks()),
+                _ => {}
+            }
+            if top_level {
+                self.starts.resize(blocks.len(), start);
+            }
+        }
+
+        if !pending.is_empty() {
+            blocks.push(Block::Plain(pending));
+        }
+        if top_level {
+            self.starts.resize(blocks.len(), self.offset);
+        }
+        self.depth -= 1;
+        blocks
+    }
+
+    fn item(&mut self) -> ListItem {
+        let outer = self.task.take();
+        let blocks = self.blocks();
+        let checked = std::mem::replace(&mut self.task, outer);
+        ListItem { checked, blocks }
+    }
+
+    fn table(&mut self, alignments: &[Alignment]) -> Block {
+        let alignments = alignments
+            .iter()
+            .map(|alignment| match alignment {
+                Alignment::None | Alignment::Left => "left",
+                Alignment::Center => "center",
+                Alignment::Right => "right",
+            })
+            .collect();
+        let mut head = Vec::new();
+        let mut rows = Vec::new();
+
+        while let Some(Event::Start(tag)) = self.next() {
+            let cells = self.cells();
+            if tag == Tag::TableHead {
+                head = cells;
+            } else {
+                rows.push(cells);
+            }
+        }
+
+        Block::Table {
+            alignments,
+            head,
+            rows,
+        }
+    }
+
+    fn cells(&mut self) -> Vec<Vec<Inline>> {
+        let mut cells = Vec::new();
+        while let Some(Event::Start(Tag::TableCell)) = self.next() {
+            cells.push(self.inlines());
+        }
+        cells
+    }
+
+    /// Collect inline content up to (and including) the end of the enclosing tag
+    fn inlines(&mut self) -> Vec<Inline> {
+        let mut inlines = Vec::new();
+        while let Some(event) = self.next() {
+            if let Some(Event::End(_)) = self.inline(event, &mut inlines) {
+                break;
+            }
+        }
+        inlines
+    }
+
+    /// Collect raw text (code and HTML blocks) up to the end of the enclosing tag
+    fn text(&mut self) -> String {
+        let mut text = String::new();
+        while let Some(event) = self.next() {
+            match event {
+                Event::Text(t) | Event::Html(t) => text.push_str(&t),
+                Event::End(_) => break,
+                _ => {}
+            }
+        }
+        text
+    }
+
+    /// Note a link or image at the current offset that a definition resolved
+    fn reference(&mut self, link_type: LinkType, label: &str) {
+        if matches!(
+            link_type,
+            LinkType::Reference | LinkType::Collapsed | LinkType::Shortcut
+        ) {
+            self.references.push((self.offset, normalize_label(label)));
+        }
+    }
+
+    /// Append an inline event to `out`, handing back events that aren't inline
+    fn inline(&mut self, event: Event<'a>, out: &mut Vec<Inline>) -> Option<Event<'a>> {
+        match event {
+            Event::Text(text) => match out.last_mut() {
+                Some(Inline::Text(last)) => last.push_str(&text),
+                _ => out.push(Inline::Text(text.into_string())),
+            },
+            Event::Code(code) => out.push(Inline::Code(code.into_string())),
+            Event::InlineHtml(html) => out.push(Inline::Html(html.into_string())),
+            Event::InlineMath(math) | Event::FootnoteReference(math) => {
+                out.push(Inline::Text(math.into_string()));
+            }
+            Event::SoftBreak => out.push(Inline::SoftBreak),
+            Event::HardBreak => out.push(Inline::HardBreak),
+            Event::TaskListMarker(checked) => self.task = Some(checked),
+            Event::Start(Tag::Emphasis) => out.push(Inline::Emphasis(self.inlines())),
+            Event::Start(Tag::Strong) => out.push(Inline::Strong(self.inlines())),
+            Event::Start(Tag::Strikethrough) => out.push(Inline::Strikethrough(self.inlines())),
+            Event::Start(Tag::Link {
+                link_type,
+                dest_url,
+                id,
+                ..
+            }) => {
+                self.reference(link_type, &id);
+                out.push(Inline::Link {
+                    url: dest_url.into_string(),
+                    content: self.inlines(),
+                });
+            }
+            Event::Start(Tag::Image {
+                link_type,
+                dest_url,
+                title,
+                id,
+            }) => {
+                self.reference(link_type, &id);
+                out.push(Inline::Image {
+                    url: dest_url.into_string(),
+                    title: title.into_string(),
+                    alt: plain_text(&self.inlines()),
+                });
+            }
+            Event::Start(Tag::Superscript | Tag::Subscript) => out.extend(self.inlines()),
+            event => return Some(event),
+        }
+        None
+    }
+}
+
+/// Link reference definitions by normalized label: destination and title
+type References = HashMap<String, (String, String)>;
+
+/// Normalize a link label the way CommonMark matches them
+fn normalize_label(label: &str) -> String {
+    label
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Blocks parsed from a Markdown source
+struct Parsed {
+    blocks: Vec<Block>,
+    /// Source offset where each top-level block starts
+    starts: Vec<usize>,
+    /// Source offsets of references (`[x][y]`, `[x]`) nothing defines
+    unresolved: Vec<usize>,
+    /// Source offset and label of references the source defines itself
+    references: Vec<(usize, String)>,
+    /// Reference definitions in the source: offset, label, destination and title
+    definitions: Vec<(usize, String, String, String)>,
+}
+
+/// Parse Markdown source into top-level blocks
+///
+/// References the source doesn't define itself are looked up in `references`,
+/// the definitions from earlier parts of the same document.
+fn parse_blocks(source: &str, references: &References) -> Parsed {
+    let mut unresolved = Vec::new();
+    let (blocks, starts, references, definitions) = {
+        let resolve = |link: BrokenLink<'_>| {
+            let found = references.get(&normalize_label(&link.reference));
+            if found.is_none() {
+                unresolved.push(link.span.start);
+            }
+            found.map(|(url, title)| (CowStr::from(url.clone()), CowStr::from(title.clone())))
+        };
+        let parser = Parser::new_with_broken_link_callback(source, options(), Some(resolve));
+        let definitions = parser
+            .reference_definitions()
+            .iter()
+            .map(|(label, def)| {
+                let title = def.title.as_deref().unwrap_or_default();
+                (
+                    def.span.start,
+                    normalize_label(label),
+                    def.dest.to_string(),
+                    title.to_string(),
+                )
+            })
+            .collect();
+        let mut builder = TreeBuilder {
+            events: parser.into_offset_iter(),
+            task: None,
+            offset: 0,
+            depth: 0,
+            starts: Vec::new(),
+            references: Vec::new(),
+        };
+        let blocks = builder.blocks();
+        (blocks, builder.starts, builder.references, definitions)
+    };
+    Parsed {
+        blocks,
+        starts,
+        unresolved,
+        references,
+        definitions,
+    }
+}
+
+fn plain_text(inlines: &[Inline]) -> String {
+    let mut text = String::new();
+    for inline in inlines {
+        match inline {
+            Inline::Text(t) | Inline::Code(t) | Inline::Html(t) => text.push_str(t),
+            Inline::Emphasis(c) | Inline::Strong(c) | Inline::Strikethrough(c) => {
+                text.push_str(&plain_text(c));
+            }
+            Inline::Link { content, .. } => text.push_str(&plain_text(content)),
+            Inline::Image { alt, .. } => text.push_str(alt),
+            Inline::SoftBreak | Inline::HardBreak => text.push(' '),
+        }
+    }
+    text
+}
+
+/// Keep relative URLs and safe schemes, dropping `javascript:` and friends
+fn safe_url(url: &str) -> Option<&str> {
+    let Some(scheme_end) = url.find(':') else {
+        return Some(url);
+    };
+    if url[..scheme_end].contains(['/', '?', '#']) {
+        return Some(url);
+    }
+    let scheme = url[..scheme_end].to_ascii_lowercase();
+    matches!(scheme.as_str(), "http" | "https" | "mailto" | "tel" | "ftp").then_some(url)
+}
+
+fn is_external(url: &str) -> bool {
+    url.starts_with("http://") || url.starts_with("https://")
+}
+
+fn render_inlines(inlines: &[Inline]) -> Element {
+    rsx! {
+        for inline in inlines {
+            {render_inline(inline)}
+        }
+    }
+}
+
+fn render_inline(inline: &Inline) -> Element {
+    match inline {
+        Inline::Text(text) => rsx! { "{text}" },
+        Inline::Code(code) => rsx! { InlineCode { "{code}" } },
+        Inline::Html(html) => rsx! { span { class: style::raw_html, "{html}" } },
+        Inline::Emphasis(content) => rsx! { em { {render_inlines(content)} } },
+        Inline::Strong(content) => rsx! { strong { {render_inlines(content)} } },
+        Inline::Strikethrough(content) => rsx! { del { {render_inlines(content)} } },
+        Inline::Link { url, content } => match safe_url(url) {
+            Some(href) => rsx! {
+                Link { href: href.to_string(), external: is_external(href),
+                    {render_inlines(content)}
+                }
+            },
+            None => render_inlines(content),
+        },
+        Inline::Image { url, title, alt } => match safe_url(url) {
+            Some(src) => rsx! {
+                img {
+                    class: style::image,
+                    src: "{src}",
+                    alt: "{alt}",
+                    title: if title.is_empty() { None } else { Some(title.clone()) },
+                }
+            },
+            None => rsx! { "{alt}" },
+        },
+        Inline::SoftBreak => rsx! { "\n" },
+        Inline::HardBreak => rsx! { br {} },
+    }
+}
+
+fn render_blocks(blocks: &[Block]) -> Element {
+    rsx! {
+        for block in blocks {
+            {render_block(block)}
+        }
+    }
+}
+
+fn render_block(block: &Block) -> Element {
+    match block {
+        Block::Paragraph(content) => {
+            rsx! { p { class: style::paragraph, {render_inlines(content)} } }
+        }
+        Block::Plain(content) => render_inlines(content),
+        Block::Heading { level, content } => {
+            let content = render_inlines(content);
+            match level {
+                1 => rsx! { h1 { class: style::heading, {content} } },
+                2 => rsx! { h2 { class: style::heading, {content} } },
+                3 => rsx! { h3 { class: style::heading, {content} } },
+                4 => rsx! { h4 { class: style::heading, {content} } },
+                5 => rsx! { h5 { class: style::heading, {content} } },
+                _ => rsx! { h6 { class: style::heading, {content} } },
+            }
+        }
+        Block::Code { language, code } => rsx! {
+            div { class: style::code,
+                CodeBlock {
+                    code: code.clone(),
+                    language: *language,
+                    show_language: language.is_some(),
+                }
+            }
+        },
+        Block::Quote(blocks) => {
+            rsx! { blockquote { class: style::quote, {render_blocks(blocks)} } }
+        }
+        Block::List { items, .. } if items.iter().all(|item| item.checked.is_some()) => rsx! {
+            div { class: style::tasks,
+                TodoList {
+                    for item in items {
+                        TodoItem { checked: item.checked == Some(true), disabled: true,
+                            {render_blocks(&item.blocks)}
+                        }
+                    }
+                }
+            }
+        },
+        Block::List { start, items } => {
+            let items = rsx! {
+                for item in items {
+                    li {
+                        if let Some(checked) = item.checked {
+                            input { r#type: "checkbox", checked, disabled: true }
+                        }
+                        {render_blocks(&item.blocks)}
+                    }
+                }
+            };
+            match start {
+                Some(start) => rsx! { ol { class: style::list, start: "{start}", {items} } },
+                None => rsx! { ul { class: style::list, {items} } },
+            }
+        }
+        Block::Table {
+            alignments,
+            head,
+            rows,
+        } => {
+            let align = |idx: usize| alignments.get(idx).copied().unwrap_or("left").to_string();
+            rsx! {
+                div { class: style::table,
+                    Table {
+                        TableHeader {
+                            TableRow {
+                                for (idx, cell) in head.iter().enumerate() {
+                                    TableHeaderCell { align: align(idx), {render_inlines(cell)} }
+                                }
+                            }
+                        }
+                        TableBody {
+                            for row in rows {
+                                TableRow {
+                                    for (idx, cell) in row.iter().enumerate() {
+                                        TableCell { align: align(idx), {render_inlines(cell)} }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Block::Rule => rsx! { Separator { class: style::rule } },
+        Block::Html(html) => rsx! { pre { class: style::raw_html, "{html}" } },
+    }
+}
+
+/// Whether a line is a table delimiter row (`| --- | :-: |`)
+fn is_delimiter_row(line: &str) -> bool {
+    let line = line.trim();
+    line.contains('-')
+        && line
+            .chars()
+            .all(|c| matches!(c, '|' | ':' | '-' | ' ' | '\t'))
+}
+
+/// Code fence on a line: its character, its length and the text after it
+///
+/// Fences are three or more backticks or tildes indented by at most three
+/// spaces; more indentation makes the line indented code instead.
+fn code_fence(line: &str) -> Option<(char, usize, &str)> {
+    let indent = line.len() - line.trim_start_matches(' ').len();
+    if indent > 3 {
+        return None;
+    }
+    let line = &line[indent..];
+    let marker = line.chars().next().filter(|c| matches!(c, '`' | '~'))?;
+    let len = line.len() - line.trim_start_matches(marker).len();
+    (len >= 3).then_some((marker, len, &line[len..]))
+}
+
+/// Whether the source ends inside an unterminated code fence
+///
+/// Only a fence of the same character, at least as long as the opening one and
+/// with nothing after it, closes the block.
+fn in_open_fence(source: &str) -> bool {
+    let mut open: Option<(char, usize)> = None;
+    for line in source.lines() {
+        let Some((marker, len, rest)) = code_fence(line) else {
+            continue;
+        };
+        match open {
+            Some((open_marker, open_len)) => {
+                if marker == open_marker && len >= open_len && rest.trim().is_empty() {
+                    open = None;
+                }
+            }
+            // The info string of a backtick fence can't contain backticks
+            None if marker == '`' && rest.contains('`') => {}
+            None => open = Some((marker, len)),
+        }
+    }
+    open.is_some()
+}
+
+/// Cut the end of a streaming source that would render differently once complete
+///
+/// An incomplete last line made only of marker characters may still become a
+/// code fence, a setext underline or a thematic break, and trailing table rows
+/// render as a paragraph until the delimiter row arrives, so both are held back
+/// rather than flickering between layouts.
+fn renderable_prefix(source: &str) -> &str {
+    if in_open_fence(source) {
+        return source;
+    }
+
+    let mut end = source.len();
+    if !source.ends_with('\n') {
+        let line_start = source.rfind('\n').map_or(0, |pos| pos + 1);
+        let line = source[line_start..].trim();
+        // Marker characters only, or a list number still waiting for its `.`
+        let is_marker = line.chars().all(|c| "`~-*_=#+>|: ".contains(c))
+            || line
+                .chars()
+                .all(|c| c.is_ascii_digit() || c == '.' || c == ')');
+        if line.starts_with('|') || is_marker {
+            end = line_start;
+        }
+    }
+
+    let mut table_start = None;
+    let mut has_delimiter = false;
+    let mut pos = end;
+    for line in source[..end].split_inclusive('\n').rev() {
+        if !line.trim_start().starts_with('|') {
+            break;
+        }
+        has_delimiter |= is_delimiter_row(line);
+        pos -= line.len();
+        table_start = Some(pos);
+    }
+    if let Some(start) = table_start
+        && !has_delimiter
+    {
+        end = start;
+    }
+
+    &source[..end]
+}
+
+/// Parsed blocks kept across renders while a message streams in
+///
+/// Every top-level block but the last is final once a later block has started,
+/// so those are kept and only the source from the last block onward is parsed
+/// again when more content arrives. Blocks with references nothing defines yet
+/// stay unfinished, since a definition may still arrive further down.
+#[derive(Default)]
+struct StreamState {
+    /// Source of the finished blocks
+    prefix: String,
+    /// Finished blocks
+    blocks: Vec<Rc<Block>>,
+    /// Reference definitions in the finished source
+    references: References,
+}
+
+impl StreamState {
+    fn update(&mut self, content: &str) -> Vec<Rc<Block>> {
+        if !content.starts_with(self.prefix.as_str()) {
+            // Not an append (edited or replaced content): start over
+            *self = Self::default();
+        }
+
+        let tail = renderable_prefix(&content[self.prefix.len()..]);
+        let parsed = parse_blocks(tail, &self.references);
+        let line_start = |offset: usize| tail[..offset].rfind('\n').map_or(0, |pos| pos + 1);
+
+        // Finish everything before the line the last block starts on, or the
+        // first block with a reference that isn't settled: undefined so far, or
+        // defined in the unfinished part where the definition may still change
+        let mut finished = parsed.starts.last().map_or(0, |&start| line_start(start));
+        loop {
+            let unsettled = parsed
+                .references
+                .iter()
+                .filter(|(_, label)| {
+                    parsed
+                        .definitions
+                        .iter()
+                        .any(|(start, defined, ..)| defined == label && *start >= finished)
+                })
+                .map(|(offset, _)| *offset);
+            let first = parsed.unresolved.iter().copied().chain(unsettled).min();
+            let cut = first
+                .and_then(|first| parsed.starts.iter().rev().find(|&&start| start <= first))
+                .map(|&start| line_start(start));
+            match cut {
+                Some(cut) if cut < finished => finished = cut,
+                _ => break,
+            }
+        }
+        let mut blocks = parsed
+            .blocks
+            .into_iter()
+            .map(Rc::new)
+            .zip(parsed.starts)
+            .peekable();
+        while let Some((block, _)) = blocks.next_if(|(_, start)| *start < finished) {
+            self.blocks.push(block);
+        }
+        for (start, label, url, title) in parsed.definitions {
+            if start < finished {
+                // The first definition of a label wins
+                self.references.entry(label).or_insert((url, title));
+            }
+        }
+        self.prefix.push_str(&tail[..finished]);
+
+        self.blocks
+            .iter()
+            .cloned()
+            .chain(blocks.map(|(block, _)| block))
+            .collect()
+    }
+}
+
+/// A top-level block, memoized so finished blocks aren't re-rendered
+#[component]
+fn MarkdownBlock(block: Rc<Block>) -> Element {
+    render_block(&block)
+}
+
+/// Markdown content (CommonMark with GFM tables, task lists and strikethrough)
+///
+/// Code fences, inline code, links, tables and task lists render through the
+/// matching glade components. Raw HTML is never interpreted: it is shown as
+/// escaped text, and links or images with unsafe URL schemes are dropped.
+///
+/// Set `streaming` while `content` is still growing (an LLM response arriving
+/// token by token): finished blocks are parsed once and kept, and unfinished
+/// constructs such as a half-written table are held back until they settle.
+#[component]
+pub fn Markdown(
+    /// Markdown source
+    content: String,
+    /// Whether more content is still being appended
+    #[props(default = false)]
+    streaming: bool,
+    /// Additional CSS class
+    #[props(default)]
+    class: Option<String>,
+) -> Element {
+    let state = use_hook(|| Rc::new(RefCell::new(StreamState::default())));
+
+    let blocks: Vec<Rc<Block>> = if streaming {
+        state.borrow_mut().update(&content)
+    } else {
+        *state.borrow_mut() = StreamState::default();
+        parse_blocks(&content, &References::new())
+            .blocks
+            .into_iter()
+            .map(Rc::new)
+            .collect()
+    };
+
+    rsx! {
+        div { class: stylance::classes!(style::markdown, class.as_deref().unwrap_or("")),
+            for (idx, block) in blocks.into_iter().enumerate() {
+                MarkdownBlock { key: "{idx}", block }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Stream `source` in chunks of `size` bytes, returning the final blocks
+    fn stream(source: &str, size: usize) -> Vec<Block> {
+        let mut state = StreamState::default();
+        let mut end = 0;
+        while end < source.len() {
+            end = (end + size).min(source.len());
+            while !source.is_char_boundary(end) {
+                end += 1;
+            }
+            state.update(&source[..end]);
+        }
+        state
+            .update(source)
+            .iter()
+            .map(|block| (**block).clone())
+            .collect()
+    }
+
+    fn parse(source: &str) -> Vec<Block> {
+        parse_blocks(source, &References::new()).blocks
+    }
+
+    const SAMPLES: &[&str] = &[
+        "[d]: https://example.com\n\nIntro.\n\nSee [the docs][d] now.\n",
+        "See [the docs][d] and [D] now.\n\nMore text.\n\n[d]: https://example.com \"Docs\"\n\nAfter.\n",
+        "[a]: /first\n\nText.\n\n[a]: /second\n\nUse [a].\n",
+        "# Title\n\nSome *text* with `code`.\n\n- one\n- two\n\n| a | b |\n| - | - |\n| 1 | 2 |\n\nEnd.\n",
+        "Array [0] and [x] stay text.\n\nNext paragraph.\n",
+    ];
+
+    #[test]
+    fn streaming_matches_a_full_parse() {
+        for source in SAMPLES {
+            for size in [1, 3, 7, 16, source.len()] {
+                assert_eq!(stream(source, size), parse(source), "{source:?} by {size}");
+            }
+        }
+    }
+
+    #[test]
+    fn earlier_definitions_resolve_references_in_the_tail() {
+        let source = SAMPLES[0];
+        let blocks = stream(source, 5);
+        let Some(Block::Paragraph(content)) = blocks.last() else {
+            panic!("{blocks:?}");
+        };
+        assert!(content.iter().any(
+            |inline| matches!(inline, Inline::Link { url, .. } if url == "https://example.com")
+        ));
+    }
+
+    #[test]
+    fn blocks_with_unresolved_references_stay_unfinished() {
+        let mut state = StreamState::default();
+        state.update("See [the docs][d].\n\nMore.\n\nAnd more");
+        assert!(state.blocks.is_empty());
+        // A definition still being written doesn't settle the reference either
+        state.update("See [the docs][d].\n\nMore.\n\nAnd more.\n\n[d]: https://exa");
+        assert!(state.blocks.is_empty());
+        state.update("See [the docs][d].\n\nMore.\n\nAnd more.\n\n[d]: https://example.com\n\nEnd");
+        assert_eq!(state.blocks.len(), 3);
+    }
+
+    #[test]
+    fn fences_close_with_the_same_marker_and_length() {
+        assert!(in_open_fence("```rust\nfn main() {}\n"));
+        assert!(!in_open_fence("```rust\nfn main() {}\n```\n"));
+        assert!(in_open_fence("````md\n```\nnested\n```\n"));
+        assert!(!in_open_fence("````md\n```\nnested\n```\n`````\n"));
+        assert!(in_open_fence("~~~\n```\n"));
+        assert!(in_open_fence("```\n``` not a closing fence\n"));
+        assert!(!in_open_fence("```\ncode\n   ```  \n"));
+    }
+
+    #[test]
+    fn fences_follow_the_indentation_rule() {
+        assert!(in_open_fence("   ```\n"));
+        assert!(!in_open_fence("    ```\n"));
+        assert!(in_open_fence("```\n    ```\n"));
+        assert!(!in_open_fence("``` a`b\n"));
+        assert!(in_open_fence("~~~ a`b\n"));
+    }
+
+    #[test]
+    fn streaming_nested_fences_matches_a_full_parse() {
+        let source = "Intro.\n\n````md\n```rust\nfn main() {}\n```\n\nText\n````\n\nAfter.\n";
+        for size in [1, 4, 9] {
+            assert_eq!(stream(source, size), parse(source));
+        }
+    }
+
+    #[test]
+    fn finished_blocks_are_kept() {
+        let mut state = StreamState::default();
+        state.update("One.\n\nTwo.\n\nThree");
+        assert_eq!(state.blocks.len(), 2);
+        assert_eq!(state.prefix, "One.\n\nTwo.\n\n");
+    }
+}
@@ -136,6 +136,10 @@ pub mod sunburst;
 pub mod color_swatch;
 #[doc = " Git diff statistics display"]
 pub mod diff_stats;
+#[doc = " Unified diff viewer with unified and split layouts"]
+pub mod diff_viewer;
+#[doc = " File tree with expand/collapse, selection and lazy loading"]
+pub mod file_tree;
 #[doc = " Tool call badge for tool execution status"]
 pub mod tool_call_badge;
 #[doc = " Todo list with dashed circle checkboxes"]
@@ -144,6 +148,24 @@ pub mod todo_list;
 pub mod retry_button;
 #[doc = " Code execution result panel"]
 pub mod code_execution_result;
+#[doc = " ANSI-colored terminal output"]
+pub mod ansi_text;
+#[doc = " Virtualized log viewer with search and follow-tail"]
+pub mod log_viewer;
+#[doc = " Collapsible JSON document viewer"]
+pub mod json_tree;
+#[doc = " Column layout state shared by tables"]
+pub mod column_layout;
+#[doc = " Table with column definitions, sorting and filtering"]
+pub mod data_table;
+#[doc = " CSV, TSV and Markdown export of table contents"]
+pub mod table_export;
+#[doc = " Table with expandable parent and child rows"]
+pub mod tree_table;
+#[doc = " Windowed Table and List for large row counts"]
+pub mod virtualized;
+#[doc = " Markdown renderer built on glade components"]
+pub mod markdown;
 #[doc = " Site footer component"]
 pub mod footer;
 #[doc = " Application shell layout"]
@@ -162,6 +184,18 @@ pub mod aspect_ratio;
 pub mod rating;
 #[doc = " Statistics display"]
 pub mod stat;
+#[doc = " SVG line, area and bar charts and sparklines"]
+pub mod chart;
+#[doc = " GitHub-style activity heatmap"]
+pub mod activity_heatmap;
+#[doc = " Timeline of spans with zoom and pan"]
+pub mod timeline;
+#[doc = " Kanban board with draggable cards"]
+pub mod kanban_board;
+#[doc = " Searchable select with async options"]
+pub mod combobox;
+#[doc = " Multi-value select shown as tags"]
+pub mod multi_select;
 #[doc = " Multi-step progress indicator"]
 pub mod steps;
 #[doc = " File input with drag-and-drop"]
@@ -176,8 +210,10 @@ pub mod descriptions;
 pub mod alert_dialog;
 #[doc = " Custom scrollbar container"]
 pub mod scroll_area;
-#[doc = " Calendar date picker"]
+#[doc = " Calendar for picking a date or a date range"]
 pub mod calendar;
+#[doc = " Date input with a calendar popover"]
+pub mod date_picker;
 #[doc = " Carousel/slider component"]
 pub mod carousel;
 
@@ -196,11 +232,13 @@ pub use empty_state::EmptyState;
 pub use icon::{Icon, IconSize};
 pub use icon_button::{IconButton, IconButtonSize, IconButtonVariant};
 pub use icons::{
-    IconAlertCircle, IconArchive, IconBuilding2, IconCheck, IconChevronDown, IconChevronLeft,
-    IconChevronRight, IconCircleCheck, IconCircleX, IconClipboardList, IconExternalLink,
-    IconFileText, IconFilter, IconGithub, IconGlobe, IconInfo, IconLoader, IconMapPin, IconMenu,
-    IconMic, IconMinus, IconPlus, IconSearch, IconServer, IconTriangleAlert, IconUser, IconX,
-    IconYoutube,
+    IconAlertCircle, IconArchive, IconArrowDownToLine, IconArrowLeftToLine, IconArrowRightToLine,
+    IconBuilding2, IconCalendar, IconCheck, IconChevronDown, IconChevronLeft, IconChevronRight,
+    IconChevronUp, IconCircleCheck, IconCircleX, IconColumns3, IconDownload,
+    IconClipboardList, IconExternalLink, IconFileText, IconFilter, IconFolder, IconFolderOpen,
+    IconGithub, IconGlobe, IconInfo, IconLoader, IconMapPin, IconMenu, IconMic, IconMinus,
+    IconPlus, IconSearch, IconServer, IconTextWrap, IconTriangleAlert, IconUser, IconX,
+    IconYoutube, IconZoomIn, IconZoomOut,
 };
 pub use input::{Input, InputSize};
 pub use modal::{Modal, ModalBody, ModalFooter, ModalHeader, ModalSize};
@@ -251,7 +289,10 @@ pub use popover::{Popover, PopoverContent, PopoverPosition};
 pub use context_menu::{
     ContextMenu, ContextMenuContent, ContextMenuDivider, ContextMenuItem, ContextMenuTrigger,
 };
-pub use code_block::{CodeBlock, CodeBlockSize, InlineCode, Language};
+pub use code_block::{
+    AnnotationSeverity, CodeBlock, CodeBlockSize, InlineCode, Language, LineAnnotation,
+    UnknownLanguage,
+};
 pub use split_pane::{Panel, SplitDirection, SplitPane};
 
 // Chat / Messaging exports
@@ -270,10 +311,24 @@ pub use pulsing_dots::{PulsingDots, PulsingDotsSize};
 pub use sunburst::{Sunburst, SunburstSize};
 pub use color_swatch::{ColorFormat, ColorSwatch};
 pub use diff_stats::{DiffFileSummary, DiffStats, DiffStatsStyle};
+pub use diff_viewer::{
+    parse_diff, DiffFile, DiffFileStatus, DiffHunk, DiffLine, DiffLineKind, DiffViewMode,
+    DiffViewer,
+};
+pub use file_tree::{FileDecoration, FileTree, FileTreeNode};
 pub use tool_call_badge::{ToolCallBadge, ToolCallStatus};
 pub use todo_list::{TodoItem, TodoList, TodoSize};
 pub use retry_button::{RetryButton, RetryButtonState};
 pub use code_execution_result::{CodeExecutionResult, ExecutionStatus};
+pub use ansi_text::AnsiText;
+pub use log_viewer::LogViewer;
+pub use json_tree::JsonTree;
+pub use column_layout::{ColumnLayout, ColumnPin};
+pub use data_table::{Column, ColumnSort, DataTable, SortDirection, visible_rows};
+pub use table_export::{ExportFormat, TableData, TableExport};
+pub use tree_table::{TreeRow, TreeTable};
+pub use virtualized::{RowHeight, VirtualList, VirtualTable};
+pub use markdown::Markdown;
 pub use footer::{Footer, FooterBottom, FooterCopyright, FooterLink, FooterLinks, FooterSection};
 pub use app_shell::{AppContent, AppHeader, AppShell};
 pub use pagination::{Pagination, PaginationSize, SimplePagination};
@@ -283,6 +338,12 @@ pub use collapsible::{Collapsible, CollapsiblePanel};
 pub use aspect_ratio::{AspectRatio, AspectRatioPreset};
 pub use rating::{Rating, RatingDisplay, RatingSize};
 pub use stat::{Stat, StatCard, StatGroup, StatSize, StatTrend};
+pub use chart::{Chart, ChartKind, Series, Sparkline, format_compact};
+pub use activity_heatmap::ActivityHeatmap;
+pub use timeline::{Timeline, TimelineRow, TimelineSpan, format_duration};
+pub use kanban_board::{KanbanBoard, KanbanColumn, KanbanMove};
+pub use combobox::{Combobox, ComboboxOption};
+pub use multi_select::MultiSelect;
 pub use steps::{Step, StepButton, StepStatus, Steps, StepsOrientation, StepsSize};
 pub use file_input::{FileInput, FileInputButton, FileInputSize};
 pub use hover_card::{HoverCard, HoverCardPosition, ProfileHoverCard};
@@ -290,5 +351,6 @@ pub use list::{List, ListItem, ListItemContent, ListSection, ListSize, ListVaria
 pub use descriptions::{DescriptionItem, Descriptions, DescriptionsLayout, DescriptionsSize};
 pub use alert_dialog::{AlertDialog, AlertDialogVariant};
 pub use scroll_area::{ScrollArea, ScrollDirection, ScrollbarVisibility};
-pub use calendar::{Calendar, CalendarSize};
+pub use calendar::{Calendar, CalendarSize, RangePreset};
+pub use date_picker::{DatePicker, parse_date};
 pub use carousel::{Carousel, CarouselNavigation, CarouselSlide};
@@ -0,0 +1,417 @@
+//! Multi-select: pick several values from a filterable list, shown as tags
+
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use dioxus::prelude::*;
+
+use crate::components::combobox::{group_options, group_runs, step_active};
+use crate::{ComboboxOption, IconCheck, IconChevronDown, Tag};
+
+stylance::import_style!(style, "multi_select.module.scss");
+
+/// Element key of the "Select all" entry, which can't collide with an option value
+const SELECT_ALL_KEY: &str = "\0select-all";
+
+/// Select several values from `options`.
+///
+/// Chosen values show as [`Tag`] chips; typing filters the list by label and
+/// description. The list stays open while picking, and Backspace in an empty
+/// input removes the last chip. Options with a `group` are listed under
+/// headings, and `select_all` adds an entry toggling every visible option.
+#[component]
+pub fn MultiSelect(
+    /// Options to choose from
+    options: Vec<ComboboxOption>,
+    /// Selected values, in the order they were picked
+    #[props(default)]
+    values: Vec<String>,
+    #[props(default)] label: String,
+    #[props(default = "Select...".to_string())] placeholder: String,
+    #[props(default)] error: String,
+    #[props(default = false)] disabled: bool,
+    #[props(default = false)] required: bool,
+    /// Maximum number of values (0 = unlimited)
+    #[props(default = 0)]
+    max_selected: usize,
+    /// Offer a "Select all" entry for the visible options
+    #[props(default = false)]
+    select_all: bool,
+    /// Shown when no option matches
+    #[props(default = "No results".to_string())]
+    empty_message: String,
+    /// Called with the new list of values
+    onchange: Option<EventHandler<Vec<String>>>,
+    /// Called when the input loses focus
+    onblur: Option<EventHandler<FocusEvent>>,
+    /// Called with the input element once mounted, e.g. to focus it
+    onmounted: Option<EventHandler<MountedEvent>>,
+) -> Element {
+    static COUNTER: AtomicU32 = AtomicU32::new(0);
+    let id = use_hook(|| format!("multi-select-{}", COUNTER.fetch_add(1, Ordering::Relaxed)));
+
+    let mut open = use_signal(|| false);
+    let mut query = use_signal(String::new);
+    let mut active = use_signal(|| None::<usize>);
+    let mut option_elements = use_signal(HashMap::<String, Rc<MountedData>>::new);
+
+    let trimmed = query().trim().to_lowercase();
+    let shown = group_options(
+        options
+            .iter()
+            .filter(|o| trimmed.is_empty() || o.matches(&trimmed))
+            .cloned()
+            .collect(),
+    );
+    let at_limit = max_selected > 0 && values.len() >= max_selected;
+    let selectable = |o: &ComboboxOption| !o.disabled && (!at_limit || values.contains(&o.value));
+
+    // Values "Select all" toggles: every enabled option in view
+    let visible: Vec<String> = shown
+        .iter()
+        .filter(|o| !o.disabled)
+        .map(|o| o.value.clone())
+        .collect();
+    let all_selected = !visible.is_empty() && visible.iter().all(|v| values.contains(v));
+    let fits = max_selected == 0
+        || values.len() + visible.iter().filter(|v| !values.contains(v)).count() <= max_selected;
+    let show_select_all = select_all && !visible.is_empty() && (all_selected || fits);
+    let offset = usize::from(show_select_all);
+
+    let keys: Vec<String> = show_select_all
+        .then(|| SELECT_ALL_KEY.to_string())
+        .into_iter()
+        .chain(shown.iter().map(|o| o.value.clone()))
+        .collect();
+    let enabled: Vec<bool> = show_select_all
+        .then_some(true)
+        .into_iter()
+        .chain(shown.iter().map(selectable))
+        .collect();
+    let active_index = active().filter(|&i| i < enabled.len());
+    let is_open = open() && !disabled;
+
+    let listbox_id = format!("{id}-listbox");
+    let option_id = {
+        let id = id.clone();
+        move |index: usize| format!("{id}-option-{index}")
+    };
+    let active_descendant = match active_index {
+        Some(index) if is_open => option_id(index),
+        _ => String::new(),
+    };
+
+    let change = move |next: Vec<String>| {
+        if let Some(handler) = &onchange {
+            handler.call(next);
+        }
+    };
+
+    let toggle = {
+        let values = values.clone();
+        move |value: String| {
+            let mut next = values.clone();
+            if let Some(position) = next.iter().position(|v| *v == value) {
+                next.remove(position);
+            } else if max_selected == 0 || next.len() < max_selected {
+                next.push(value);
+            } else {
+                return;
+            }
+            // Clearing the query reshuffles the list, so the active row is stale
+            if !query.peek().is_empty() {
+                query.set(String::new());
+                active.set(None);
+            }
+            change(next);
+        }
+    };
+
+    let toggle_all = {
+        let values = values.clone();
+        let visible = visible.clone();
+        move || {
+            let next: Vec<String> = if all_selected {
+                values
+                    .iter()
+                    .filter(|v| !visible.contains(v))
+                    .cloned()
+                    .collect()
+            } else {
+                let missing = visible.iter().filter(|v| !values.contains(v)).cloned();
+                values.iter().cloned().chain(missing).collect()
+            };
+            change(next);
+        }
+    };
+
+    let mut set_active = move |index: Option<usize>, key: Option<String>| {
+        active.set(index);
+        let element = key.and_then(|key| option_elements.peek().get(&key).cloned());
+        if let Some(element) = element {
+            spawn(async move {
+                let _ = element
+                    .scroll_to_with_options(ScrollToOptions {
+                        behavior: ScrollBehavior::Instant,
+                        vertical: ScrollLogicalPosition::Nearest,
+                        horizontal: ScrollLogicalPosition::Nearest,
+                    })
+                    .await;
+            });
+        }
+    };
+
+    let keydown_shown = shown.clone();
+    let keydown_values = values.clone();
+    let mut keydown_toggle = toggle.clone();
+    let keydown_toggle_all = toggle_all.clone();
+    let handle_keydown = move |evt: KeyboardEvent| {
+        let key_at = |index: Option<usize>| index.and_then(|i| keys.get(i).cloned());
+        match evt.key() {
+            Key::ArrowDown => {
+                evt.prevent_default();
+                if !is_open {
+                    open.set(true);
+                    if evt.modifiers().alt() {
+                        return;
+                    }
+                }
+                let next = step_active(&enabled, active_index, true);
+                set_active(next, key_at(next));
+            }
+            Key::ArrowUp => {
+                evt.prevent_default();
+                if !is_open {
+                    open.set(true);
+                }
+                let next = step_active(&enabled, active_index, false);
+                set_active(next, key_at(next));
+            }
+            Key::Home if is_open => {
+                evt.prevent_default();
+                let next = step_active(&enabled, None, true);
+                set_active(next, key_at(next));
+            }
+            Key::End if is_open => {
+                evt.prevent_default();
+                let next = step_active(&enabled, None, false);
+                set_active(next, key_at(next));
+            }
+            Key::Enter if is_open => {
+                // Keep Enter from submitting a surrounding form while picking
+                evt.prevent_default();
+                match active_index {
+                    Some(0) if show_select_all => keydown_toggle_all(),
+                    Some(index) if enabled[index] => {
+                        keydown_toggle(keydown_shown[index - offset].value.clone())
+                    }
+                    _ => {}
+                }
+            }
+            Key::Escape if is_open => {
+                evt.prevent_default();
+                open.set(false);
+                active.set(None);
+            }
+            Key::Backspace if query().is_empty() => {
+                if let Some(last) = keydown_values.last() {
+                    keydown_toggle(last.clone());
+                }
+            }
+            _ => {}
+        }
+    };
+
+    let label_of = |value: &str| {
+        options
+            .iter()
+            .find(|o| o.value == value)
+            .map_or_else(|| value.to_string(), |o| o.label.clone())
+    };
+    let chips: Vec<(String, String)> = values.iter().map(|v| (v.clone(), label_of(v))).collect();
+    let error_class = if !error.is_empty() { style::error } else { "" };
+
+    rsx! {
+        div { class: style::wrapper,
+            if !label.is_empty() {
+                label { class: style::label, r#for: "{id}",
+                    "{label}"
+                    if required {
+                        span { class: style::required, " *" }
+                    }
+                }
+            }
+            div {
+                class: stylance::classes!(
+                    style::control,
+                    error_class,
+                    if is_open { style::open } else { "" },
+                    if disabled { style::disabled } else { "" }
+                ),
+                for (value, chip) in chips {
+                    Tag {
+                        key: "{value}",
+                        label: chip,
+                        removable: !disabled,
+                        on_remove: {
+                            let mut toggle = toggle.clone();
+                            EventHandler::new(move |_| toggle(value.clone()))
+                        },
+                    }
+                }
+                input {
+                    id: "{id}",
+                    class: style::input,
+                    r#type: "text",
+                    role: "combobox",
+                    autocomplete: "off",
+                    aria_autocomplete: "list",
+                    aria_expanded: "{is_open}",
+                    aria_controls: "{listbox_id}",
+                    aria_activedescendant: "{active_descendant}",
+                    aria_invalid: if !error.is_empty() { "true" } else { "false" },
+                    placeholder: if values.is_empty() { placeholder.clone() } else { String::new() },
+                    value: "{query}",
+                    disabled,
+                    oninput: move |evt| {
+                        query.set(evt.value());
+                        open.set(true);
+                        active.set(None);
+                    },
+                    onclick: move |_| {
+                        if !disabled {
+                            open.set(true);
+                        }
+                    },
+                    onkeydown: handle_keydown,
+                    onblur: move |evt| {
+                        open.set(false);
+                        active.set(None);
+                        query.set(String::new());
+                        if let Some(handler) = &onblur {
+                            handler.call(evt);
+                        }
+                    },
+                    onmounted: move |evt| {
+                        if let Some(handler) = &onmounted {
+                            handler.call(evt);
+                        }
+                    },
+                }
+                if max_selected > 0 {
+                    span { class: style::count, "{values.len()}/{max_selected}" }
+                }
+                span { class: style::chevron, aria_hidden: "true", IconChevronDown {} }
+            }
+            if is_open {
+                div {
+                    id: "{listbox_id}",
+                    class: style::listbox,
+                    role: "listbox",
+                    aria_multiselectable: "true",
+                    aria_label: if label.is_empty() { placeholder.clone() } else { label.clone() },
+                    // Keep focus in the input while clicking options
+                    onmousedown: move |evt| evt.prevent_default(),
+                    if show_select_all {
+                        div {
+                            id: option_id(0),
+                            class: stylance::classes!(
+                                style::option,
+                                style::select_all,
+                                if active_index == Some(0) { style::active } else { "" }
+                            ),
+                            role: "option",
+                            aria_selected: "{all_selected}",
+                            onmounted: move |evt: MountedEvent| {
+                                option_elements.write().insert(SELECT_ALL_KEY.to_string(), evt.data());
+                            },
+                            onmouseenter: move |_| active.set(Some(0)),
+                            onclick: {
+                                let toggle_all = toggle_all.clone();
+                                move |_| toggle_all()
+                            },
+                            span { class: stylance::classes!(style::checkbox, if all_selected { style::checked } else { "" }),
+                                if all_selected {
+                                    IconCheck {}
+                                }
+                            }
+                            "Select all"
+                        }
+                    }
+                    if shown.is_empty() {
+                        div { class: style::status, role: "status", "{empty_message}" }
+                    }
+                    if at_limit {
+                        div { class: style::status, role: "status", "Limit of {max_selected} reached" }
+                    }
+                    for (group, range) in group_runs(&shown) {
+                        div {
+                            key: "{group:?}",
+                            class: style::group,
+                            role: if group.is_some() { "group" } else { "presentation" },
+                            aria_label: group.clone(),
+                            if let Some(name) = &group {
+                                div { class: style::group_label, aria_hidden: "true", "{name}" }
+                            }
+                            for (index, option) in range.clone().map(|i| i + offset).zip(shown[range].iter().cloned()) {
+                                {
+                                    let is_selected = values.contains(&option.value);
+                                    let is_enabled = selectable(&option);
+                                    let mut toggle = toggle.clone();
+                                    rsx! {
+                                        div {
+                                            key: "{option.value}",
+                                            id: option_id(index),
+                                            class: stylance::classes!(
+                                                style::option,
+                                                if active_index == Some(index) { style::active } else { "" },
+                                                if is_enabled { "" } else { style::unavailable }
+                                            ),
+                                            role: "option",
+                                            aria_selected: "{is_selected}",
+                                            aria_disabled: "{!is_enabled}",
+                                            onmounted: {
+                                                let key = option.value.clone();
+                                                move |evt: MountedEvent| {
+                                                    option_elements.write().insert(key.clone(), evt.data());
+                                                }
+                                            },
+                                            onmouseenter: move |_| {
+                                                if is_enabled {
+                                                    active.set(Some(index));
+                                                }
+                                            },
+                                            onclick: {
+                                                let value = option.value.clone();
+                                                move |_| {
+                                                    if is_enabled {
+                                                        toggle(value.clone());
+                                                    }
+                                                }
+                                            },
+                                            span { class: stylance::classes!(style::checkbox, if is_selected { style::checked } else { "" }),
+                                                if is_selected {
+                                                    IconCheck {}
+                                                }
+                                            }
+                                            span { class: style::option_text,
+                                                span { class: style::option_label, "{option.label}" }
+                                                if let Some(description) = &option.description {
+                                                    span { class: style::option_description, "{description}" }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            if !error.is_empty() {
+                span { class: style::error_text, "{error}" }
+            }
+        }
+    }
+}
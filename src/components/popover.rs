@@ -36,8 +36,12 @@ pub fn Popover(
     trigger: Element,
     /// The popover content
     children: Element,
+    /// Open state, when the parent needs to open or close the popover itself
+    #[props(optional)]
+    open: Option<Signal<bool>>,
 ) -> Element {
-    let mut is_open = use_signal(|| false);
+    let internal_open = use_signal(|| false);
+    let mut is_open = open.unwrap_or(internal_open);
     let mut container_ref: Signal<Option<std::rc::Rc<MountedData>>> = use_signal(|| None);
 
     let position_class = match position {
@@ -25,6 +25,10 @@ pub fn Select(
     #[props(default = false)] disabled: bool,
     #[props(default = false)] required: bool,
     onchange: Option<EventHandler<FormEvent>>,
+    /// Called when the field loses focus
+    onblur: Option<EventHandler<FocusEvent>>,
+    /// Called with the native element once mounted, e.g. to focus it
+    onmounted: Option<EventHandler<MountedEvent>>,
     children: Element,
 ) -> Element {
     let size_class = match size {
@@ -56,6 +60,16 @@ pub fn Select(
                             handler.call(evt);
                         }
                     },
+                    onblur: move |evt| {
+                        if let Some(handler) = &onblur {
+                            handler.call(evt);
+                        }
+                    },
+                    onmounted: move |evt| {
+                        if let Some(handler) = &onmounted {
+                            handler.call(evt);
+                        }
+                    },
                     {children}
                 }
                 span { class: style::arrow }
@@ -52,6 +52,9 @@ pub fn Stat(
     /// Optional icon element
     #[props(optional)]
     icon: Option<Element>,
+    /// Optional small chart shown beside the value, such as a `Sparkline`
+    #[props(optional)]
+    chart: Option<Element>,
 ) -> Element {
     let size_class = match size {
         StatSize::Small => style::small,
@@ -113,6 +116,12 @@ pub fn Stat(
                     }
                 }
             }
+
+            if let Some(chart_el) = chart {
+                div { class: style::chart,
+                    {chart_el}
+                }
+            }
         }
     }
 }
@@ -167,6 +176,9 @@ pub fn StatCard(
     /// Optional icon
     #[props(optional)]
     icon: Option<Element>,
+    /// Optional small chart, such as a `Sparkline`
+    #[props(optional)]
+    chart: Option<Element>,
 ) -> Element {
     rsx! {
         div { class: style::stat_card,
@@ -178,6 +190,7 @@ pub fn StatCard(
                 trend,
                 size,
                 icon,
+                chart,
             }
         }
     }
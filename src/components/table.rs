@@ -2,6 +2,8 @@
 
 use dioxus::prelude::*;
 
+use crate::ColumnPin;
+
 stylance::import_style!(style, "table.module.scss");
 
 /// Table size variants
@@ -78,9 +80,21 @@ pub fn TableHeader(children: Element) -> Element {
 
 /// Table body component
 #[component]
-pub fn TableBody(children: Element) -> Element {
+pub fn TableBody(
+    /// Called when the body element is mounted
+    onmounted: Option<EventHandler<MountedEvent>>,
+    children: Element,
+) -> Element {
     rsx! {
-        tbody { class: style::tbody, {children} }
+        tbody {
+            class: style::tbody,
+            onmounted: move |evt| {
+                if let Some(handler) = &onmounted {
+                    handler.call(evt);
+                }
+            },
+            {children}
+        }
     }
 }
 
@@ -98,6 +112,9 @@ pub fn TableRow(
     /// Whether this row is selected
     #[props(default = false)]
     selected: bool,
+    /// Whether this row has the keyboard cursor
+    #[props(default = false)]
+    focused: bool,
     /// Click handler
     onclick: Option<EventHandler<()>>,
     /// Row content (TableCell or TableHeaderCell)
@@ -106,10 +123,11 @@ pub fn TableRow(
     let clickable = onclick.is_some();
     let selected_class = if selected { style::selected } else { "" };
     let clickable_class = if clickable { style::clickable } else { "" };
+    let focused_class = if focused { style::focused } else { "" };
 
     rsx! {
         tr {
-            class: stylance::classes!(style::tr, selected_class, clickable_class),
+            class: stylance::classes!(style::tr, selected_class, clickable_class, focused_class),
             onclick: move |_| {
                 if let Some(handler) = &onclick {
                     handler.call(());
@@ -120,6 +138,20 @@ pub fn TableRow(
     }
 }
 
+/// Inline style shared by header and data cells
+fn cell_style(align: &str, width: Option<f64>, pin: Option<ColumnPin>, pin_offset: f64) -> String {
+    let mut style = format!("text-align: {align};");
+    if let Some(width) = width {
+        style.push_str(&format!(
+            " width: {width}px; min-width: {width}px; max-width: {width}px;"
+        ));
+    }
+    if let Some(pin) = pin {
+        style.push_str(&format!(" {}: {pin_offset}px;", pin.as_str()));
+    }
+    style
+}
+
 /// Table header cell component
 #[component]
 pub fn TableHeaderCell(
@@ -128,11 +160,38 @@ pub fn TableHeaderCell(
     sortable: bool,
     /// Current sort direction (None, Some("asc"), Some("desc"))
     sort_direction: Option<String>,
+    /// Position of this column among several sorted columns (1 = primary)
+    sort_order: Option<usize>,
     /// Click handler for sorting
     onclick: Option<EventHandler<()>>,
+    /// Click handler for sorting that receives the event (check `modifiers()` for shift-click)
+    on_sort_click: Option<EventHandler<MouseEvent>>,
     /// Text alignment
     #[props(default = "left".to_string())]
     align: String,
+    /// Column width in pixels
+    width: Option<f64>,
+    /// Keep the column at an edge while the table scrolls horizontally
+    pin: Option<ColumnPin>,
+    /// Distance from the pinned edge in pixels (width of the columns pinned before it)
+    #[props(default)]
+    pin_offset: f64,
+    /// Show a resize handle; called when the user starts dragging it
+    on_resize_start: Option<EventHandler<MouseEvent>>,
+    /// Whether the column can be dragged to reorder it
+    #[props(default = false)]
+    draggable: bool,
+    /// Highlight the cell as the drop target of a column drag
+    #[props(default = false)]
+    drop_target: bool,
+    /// Called when a column drag starts on this cell
+    ondragstart: Option<EventHandler<DragEvent>>,
+    /// Called when a column is dragged over this cell
+    ondragover: Option<EventHandler<DragEvent>>,
+    /// Called when a column is dropped on this cell
+    ondrop: Option<EventHandler<DragEvent>>,
+    /// Called when a column drag started on this cell ends
+    ondragend: Option<EventHandler<DragEvent>>,
     /// Cell content
     children: Element,
 ) -> Element {
@@ -155,17 +214,66 @@ pub fn TableHeaderCell(
 
     rsx! {
         th {
-            class: stylance::classes!(style::th, sortable_class, sort_class),
-            style: "text-align: {align}",
-            onclick: move |_| {
+            class: stylance::classes!(
+                style::th,
+                sortable_class,
+                sort_class,
+                if pin.is_some() { style::pinned } else { "" },
+                if draggable { style::draggable } else { "" },
+                if drop_target { style::drop_target } else { "" }
+            ),
+            style: cell_style(&align, width, pin, pin_offset),
+            draggable: draggable.then_some("true"),
+            onclick: move |evt| {
                 if let Some(handler) = &onclick {
                     handler.call(());
                 }
+                if let Some(handler) = &on_sort_click {
+                    handler.call(evt);
+                }
+            },
+            ondragstart: move |evt| {
+                if let Some(handler) = &ondragstart {
+                    handler.call(evt);
+                }
+            },
+            ondragover: move |evt| {
+                if let Some(handler) = &ondragover {
+                    handler.call(evt);
+                }
+            },
+            ondrop: move |evt| {
+                if let Some(handler) = &ondrop {
+                    handler.call(evt);
+                }
+            },
+            ondragend: move |evt| {
+                if let Some(handler) = &ondragend {
+                    handler.call(evt);
+                }
             },
             div { class: style::th_content,
                 {children}
                 if sortable {
-                    span { class: style::sort_icon, "{sort_icon}" }
+                    span { class: style::sort_icon,
+                        "{sort_icon}"
+                        if let Some(order) = sort_order {
+                            sup { class: style::sort_order, "{order}" }
+                        }
+                    }
+                }
+            }
+            if let Some(handler) = on_resize_start {
+                span {
+                    class: style::resize_handle,
+                    role: "separator",
+                    aria_orientation: "vertical",
+                    onmousedown: move |evt| {
+                        evt.prevent_default();
+                        evt.stop_propagation();
+                        handler.call(evt);
+                    },
+                    onclick: move |evt| evt.stop_propagation(),
                 }
             }
         }
@@ -182,13 +290,18 @@ pub fn TableCell(
     colspan: Option<u32>,
     /// Row span
     rowspan: Option<u32>,
+    /// Keep the cell at an edge while the table scrolls horizontally
+    pin: Option<ColumnPin>,
+    /// Distance from the pinned edge in pixels
+    #[props(default)]
+    pin_offset: f64,
     /// Cell content
     children: Element,
 ) -> Element {
     rsx! {
         td {
-            class: style::td,
-            style: "text-align: {align}",
+            class: stylance::classes!(style::td, if pin.is_some() { style::pinned } else { "" }),
+            style: cell_style(&align, None, pin, pin_offset),
             colspan: colspan.map(|c| c.to_string()),
             rowspan: rowspan.map(|r| r.to_string()),
             {children}
@@ -0,0 +1,291 @@
+//! Export table contents as CSV, TSV or Markdown
+
+use dioxus::prelude::*;
+
+use crate::utils::{download_text, write_clipboard};
+use crate::{
+    Button, ButtonSize, ButtonVariant, Dropdown, DropdownAlign, DropdownDivider, DropdownItem,
+    DropdownMenu, DropdownTrigger, IconDownload,
+};
+
+stylance::import_style!(style, "table_export.module.scss");
+
+/// Text format for exported tables
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Comma-separated values (RFC 4180)
+    Csv,
+    /// Tab-separated values, as pasted into spreadsheets
+    Tsv,
+    /// GitHub-flavored Markdown table
+    Markdown,
+}
+
+impl ExportFormat {
+    /// All formats, in menu order
+    pub const ALL: [Self; 3] = [Self::Csv, Self::Tsv, Self::Markdown];
+
+    /// Get the format name shown to users
+    #[must_use]
+    pub const fn label(&self) -> &'static str {
+        match self {
+            Self::Csv => "CSV",
+            Self::Tsv => "TSV",
+            Self::Markdown => "Markdown",
+        }
+    }
+
+    /// File extension, without the dot
+    #[must_use]
+    pub const fn extension(&self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Tsv => "tsv",
+            Self::Markdown => "md",
+        }
+    }
+
+    /// MIME type of the exported text
+    #[must_use]
+    pub const fn mime_type(&self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv",
+            Self::Tsv => "text/tab-separated-values",
+            Self::Markdown => "text/markdown",
+        }
+    }
+}
+
+/// Plain-text contents of a table, ready to export
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TableData {
+    /// Header labels
+    pub headers: Vec<String>,
+    /// Text alignment of each column ("left", "center" or "right"); used by Markdown
+    pub alignments: Vec<String>,
+    /// Cell texts, row by row
+    pub rows: Vec<Vec<String>>,
+}
+
+impl TableData {
+    /// Serialize the table in the given format
+    #[must_use]
+    pub fn export(&self, format: ExportFormat) -> String {
+        match format {
+            ExportFormat::Csv => self.to_csv(),
+            ExportFormat::Tsv => self.to_tsv(),
+            ExportFormat::Markdown => self.to_markdown(),
+        }
+    }
+
+    fn lines(&self) -> impl Iterator<Item = &Vec<String>> {
+        std::iter::once(&self.headers).chain(&self.rows)
+    }
+
+    /// Serialize as CSV, quoting fields that contain separators, quotes or line breaks
+    #[must_use]
+    pub fn to_csv(&self) -> String {
+        fn field(text: &str) -> String {
+            if text.contains([',', '"', '\n', '\r']) || text.trim() != text {
+                format!("\"{}\"", text.replace('"', "\"\""))
+            } else {
+                text.to_string()
+            }
+        }
+        self.lines()
+            .map(|line| line.iter().map(|t| field(t)).collect::<Vec<_>>().join(","))
+            .map(|line| line + "\r\n")
+            .collect()
+    }
+
+    /// Serialize as TSV; tabs and line breaks inside cells become spaces
+    #[must_use]
+    pub fn to_tsv(&self) -> String {
+        fn field(text: &str) -> String {
+            text.replace(['\t', '\n', '\r'], " ")
+        }
+        self.lines()
+            .map(|line| line.iter().map(|t| field(t)).collect::<Vec<_>>().join("\t"))
+            .map(|line| line + "\n")
+            .collect()
+    }
+
+    /// Serialize as a GitHub-flavored Markdown table
+    #[must_use]
+    pub fn to_markdown(&self) -> String {
+        fn field(text: &str) -> String {
+            text.replace('\\', "\\\\")
+                .replace('|', "\\|")
+                .replace("\r\n", "<br>")
+                .replace(['\n', '\r'], "<br>")
+        }
+        fn row(cells: &[String], columns: usize) -> String {
+            let mut line = String::from("|");
+            for i in 0..columns {
+                let text = cells.get(i).map(|t| field(t)).unwrap_or_default();
+                line.push_str(&format!(" {text} |"));
+            }
+            line.push('\n');
+            line
+        }
+        let columns = self.headers.len();
+        if columns == 0 {
+            return String::new();
+        }
+        let mut out = row(&self.headers, columns);
+        out.push('|');
+        for i in 0..columns {
+            let rule = match self.alignments.get(i).map(String::as_str) {
+                Some("center") => ":---:",
+                Some("right") => "---:",
+                _ => "---",
+            };
+            out.push_str(&format!(" {rule} |"));
+        }
+        out.push('\n');
+        for cells in &self.rows {
+            out.push_str(&row(cells, columns));
+        }
+        out
+    }
+}
+
+/// Menu to copy a table to the clipboard or download it
+///
+/// Copying works in the browser through the clipboard API, like `CopyInput`;
+/// downloads save `{filename}.csv` (or `.tsv`, `.md`).
+#[component]
+pub fn TableExport(
+    /// Produce the table contents, as displayed (called when an action is picked)
+    data: Callback<(), TableData>,
+    /// Download file name, without extension
+    #[props(default = "table".to_string())]
+    filename: String,
+) -> Element {
+    let mut copied: Signal<Option<ExportFormat>> = use_signal(|| None);
+
+    let mut copy = move |format: ExportFormat| {
+        write_clipboard(&data.call(()).export(format));
+        copied.set(Some(format));
+        #[cfg(target_arch = "wasm32")]
+        spawn(async move {
+            gloo_timers::future::TimeoutFuture::new(2_000).await;
+            copied.set(None);
+        });
+    };
+
+    let label = match copied() {
+        Some(format) => format!("Copied {}", format.label()),
+        None => "Export".to_string(),
+    };
+
+    rsx! {
+        Dropdown { align: DropdownAlign::End,
+            DropdownTrigger {
+                Button { variant: ButtonVariant::Ghost, size: ButtonSize::Small,
+                    IconDownload { class: style::icon }
+                    "{label}"
+                }
+            }
+            DropdownMenu {
+                for format in ExportFormat::ALL {
+                    DropdownItem { key: "copy-{format.extension()}", onclick: move |_| copy(format),
+                        "Copy as {format.label()}"
+                    }
+                }
+                DropdownDivider {}
+                for format in ExportFormat::ALL {
+                    DropdownItem {
+                        key: "download-{format.extension()}",
+                        onclick: {
+                            let filename = format!("{filename}.{}", format.extension());
+                            move |_| download_text(&filename, format.mime_type(), &data.call(()).export(format))
+                        },
+                        "Download {format.label()}"
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(rows: &[&[&str]]) -> TableData {
+        TableData {
+            headers: vec!["Name".into(), "Note".into()],
+            alignments: vec!["left".into(), "right".into()],
+            rows: rows
+                .iter()
+                .map(|row| row.iter().map(|t| t.to_string()).collect())
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn csv_leaves_plain_fields_alone() {
+        let data = table(&[&["ada", "42"]]);
+        assert_eq!(data.to_csv(), "Name,Note\r\nada,42\r\n");
+    }
+
+    #[test]
+    fn csv_quotes_separators_quotes_and_line_breaks() {
+        let data = table(&[
+            &["a,b", r#"say "hi""#],
+            &["two\nlines", "cr\r"],
+            &[" padded", ""],
+        ]);
+        assert_eq!(
+            data.to_csv(),
+            "Name,Note\r\n\
+             \"a,b\",\"say \"\"hi\"\"\"\r\n\
+             \"two\nlines\",\"cr\r\"\r\n\
+             \" padded\",\r\n"
+        );
+    }
+
+    #[test]
+    fn tsv_flattens_tabs_and_line_breaks() {
+        let data = table(&[&["a\tb", "c\r\nd"]]);
+        assert_eq!(data.to_tsv(), "Name\tNote\na b\tc  d\n");
+    }
+
+    #[test]
+    fn markdown_uses_alignments() {
+        let mut data = table(&[&["ada", "42"]]);
+        data.alignments[0] = "center".into();
+        assert_eq!(
+            data.to_markdown(),
+            "| Name | Note |\n| :---: | ---: |\n| ada | 42 |\n"
+        );
+    }
+
+    #[test]
+    fn markdown_escapes_pipes_backslashes_and_line_breaks() {
+        let data = table(&[&["a|b", r"c:\dir"], &["one\r\ntwo", "three\nfour\rfive"]]);
+        assert_eq!(
+            data.to_markdown(),
+            "| Name | Note |\n\
+             | --- | ---: |\n\
+             | a\\|b | c:\\\\dir |\n\
+             | one<br>two | three<br>four<br>five |\n"
+        );
+    }
+
+    #[test]
+    fn markdown_pads_short_rows_and_skips_headerless_tables() {
+        let data = table(&[&["only"]]);
+        assert!(data.to_markdown().ends_with("| only |  |\n"));
+        assert_eq!(TableData::default().to_markdown(), "");
+    }
+
+    #[test]
+    fn export_dispatches_on_format() {
+        let data = table(&[&["ada", "42"]]);
+        assert_eq!(data.export(ExportFormat::Csv), data.to_csv());
+        assert_eq!(data.export(ExportFormat::Tsv), data.to_tsv());
+        assert_eq!(data.export(ExportFormat::Markdown), data.to_markdown());
+    }
+}
@@ -27,6 +27,10 @@ pub fn Textarea(
     #[props(default = false)] required: bool,
     #[props(default = 4)] rows: u32,
     oninput: Option<EventHandler<FormEvent>>,
+    /// Called when the field loses focus
+    onblur: Option<EventHandler<FocusEvent>>,
+    /// Called with the native element once mounted, e.g. to focus it
+    onmounted: Option<EventHandler<MountedEvent>>,
 ) -> Element {
     let size_class = match size {
         TextareaSize::Small => style::small,
@@ -58,6 +62,16 @@ pub fn Textarea(
                         handler.call(evt);
                     }
                 },
+                onblur: move |evt| {
+                    if let Some(handler) = &onblur {
+                        handler.call(evt);
+                    }
+                },
+                onmounted: move |evt| {
+                    if let Some(handler) = &onmounted {
+                        handler.call(evt);
+                    }
+                },
             }
             if !error.is_empty() {
                 span { class: style::error_text, "{error}" }
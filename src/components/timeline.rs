@@ -0,0 +1,473 @@
+//! Timeline of spans in time, for CI jobs and tool calls
+
+use dioxus::prelude::*;
+
+use crate::{
+    Button, ButtonSize, ButtonVariant, IconButton, IconButtonSize, IconButtonVariant, IconZoomIn,
+    IconZoomOut, ToolCallStatus,
+};
+
+stylance::import_style!(style, "timeline.module.scss");
+
+/// Smallest visible time range, in milliseconds
+const MIN_SPAN_MS: f64 = 1.0;
+/// Zoom factor of one zoom step
+const ZOOM_STEP: f64 = 1.5;
+/// Fraction of the visible range moved by one pan step
+const PAN_STEP: f64 = 0.2;
+
+const SECOND: f64 = 1_000.0;
+const MINUTE: f64 = 60.0 * SECOND;
+const HOUR: f64 = 60.0 * MINUTE;
+const DAY: f64 = 24.0 * HOUR;
+
+/// Candidate distances between axis ticks, in milliseconds
+const TICK_STEPS: [f64; 30] = [
+    1.0,
+    2.0,
+    5.0,
+    10.0,
+    20.0,
+    50.0,
+    100.0,
+    200.0,
+    500.0,
+    SECOND,
+    2.0 * SECOND,
+    5.0 * SECOND,
+    10.0 * SECOND,
+    15.0 * SECOND,
+    30.0 * SECOND,
+    MINUTE,
+    2.0 * MINUTE,
+    5.0 * MINUTE,
+    10.0 * MINUTE,
+    15.0 * MINUTE,
+    30.0 * MINUTE,
+    HOUR,
+    2.0 * HOUR,
+    3.0 * HOUR,
+    6.0 * HOUR,
+    12.0 * HOUR,
+    DAY,
+    2.0 * DAY,
+    7.0 * DAY,
+    30.0 * DAY,
+];
+
+/// A span of time in a [`TimelineRow`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimelineSpan {
+    /// Stable id, passed to `on_span_click`
+    pub id: String,
+    /// Start, in milliseconds
+    pub start: f64,
+    /// End, in milliseconds; `None` while still running
+    pub end: Option<f64>,
+    /// Text shown on the bar
+    pub label: String,
+    /// Status, which sets the bar color
+    pub status: ToolCallStatus,
+}
+
+impl TimelineSpan {
+    /// Create a finished span
+    #[must_use]
+    pub fn new(id: impl Into<String>, label: impl Into<String>, start: f64, end: f64) -> Self {
+        Self {
+            id: id.into(),
+            start,
+            end: Some(end),
+            label: label.into(),
+            status: ToolCallStatus::Success,
+        }
+    }
+
+    /// Create a span that hasn't ended yet
+    #[must_use]
+    pub fn running(id: impl Into<String>, label: impl Into<String>, start: f64) -> Self {
+        Self {
+            id: id.into(),
+            start,
+            end: None,
+            label: label.into(),
+            status: ToolCallStatus::Running,
+        }
+    }
+
+    /// Set the status, from a `ToolCallStatus` or a `StepStatus`
+    #[must_use]
+    pub fn status(mut self, status: impl Into<ToolCallStatus>) -> Self {
+        self.status = status.into();
+        self
+    }
+}
+
+/// A labelled row of spans; overlapping spans are stacked in lanes
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimelineRow {
+    /// Stable id
+    pub id: String,
+    /// Row label
+    pub label: String,
+    /// Spans in the row
+    pub spans: Vec<TimelineSpan>,
+}
+
+impl TimelineRow {
+    /// Create a row
+    #[must_use]
+    pub fn new(id: impl Into<String>, label: impl Into<String>, spans: Vec<TimelineSpan>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            spans,
+        }
+    }
+}
+
+/// Format a duration compactly, e.g. `250ms`, `1.5s`, `2m 30s`, `1h 5m` or `2d 3h`
+#[must_use]
+pub fn format_duration(ms: f64) -> String {
+    if ms == 0.0 {
+        return "0".to_string();
+    }
+    let sign = if ms < 0.0 { "-" } else { "" };
+    let ms = ms.abs();
+    let (major, minor, units) = if ms < SECOND {
+        return format!("{sign}{}ms", (ms * 10.0).round() / 10.0);
+    } else if ms < MINUTE {
+        return format!("{sign}{}s", (ms / SECOND * 100.0).round() / 100.0);
+    } else if ms < HOUR {
+        (MINUTE, SECOND, ("m", "s"))
+    } else if ms < DAY {
+        (HOUR, MINUTE, ("h", "m"))
+    } else {
+        (DAY, HOUR, ("d", "h"))
+    };
+    let whole = (ms / major).floor();
+    let rest = ((ms - whole * major) / minor).floor();
+    if rest > 0.0 {
+        format!("{sign}{whole}{} {rest}{}", units.0, units.1)
+    } else {
+        format!("{sign}{whole}{}", units.0)
+    }
+}
+
+/// Axis ticks for `start..end`, at most about `max_ticks` of them
+fn axis_ticks(start: f64, end: f64, max_ticks: usize) -> Vec<f64> {
+    let range = (end - start).max(MIN_SPAN_MS);
+    let step = TICK_STEPS
+        .iter()
+        .copied()
+        .find(|step| range / step <= max_ticks as f64)
+        .unwrap_or_else(|| (range / max_ticks as f64 / DAY).ceil() * DAY);
+    let first = (start / step).ceil() as i64;
+    let last = (end / step).floor() as i64;
+    (first..=last).map(|i| i as f64 * step).collect()
+}
+
+/// Stack spans into lanes so overlapping ones don't cover each other
+///
+/// Returns the lane of each span and the number of lanes.
+fn assign_lanes(spans: &[TimelineSpan], now: f64) -> (Vec<usize>, usize) {
+    let mut order: Vec<usize> = (0..spans.len()).collect();
+    order.sort_by(|&a, &b| spans[a].start.total_cmp(&spans[b].start));
+    let mut lane_ends: Vec<f64> = Vec::new();
+    let mut lanes = vec![0; spans.len()];
+    for i in order {
+        let span = &spans[i];
+        let end = span.end.unwrap_or(now).max(span.start);
+        let lane = match lane_ends.iter().position(|&e| e <= span.start) {
+            Some(lane) => lane,
+            None => {
+                lane_ends.push(f64::NEG_INFINITY);
+                lane_ends.len() - 1
+            }
+        };
+        lane_ends[lane] = end;
+        lanes[i] = lane;
+    }
+    (lanes, lane_ends.len().max(1))
+}
+
+/// Keep a view within `bounds`, at least `MIN_SPAN_MS` wide
+fn clamp_view(start: f64, end: f64, bounds: (f64, f64)) -> (f64, f64) {
+    let full = bounds.1 - bounds.0;
+    let span = (end - start).clamp(MIN_SPAN_MS.min(full), full);
+    if span >= full {
+        return bounds;
+    }
+    // `bounds.1 - span` can round below `bounds.0`, so no `f64::clamp` here
+    let start = start.max(bounds.0).min((bounds.1 - span).max(bounds.0));
+    (start, start + span)
+}
+
+/// Zoom a view by `factor` (below 1 zooms in) around the point at `anchor` (0 to 1)
+fn zoom_view(view: (f64, f64), factor: f64, anchor: f64, bounds: (f64, f64)) -> (f64, f64) {
+    let span = view.1 - view.0;
+    let at = view.0 + span * anchor;
+    let new_span = span * factor;
+    clamp_view(
+        at - new_span * anchor,
+        at + new_span * (1.0 - anchor),
+        bounds,
+    )
+}
+
+/// Rows of spans along a shared time axis
+///
+/// Times are milliseconds on any clock; the axis is labelled with offsets from
+/// the earliest start and picks its tick spacing, from milliseconds to days,
+/// to fit the visible range. Ctrl/Cmd + wheel zooms around the pointer;
+/// dragging, horizontal scrolling or Shift + wheel pans; with focus, the arrow
+/// keys pan, `+`/`-` zoom and `0` fits everything. Bars use the
+/// `ToolCallStatus` colors.
+#[component]
+pub fn Timeline(
+    /// Rows of spans
+    rows: Vec<TimelineRow>,
+    /// Current time, where running spans end (defaults to the latest end)
+    #[props(optional)]
+    now: Option<f64>,
+    /// Approximate number of axis ticks
+    #[props(default = 8)]
+    ticks: usize,
+    /// Whether to show the zoom buttons
+    #[props(default = true)]
+    controls: bool,
+    /// Called with the id of a clicked span
+    #[props(optional)]
+    on_span_click: Option<EventHandler<String>>,
+    /// Message shown when there are no spans
+    #[props(default = "No activity".to_string())]
+    empty_message: String,
+    /// Additional CSS class
+    #[props(default)]
+    class: Option<String>,
+) -> Element {
+    let spans = || rows.iter().flat_map(|row| row.spans.iter());
+    let origin = spans().map(|s| s.start).fold(f64::INFINITY, f64::min);
+    let latest = spans()
+        .map(|s| s.end.unwrap_or(s.start))
+        .fold(f64::NEG_INFINITY, f64::max);
+    let now = now.unwrap_or(latest);
+    let bounds = if origin.is_finite() {
+        (origin, latest.max(now).max(origin + MIN_SPAN_MS))
+    } else {
+        (0.0, SECOND)
+    };
+
+    // Visible range; `None` shows everything
+    let mut view: Signal<Option<(f64, f64)>> = use_signal(|| None);
+    let mut drag: Signal<Option<(f64, (f64, f64))>> = use_signal(|| None);
+    let mut track: Signal<Option<std::rc::Rc<MountedData>>> = use_signal(|| None);
+
+    let (view_start, view_end) = view()
+        .map(|(s, e)| clamp_view(s, e, bounds))
+        .unwrap_or(bounds);
+    let range = view_end - view_start;
+    let pct = move |t: f64| (t - view_start) / range * 100.0;
+    let zoomed = view_end - view_start < bounds.1 - bounds.0;
+
+    let mut zoom = move |factor: f64, anchor: f64| {
+        view.set(Some(zoom_view(
+            (view_start, view_end),
+            factor,
+            anchor,
+            bounds,
+        )));
+    };
+    let mut pan = move |by: f64| {
+        view.set(Some(clamp_view(view_start + by, view_end + by, bounds)));
+    };
+
+    // Ticks at round offsets from the start of the timeline
+    let tick_values: Vec<f64> = axis_ticks(view_start - bounds.0, view_end - bounds.0, ticks)
+        .into_iter()
+        .map(|t| t + bounds.0)
+        .collect();
+
+    rsx! {
+        div { class: stylance::classes!(style::timeline, class.as_deref().unwrap_or("")),
+            if controls {
+                div { class: style::toolbar,
+                    span { class: style::range,
+                        "{format_duration(view_start - bounds.0)} – {format_duration(view_end - bounds.0)}"
+                    }
+                    IconButton {
+                        variant: IconButtonVariant::Ghost,
+                        size: IconButtonSize::Small,
+                        aria_label: "Zoom out",
+                        disabled: !zoomed,
+                        onclick: move |_| zoom(ZOOM_STEP, 0.5),
+                        IconZoomOut {}
+                    }
+                    IconButton {
+                        variant: IconButtonVariant::Ghost,
+                        size: IconButtonSize::Small,
+                        aria_label: "Zoom in",
+                        disabled: range <= MIN_SPAN_MS,
+                        onclick: move |_| zoom(1.0 / ZOOM_STEP, 0.5),
+                        IconZoomIn {}
+                    }
+                    Button {
+                        variant: ButtonVariant::Ghost,
+                        size: ButtonSize::Small,
+                        disabled: !zoomed,
+                        onclick: move |_| view.set(None),
+                        "Fit"
+                    }
+                }
+            }
+            div {
+                class: stylance::classes!(style::grid, if drag().is_some() { style::dragging } else { "" }),
+                tabindex: "0",
+                aria_label: "Timeline; use the arrow keys to pan and + or - to zoom",
+                onwheel: move |evt| {
+                    let delta = evt.delta().strip_units();
+                    let zooming = evt.modifiers().ctrl() || evt.modifiers().meta();
+                    let panning = delta.x != 0.0 || evt.modifiers().shift();
+                    if !zooming && !panning {
+                        return;
+                    }
+                    evt.prevent_default();
+                    let x = evt.client_coordinates().x;
+                    let Some(el) = track() else { return };
+                    spawn(async move {
+                        let Ok(rect) = el.get_client_rect().await else { return };
+                        let width = rect.size.width.max(1.0);
+                        if zooming {
+                            let anchor = ((x - rect.origin.x) / width).clamp(0.0, 1.0);
+                            zoom(if delta.y > 0.0 { ZOOM_STEP } else { 1.0 / ZOOM_STEP }, anchor);
+                        } else {
+                            let pixels = if delta.x != 0.0 { delta.x } else { delta.y };
+                            pan(pixels / width * range);
+                        }
+                    });
+                },
+                onmousedown: move |evt| {
+                    drag.set(Some((evt.client_coordinates().x, (view_start, view_end))));
+                },
+                onmousemove: move |evt| {
+                    let Some((from, (start, end))) = drag() else { return };
+                    let Some(el) = track() else { return };
+                    let x = evt.client_coordinates().x;
+                    spawn(async move {
+                        if let Ok(rect) = el.get_client_rect().await {
+                            let shift = (from - x) / rect.size.width.max(1.0) * (end - start);
+                            view.set(Some(clamp_view(start + shift, end + shift, bounds)));
+                        }
+                    });
+                },
+                onmouseup: move |_| drag.set(None),
+                onmouseleave: move |_| drag.set(None),
+                onkeydown: move |evt| {
+                    match evt.key() {
+                        Key::ArrowLeft => pan(-range * PAN_STEP),
+                        Key::ArrowRight => pan(range * PAN_STEP),
+                        Key::Character(c) if c == "+" || c == "=" => zoom(1.0 / ZOOM_STEP, 0.5),
+                        Key::Character(c) if c == "-" => zoom(ZOOM_STEP, 0.5),
+                        Key::Character(c) if c == "0" => view.set(None),
+                        _ => return,
+                    }
+                    evt.prevent_default();
+                },
+                div { class: style::corner }
+                div { class: style::axis, onmounted: move |evt| track.set(Some(evt.data())),
+                    for tick in tick_values.iter().copied() {
+                        span { key: "{tick}", class: style::tick, style: "left: {pct(tick):.3}%",
+                            "{format_duration(tick - bounds.0)}"
+                        }
+                    }
+                }
+                for row in rows.iter() {
+                    div { key: "label-{row.id}", class: style::row_label, title: "{row.label}", "{row.label}" }
+                    {
+                        let (lanes, lane_count) = assign_lanes(&row.spans, now);
+                        rsx! {
+                            div { key: "track-{row.id}", class: style::row_track, style: "--lanes: {lane_count}",
+                                for tick in tick_values.iter().copied() {
+                                    span { key: "{tick}", class: style::gridline, style: "left: {pct(tick):.3}%" }
+                                }
+                                for (span, lane) in row.spans.iter().zip(lanes) {
+                                    {
+                                        let end = span.end.unwrap_or(now).max(span.start);
+                                        let left = pct(span.start);
+                                        let width = pct(end) - left;
+                                        let visible = end >= view_start && span.start <= view_end;
+                                        let id = span.id.clone();
+                                        let status_class = match span.status {
+                                            ToolCallStatus::Pending => style::pending,
+                                            ToolCallStatus::Running => style::running,
+                                            ToolCallStatus::Success => style::success,
+                                            ToolCallStatus::Error => style::error,
+                                            ToolCallStatus::Cancelled => style::cancelled,
+                                        };
+                                        let duration = match span.end {
+                                            Some(_) => format_duration(end - span.start),
+                                            None => format!("{} so far", format_duration(end - span.start)),
+                                        };
+                                        rsx! {
+                                            if visible {
+                                                div {
+                                                    key: "{span.id}",
+                                                    class: stylance::classes!(
+                                                        style::bar,
+                                                        status_class,
+                                                        if on_span_click.is_some() { style::clickable } else { "" }
+                                                    ),
+                                                    style: "left: {left:.3}%; width: max({width:.3}%, 2px); --lane: {lane}",
+                                                    title: "{span.label} · {duration}",
+                                                    onmousedown: move |evt| evt.stop_propagation(),
+                                                    onclick: move |_| {
+                                                        if let Some(handler) = &on_span_click {
+                                                            handler.call(id.clone());
+                                                        }
+                                                    },
+                                                    span { class: style::bar_label, "{span.label}" }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                if !origin.is_finite() {
+                    div { class: style::empty, "{empty_message}" }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clamp_view_with_fractional_bounds() {
+        let bounds = (605.635609, 808482.832609);
+        let range = bounds.1 - bounds.0;
+        let panned = clamp_view(
+            bounds.0 + range * PAN_STEP,
+            bounds.1 + range * PAN_STEP,
+            bounds,
+        );
+        assert_eq!(panned, bounds);
+        let zoomed = zoom_view(bounds, 1.5, 0.5, bounds);
+        assert_eq!(zoomed, bounds);
+    }
+
+    #[test]
+    fn clamp_view_keeps_span_inside_bounds() {
+        let bounds = (0.5, 100.25);
+        let (start, end) = clamp_view(90.0, 120.0, bounds);
+        assert_eq!(end, bounds.1);
+        assert!((end - start - 30.0).abs() < 1e-9);
+        let (start, _) = clamp_view(-10.0, 20.0, bounds);
+        assert_eq!(start, bounds.0);
+    }
+}
@@ -2,12 +2,12 @@
 
 use dioxus::prelude::*;
 
-use crate::{IconCheck, IconLoader, IconX};
+use crate::{IconCheck, IconLoader, IconX, StepStatus};
 
 stylance::import_style!(style, "tool_call_badge.module.scss");
 
 /// Status of a tool call
-#[derive(Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ToolCallStatus {
     /// Pending execution
     #[default]
@@ -22,6 +22,17 @@ pub enum ToolCallStatus {
     Cancelled,
 }
 
+impl From<StepStatus> for ToolCallStatus {
+    fn from(status: StepStatus) -> Self {
+        match status {
+            StepStatus::Pending => Self::Pending,
+            StepStatus::Current => Self::Running,
+            StepStatus::Completed => Self::Success,
+            StepStatus::Error => Self::Error,
+        }
+    }
+}
+
 /// Tool call badge showing tool name and status
 #[component]
 pub fn ToolCallBadge(
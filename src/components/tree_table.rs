@@ -0,0 +1,347 @@
+//! `TreeTable` component for hierarchical rows
+
+use std::collections::HashSet;
+
+use dioxus::prelude::*;
+
+use crate::{
+    Column, IconChevronRight, Spinner, SpinnerSize, Table, TableBody, TableCell, TableEmpty,
+    TableHeader, TableHeaderCell, TableRow, TableSize, TableVariant,
+};
+
+stylance::import_style!(style, "tree_table.module.scss");
+
+/// A row of a [`TreeTable`], with optional child rows
+#[derive(Debug, Clone, PartialEq)]
+pub struct TreeRow<T> {
+    /// Stable id, used for expansion state and `on_expand`
+    pub key: String,
+    /// Row data, rendered through the table's columns
+    pub data: T,
+    /// Child rows, or `None` for leaves and for rows whose children haven't
+    /// been loaded yet
+    pub children: Option<Vec<TreeRow<T>>>,
+    /// Whether the row can be expanded
+    pub expandable: bool,
+}
+
+impl<T> TreeRow<T> {
+    /// Create a row without children
+    #[must_use]
+    pub fn leaf(key: impl Into<String>, data: T) -> Self {
+        Self {
+            key: key.into(),
+            data,
+            children: None,
+            expandable: false,
+        }
+    }
+
+    /// Create a row with known children
+    #[must_use]
+    pub fn parent(key: impl Into<String>, data: T, children: Vec<TreeRow<T>>) -> Self {
+        Self {
+            key: key.into(),
+            data,
+            children: Some(children),
+            expandable: true,
+        }
+    }
+
+    /// Create a row whose children are loaded when it's first expanded
+    #[must_use]
+    pub fn lazy(key: impl Into<String>, data: T) -> Self {
+        Self {
+            key: key.into(),
+            data,
+            children: None,
+            expandable: true,
+        }
+    }
+
+    /// Data of the loaded leaf rows under this row
+    fn leaves<'a>(&'a self, out: &mut Vec<&'a T>) {
+        match &self.children {
+            Some(children) if !children.is_empty() => {
+                for child in children {
+                    child.leaves(out);
+                }
+            }
+            _ => out.push(&self.data),
+        }
+    }
+}
+
+/// A row of the flattened, currently visible tree
+struct VisibleRow<'a, T> {
+    row: &'a TreeRow<T>,
+    depth: usize,
+    expanded: bool,
+    /// Expanded, but children not loaded yet
+    loading: bool,
+}
+
+fn visible_rows<'a, T>(
+    rows: &'a [TreeRow<T>],
+    expanded: &HashSet<String>,
+    depth: usize,
+    out: &mut Vec<VisibleRow<'a, T>>,
+) {
+    for row in rows {
+        let is_expanded = row.expandable && expanded.contains(&row.key);
+        out.push(VisibleRow {
+            row,
+            depth,
+            expanded: is_expanded,
+            loading: is_expanded && row.children.is_none(),
+        });
+        if is_expanded && let Some(children) = &row.children {
+            visible_rows(children, expanded, depth + 1, out);
+        }
+    }
+}
+
+fn collect_expandable<T>(rows: &[TreeRow<T>], out: &mut HashSet<String>) {
+    for row in rows {
+        if let Some(children) = &row.children {
+            out.insert(row.key.clone());
+            collect_expandable(children, out);
+        }
+    }
+}
+
+/// Table with parent and child rows
+///
+/// The first column is indented by depth and carries the expand toggle. Rows
+/// made with [`TreeRow::lazy`] show a loading row while expanded until their
+/// children are set, typically in response to `on_expand`. On parent rows, columns
+/// with an [`Column::aggregate`] show the aggregate of the loaded leaf rows
+/// below instead of the parent's own value.
+#[component]
+pub fn TreeTable<T: Clone + PartialEq + 'static>(
+    /// Top-level rows
+    rows: ReadSignal<Vec<TreeRow<T>>>,
+    /// Column definitions
+    columns: Vec<Column<T>>,
+    /// Whether rows with loaded children start expanded
+    #[props(default = false)]
+    default_expanded: bool,
+    /// Called with the key of a row when it's expanded
+    on_expand: Option<EventHandler<String>>,
+    /// Size variant
+    #[props(default)]
+    size: TableSize,
+    /// Style variant
+    #[props(default)]
+    variant: TableVariant,
+    /// Whether rows are hoverable
+    #[props(default = true)]
+    hoverable: bool,
+    /// Whether the table has a sticky header
+    #[props(default = false)]
+    sticky_header: bool,
+    /// Message shown when there are no rows
+    #[props(default = "No data available".to_string())]
+    empty_message: String,
+) -> Element {
+    let mut expanded = use_signal(|| {
+        let mut keys = HashSet::new();
+        if default_expanded {
+            collect_expandable(&rows.peek(), &mut keys);
+        }
+        keys
+    });
+
+    let mut toggle = move |key: String, open: bool| {
+        if open {
+            expanded.write().insert(key.clone());
+            if let Some(handler) = &on_expand {
+                handler.call(key);
+            }
+        } else {
+            expanded.write().remove(&key);
+        }
+    };
+
+    let tree = rows.read();
+    let expanded_read = expanded.read();
+    let mut visible = Vec::new();
+    visible_rows(&tree, &expanded_read, 0, &mut visible);
+    let column_count = columns.len() as u32;
+
+    rsx! {
+        Table { size, variant, hoverable, sticky_header,
+            TableHeader {
+                TableRow {
+                    for col in columns.iter() {
+                        TableHeaderCell { key: "{col.id()}", align: col.alignment().to_string(), "{col.header()}" }
+                    }
+                }
+            }
+            TableBody {
+                if visible.is_empty() {
+                    TableEmpty { colspan: column_count, message: empty_message }
+                }
+                for entry in visible.iter() {
+                    {
+                        let row = entry.row;
+                        let key = row.key.clone();
+                        let is_expanded = entry.expanded;
+                        let indent = format!("--tree-depth: {}", entry.depth);
+                        let leaves = match &row.children {
+                            Some(children) if !children.is_empty() => {
+                                let mut leaves = Vec::new();
+                                row.leaves(&mut leaves);
+                                Some(leaves)
+                            }
+                            _ => None,
+                        };
+                        rsx! {
+                            TableRow { key: "{row.key}",
+                                for (i, col) in columns.iter().enumerate() {
+                                    TableCell { key: "{col.id()}", align: col.alignment().to_string(),
+                                        if i == 0 {
+                                            span { class: style::tree_cell, style: "{indent}",
+                                                if row.expandable {
+                                                    button {
+                                                        r#type: "button",
+                                                        class: stylance::classes!(style::toggle, if is_expanded { style::open } else { "" }),
+                                                        aria_expanded: "{is_expanded}",
+                                                        aria_label: if is_expanded { "Collapse" } else { "Expand" },
+                                                        onclick: {
+                                                            let key = key.clone();
+                                                            move |_| toggle(key.clone(), !is_expanded)
+                                                        },
+                                                        IconChevronRight {}
+                                                    }
+                                                } else {
+                                                    span { class: style::toggle_spacer }
+                                                }
+                                                span { class: style::content, {col.render(&row.data)} }
+                                            }
+                                        } else {
+                                            match leaves.as_deref().and_then(|leaves| col.aggregate_text(leaves)) {
+                                                Some(total) => rsx! {
+                                                    span { class: style::aggregate, "{total}" }
+                                                },
+                                                None => col.render(&row.data),
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                            if entry.loading {
+                                TableRow { key: "{row.key}/loading",
+                                    TableCell { colspan: column_count,
+                                        span {
+                                            class: stylance::classes!(style::tree_cell, style::loading),
+                                            style: "--tree-depth: {entry.depth + 1}",
+                                            span { class: style::toggle_spacer }
+                                            Spinner { size: SpinnerSize::Small }
+                                            "Loading…"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree() -> Vec<TreeRow<u32>> {
+        vec![
+            TreeRow::parent(
+                "src",
+                0,
+                vec![
+                    TreeRow::leaf("main", 10),
+                    TreeRow::parent(
+                        "ui",
+                        0,
+                        vec![TreeRow::leaf("button", 3), TreeRow::leaf("input", 4)],
+                    ),
+                    TreeRow::lazy("gen", 0),
+                ],
+            ),
+            TreeRow::leaf("readme", 1),
+            TreeRow::parent("empty", 5, vec![]),
+        ]
+    }
+
+    fn visible(rows: &[TreeRow<u32>], expanded: &[&str]) -> Vec<(String, usize, bool, bool)> {
+        let expanded = expanded.iter().map(|key| key.to_string()).collect();
+        let mut out = Vec::new();
+        visible_rows(rows, &expanded, 0, &mut out);
+        out.into_iter()
+            .map(|r| (r.row.key.clone(), r.depth, r.expanded, r.loading))
+            .collect()
+    }
+
+    fn keys(rows: &[TreeRow<u32>], expanded: &[&str]) -> Vec<String> {
+        visible(rows, expanded)
+            .into_iter()
+            .map(|(key, ..)| key)
+            .collect()
+    }
+
+    #[test]
+    fn collapsed_tree_shows_top_level_rows() {
+        assert_eq!(keys(&tree(), &[]), ["src", "readme", "empty"]);
+    }
+
+    #[test]
+    fn expanding_shows_children_with_depth() {
+        let rows = tree();
+        assert_eq!(
+            visible(&rows, &["src", "ui"])[..5],
+            [
+                ("src".to_string(), 0, true, false),
+                ("main".to_string(), 1, false, false),
+                ("ui".to_string(), 1, true, false),
+                ("button".to_string(), 2, false, false),
+                ("input".to_string(), 2, false, false),
+            ]
+        );
+        // A collapsed parent hides its expanded descendants
+        assert_eq!(keys(&rows, &["ui"]), ["src", "readme", "empty"]);
+    }
+
+    #[test]
+    fn expanded_lazy_rows_are_loading() {
+        let rows = tree();
+        let shown = visible(&rows, &["src", "gen"]);
+        assert!(shown.contains(&("gen".to_string(), 1, true, true)));
+        // Leaves can't be expanded
+        let shown = visible(&rows, &["readme"]);
+        assert!(shown.contains(&("readme".to_string(), 0, false, false)));
+    }
+
+    #[test]
+    fn leaves_collect_loaded_leaf_data() {
+        let rows = tree();
+        let mut out = Vec::new();
+        rows[0].leaves(&mut out);
+        // The unloaded lazy row counts as a leaf of its own
+        assert_eq!(out, [&10, &3, &4, &0]);
+
+        let mut out = Vec::new();
+        rows[2].leaves(&mut out);
+        assert_eq!(out, [&5]);
+    }
+
+    #[test]
+    fn collect_expandable_finds_rows_with_loaded_children() {
+        let mut out = HashSet::new();
+        collect_expandable(&tree(), &mut out);
+        let mut keys: Vec<_> = out.into_iter().collect();
+        keys.sort();
+        assert_eq!(keys, ["empty", "src", "ui"]);
+    }
+}
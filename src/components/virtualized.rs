@@ -0,0 +1,401 @@
+//! Windowed `Table` and `List` that only render the rows in view
+
+use std::cell::Cell;
+use std::ops::Range;
+use std::rc::Rc;
+
+use dioxus::html::geometry::PixelsVector2D;
+use dioxus::prelude::*;
+
+use crate::{
+    List, ListSize, ListVariant, Table, TableBody, TableHeader, TableRow, TableSize, TableVariant,
+};
+
+stylance::import_style!(style, "virtualized.module.scss");
+
+/// How tall the rows of a virtualized table or list are
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RowHeight {
+    /// Every row is exactly this many pixels tall
+    Fixed(f64),
+    /// Rows vary: start from this estimate (in pixels) and adapt to the
+    /// average height of the rendered rows
+    Measured(f64),
+}
+
+impl Default for RowHeight {
+    fn default() -> Self {
+        Self::Measured(40.0)
+    }
+}
+
+/// Rows to render and the space to reserve around them
+struct Window {
+    range: Range<usize>,
+    top: f64,
+    bottom: f64,
+}
+
+/// Range of rows covering the viewport, widened by `overscan` on each side
+fn visible_range(
+    count: usize,
+    row_height: f64,
+    scroll_top: f64,
+    viewport_height: f64,
+    overscan: usize,
+) -> Range<usize> {
+    let row_height = row_height.max(1.0);
+    let start = (scroll_top.max(0.0) / row_height) as usize;
+    let visible = (viewport_height / row_height).ceil() as usize + 1;
+    let first = start.saturating_sub(overscan).min(count);
+    let last = (start + visible + overscan).min(count);
+    first..last
+}
+
+/// Whether every row is rendered until the viewport is mounted
+///
+/// Only off the browser, so server rendering produces the plain, complete
+/// output; in the browser a huge list must never build all of its rows.
+const RENDER_ALL_UNMOUNTED: bool = !cfg!(target_arch = "wasm32");
+
+/// Rows to render: all of them when not `windowed`, otherwise the rows in view
+///
+/// Before the viewport is measured, `viewport_height` is the default estimate
+/// and `scroll_top` is zero, so the first render covers the top of the list.
+fn window_range(
+    count: usize,
+    row_height: f64,
+    scroll_top: f64,
+    viewport_height: f64,
+    overscan: usize,
+    windowed: bool,
+) -> Range<usize> {
+    if windowed {
+        visible_range(count, row_height, scroll_top, viewport_height, overscan)
+    } else {
+        0..count
+    }
+}
+
+/// Scroll state shared by `VirtualTable` and `VirtualList`
+#[derive(Clone, Copy)]
+struct Virtualizer {
+    viewport: Signal<Option<Rc<MountedData>>>,
+    content: Signal<Option<Rc<MountedData>>>,
+    scroll_top: Signal<f64>,
+    viewport_height: Signal<f64>,
+    measured: Signal<Option<f64>>,
+    pending_index: Signal<Option<usize>>,
+}
+
+impl Virtualizer {
+    /// Pixel height used for one row
+    fn row_px(&self, row_height: RowHeight) -> f64 {
+        match row_height {
+            RowHeight::Fixed(height) => height,
+            RowHeight::Measured(estimate) => self.measured.peek().unwrap_or(estimate),
+        }
+    }
+
+    /// Rows to render, see [`window_range`]
+    fn window(&self, count: usize, row_height: RowHeight, overscan: usize) -> Window {
+        let mounted = self.viewport.read().is_some();
+        // Subscribe to the measurement so the window follows it
+        self.measured.read();
+        let px = self.row_px(row_height);
+        let range = window_range(
+            count,
+            px,
+            *self.scroll_top.read(),
+            *self.viewport_height.read(),
+            overscan,
+            mounted || !RENDER_ALL_UNMOUNTED,
+        );
+        Window {
+            top: range.start as f64 * px,
+            bottom: (count - range.end) as f64 * px,
+            range,
+        }
+    }
+
+    fn scroll_to(&self, index: usize, row_height: RowHeight) {
+        let Some(el) = self.viewport.peek().clone() else {
+            let mut pending = self.pending_index;
+            pending.set(Some(index));
+            return;
+        };
+        let top = index as f64 * self.row_px(row_height);
+        spawn(async move {
+            let _ = el
+                .scroll(PixelsVector2D::new(0.0, top), ScrollBehavior::Instant)
+                .await;
+        });
+    }
+
+    fn mount_viewport(&self, el: Rc<MountedData>, row_height: RowHeight) {
+        let mut viewport = self.viewport;
+        let mut viewport_height = self.viewport_height;
+        viewport.set(Some(el.clone()));
+        spawn(async move {
+            if let Ok(rect) = el.get_client_rect().await {
+                viewport_height.set(rect.size.height);
+            }
+        });
+        let mut pending = self.pending_index;
+        if let Some(index) = pending.take() {
+            self.scroll_to(index, row_height);
+        }
+    }
+
+    fn on_scroll(&self, evt: ScrollEvent) {
+        let mut scroll_top = self.scroll_top;
+        let mut viewport_height = self.viewport_height;
+        scroll_top.set(evt.data().scroll_top());
+        let client = f64::from(evt.data().client_height());
+        if client > 0.0 {
+            viewport_height.set(client);
+        }
+    }
+
+    fn mount_content(&self, el: Rc<MountedData>) {
+        let mut content = self.content;
+        content.set(Some(el));
+    }
+}
+
+/// Create the scroll state and keep row measurements and scroll requests in sync
+fn use_virtualizer(
+    count: usize,
+    row_height: RowHeight,
+    overscan: usize,
+    scroll_to_index: Option<usize>,
+) -> Virtualizer {
+    let virtualizer = Virtualizer {
+        viewport: use_signal(|| None),
+        content: use_signal(|| None),
+        scroll_top: use_signal(|| 0.0),
+        viewport_height: use_signal(|| 480.0),
+        measured: use_signal(|| None),
+        pending_index: use_signal(|| None),
+    };
+    let last_measured: Rc<Cell<Option<(usize, usize)>>> = use_hook(|| Rc::new(Cell::new(None)));
+
+    // Measure the rendered rows whenever the window moves
+    use_effect(use_reactive(
+        (&count, &row_height, &overscan),
+        move |(count, row_height, overscan)| {
+            let window = virtualizer.window(count, row_height, overscan);
+            let RowHeight::Measured(_) = row_height else {
+                return;
+            };
+            let rendered = window.range.len();
+            let key = (window.range.start, window.range.end);
+            if rendered == 0 || last_measured.get() == Some(key) {
+                return;
+            }
+            last_measured.set(Some(key));
+            let Some(el) = virtualizer.content.read().clone() else {
+                return;
+            };
+            let mut measured = virtualizer.measured;
+            spawn(async move {
+                let Ok(rect) = el.get_client_rect().await else {
+                    return;
+                };
+                let rows = rect.size.height - window.top - window.bottom;
+                let average = rows / rendered as f64;
+                let current = measured.peek().unwrap_or(0.0);
+                if average > 0.0 && (average - current).abs() > 0.5 {
+                    measured.set(Some(average));
+                }
+            });
+        },
+    ));
+
+    use_effect(use_reactive(
+        (&scroll_to_index, &row_height),
+        move |(index, row_height)| {
+            if let Some(index) = index {
+                virtualizer.scroll_to(index, row_height);
+            }
+        },
+    ));
+
+    virtualizer
+}
+
+/// Table that only renders the rows in view
+///
+/// Rows are produced on demand by `render_row`, which should return a
+/// `TableRow`. Server-side rendering outputs every row; in the browser the
+/// first render already covers only the top of the table. With `sticky_header` the header stays
+/// on top of the scrolling area.
+#[component]
+pub fn VirtualTable(
+    /// Number of rows
+    count: usize,
+    /// Render the row at an index
+    render_row: Callback<usize, Element>,
+    /// Header cells
+    header: Element,
+    /// Number of columns (spans the spacer rows)
+    columns: u32,
+    /// Row height, fixed or measured
+    #[props(default)]
+    row_height: RowHeight,
+    /// Rows rendered above and below the visible ones
+    #[props(default = 10)]
+    overscan: usize,
+    /// Height of the scrolling area (CSS value)
+    #[props(default = "24rem".to_string())]
+    height: String,
+    /// Scroll so the row at this index is at the top
+    #[props(default)]
+    scroll_to_index: Option<usize>,
+    /// Size variant
+    #[props(default)]
+    size: TableSize,
+    /// Style variant
+    #[props(default)]
+    variant: TableVariant,
+    /// Whether rows are hoverable
+    #[props(default = false)]
+    hoverable: bool,
+    /// Whether the header stays visible while scrolling
+    #[props(default = true)]
+    sticky_header: bool,
+    /// Additional CSS class
+    #[props(default)]
+    class: Option<String>,
+) -> Element {
+    let virtualizer = use_virtualizer(count, row_height, overscan, scroll_to_index);
+    let window = virtualizer.window(count, row_height, overscan);
+    // Keep even/odd row parity stable for striped tables
+    let parity_spacer = window.range.start.is_multiple_of(2);
+
+    rsx! {
+        div {
+            class: stylance::classes!(style::viewport, class.as_deref().unwrap_or("")),
+            style: "max-height: {height}",
+            onmounted: move |evt| virtualizer.mount_viewport(evt.data(), row_height),
+            onscroll: move |evt| virtualizer.on_scroll(evt),
+            Table { size, variant, hoverable, sticky_header,
+                TableHeader {
+                    TableRow { {header} }
+                }
+                TableBody { onmounted: move |evt: MountedEvent| virtualizer.mount_content(evt.data()),
+                    if window.top > 0.0 {
+                        if parity_spacer {
+                            tr { class: style::hidden_spacer, aria_hidden: "true" }
+                        }
+                        tr { class: style::spacer, aria_hidden: "true",
+                            td { colspan: columns, style: "height: {window.top}px" }
+                        }
+                    }
+                    for index in window.range.clone() {
+                        Fragment { key: "{index}", {render_row.call(index)} }
+                    }
+                    if window.bottom > 0.0 {
+                        tr { class: style::spacer, aria_hidden: "true",
+                            td { colspan: columns, style: "height: {window.bottom}px" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// List that only renders the items in view
+///
+/// Items are produced on demand by `render_item`, which should return a
+/// `ListItem`. Server-side rendering outputs every item; in the browser the
+/// first render already covers only the top of the list.
+#[component]
+pub fn VirtualList(
+    /// Number of items
+    count: usize,
+    /// Render the item at an index
+    render_item: Callback<usize, Element>,
+    /// Item height, fixed or measured
+    #[props(default)]
+    row_height: RowHeight,
+    /// Items rendered above and below the visible ones
+    #[props(default = 10)]
+    overscan: usize,
+    /// Height of the scrolling area (CSS value)
+    #[props(default = "24rem".to_string())]
+    height: String,
+    /// Scroll so the item at this index is at the top
+    #[props(default)]
+    scroll_to_index: Option<usize>,
+    /// Size variant
+    #[props(default)]
+    size: ListSize,
+    /// Visual variant
+    #[props(default)]
+    variant: ListVariant,
+    /// Additional CSS class
+    #[props(default)]
+    class: Option<String>,
+) -> Element {
+    let virtualizer = use_virtualizer(count, row_height, overscan, scroll_to_index);
+    let window = virtualizer.window(count, row_height, overscan);
+
+    rsx! {
+        div {
+            class: stylance::classes!(style::viewport, class.as_deref().unwrap_or("")),
+            style: "max-height: {height}",
+            onmounted: move |evt| virtualizer.mount_viewport(evt.data(), row_height),
+            onscroll: move |evt| virtualizer.on_scroll(evt),
+            List {
+                size,
+                variant,
+                onmounted: move |evt: MountedEvent| virtualizer.mount_content(evt.data()),
+                if window.top > 0.0 {
+                    li { class: style::spacer, aria_hidden: "true", style: "height: {window.top}px" }
+                }
+                for index in window.range.clone() {
+                    Fragment { key: "{index}", {render_item.call(index)} }
+                }
+                if window.bottom > 0.0 {
+                    li { class: style::spacer, aria_hidden: "true", style: "height: {window.bottom}px" }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn visible_range_covers_the_viewport_and_overscan() {
+        assert_eq!(visible_range(1000, 20.0, 0.0, 100.0, 5), 0..11);
+        assert_eq!(visible_range(1000, 20.0, 400.0, 100.0, 5), 15..31);
+        assert_eq!(visible_range(1000, 20.0, 1_000_000.0, 100.0, 5), 1000..1000);
+        assert_eq!(visible_range(3, 20.0, 0.0, 100.0, 5), 0..3);
+    }
+
+    #[test]
+    fn unmeasured_window_is_bounded() {
+        // Default viewport height and row estimate, before anything is measured
+        let range = window_range(100_000, 40.0, 0.0, 480.0, 10, true);
+        assert_eq!(range.start, 0);
+        assert!(range.len() < 100, "{range:?}");
+    }
+
+    #[test]
+    fn unwindowed_range_renders_every_row() {
+        assert_eq!(
+            window_range(100_000, 40.0, 0.0, 480.0, 10, false),
+            0..100_000
+        );
+    }
+
+    #[test]
+    fn tiny_row_heights_do_not_divide_by_zero() {
+        assert_eq!(visible_range(10, 0.0, 0.0, 5.0, 0), 0..6);
+    }
+}
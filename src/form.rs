@@ -0,0 +1,497 @@
+//! Form state and validation
+//!
+//! [`use_form`] owns a typed value (usually a struct with one member per
+//! field) and a set of named fields, each with a getter/setter pair and a list
+//! of validators. Errors come back as plain strings, so they plug straight
+//! into the `error` props of [`Input`](crate::Input), [`Select`](crate::Select),
+//! [`Textarea`](crate::Textarea) and [`FormField`](crate::FormField).
+//!
+//! ```ignore
+//! #[derive(Clone, PartialEq, Default)]
+//! struct Signup { email: String, name: String }
+//!
+//! let form = use_form(Signup::default, |rules| {
+//!     rules
+//!         .field("email", |s| s.email.clone(), |s, v| s.email = v)
+//!         .required("Email is required")
+//!         .pattern(r"^[^@\s]+@[^@\s]+$", "Enter a valid email address");
+//!     rules
+//!         .field("name", |s| s.name.clone(), |s, v| s.name = v)
+//!         .max_length(40, "Keep it under 40 characters");
+//! });
+//!
+//! rsx! {
+//!     form { onsubmit: form.handle_submit(|signup| async move { save(signup).await }),
+//!         Input {
+//!             label: "Email",
+//!             value: form.text("email"),
+//!             error: form.error("email"),
+//!             oninput: form.oninput("email"),
+//!             onblur: form.onblur("email"),
+//!             onmounted: form.onmounted("email"),
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use regex::Regex;
+
+type Getter<V> = Rc<dyn Fn(&V) -> String>;
+type Setter<V> = Rc<dyn Fn(&mut V, String)>;
+type Check<V> = Rc<dyn Fn(&V, &str) -> Result<(), String>>;
+type AsyncCheck<V> = Rc<dyn Fn(V) -> Pin<Box<dyn Future<Output = Result<(), String>>>>>;
+
+/// Validation rules for a single form field, built by [`FormRules::field`]
+pub struct FieldRules<V> {
+    name: String,
+    get: Getter<V>,
+    set: Setter<V>,
+    checks: Vec<Check<V>>,
+    async_checks: Vec<AsyncCheck<V>>,
+}
+
+impl<V: 'static> FieldRules<V> {
+    fn check(&mut self, check: impl Fn(&V, &str) -> Result<(), String> + 'static) -> &mut Self {
+        self.checks.push(Rc::new(check));
+        self
+    }
+
+    /// Fail when the field is empty or only whitespace
+    pub fn required(&mut self, message: impl Into<String>) -> &mut Self {
+        let message = message.into();
+        self.check(move |_, text| {
+            if text.trim().is_empty() {
+                Err(message.clone())
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    /// Fail when a non-empty field has fewer than `min` characters
+    pub fn min_length(&mut self, min: usize, message: impl Into<String>) -> &mut Self {
+        let message = message.into();
+        self.check(move |_, text| {
+            if !text.is_empty() && text.chars().count() < min {
+                Err(message.clone())
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    /// Fail when the field has more than `max` characters
+    pub fn max_length(&mut self, max: usize, message: impl Into<String>) -> &mut Self {
+        let message = message.into();
+        self.check(move |_, text| {
+            if text.chars().count() > max {
+                Err(message.clone())
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    /// Fail when a non-empty field doesn't match `pattern`.
+    ///
+    /// The pattern is not anchored; use `^...$` to match the whole value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pattern` is not a valid regular expression.
+    pub fn pattern(&mut self, pattern: &str, message: impl Into<String>) -> &mut Self {
+        let regex = Regex::new(pattern)
+            .unwrap_or_else(|err| panic!("invalid pattern for field `{}`: {err}", self.name));
+        let message = message.into();
+        self.check(move |_, text| {
+            if !text.is_empty() && !regex.is_match(text) {
+                Err(message.clone())
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    /// Run a custom check against the whole form value, e.g. to compare two fields
+    pub fn custom(&mut self, check: impl Fn(&V) -> Result<(), String> + 'static) -> &mut Self {
+        self.check(move |value, _| check(value))
+    }
+
+    /// Run an async check, e.g. a server-side availability lookup.
+    ///
+    /// Async checks only run once every sync check on the field passes, after
+    /// each edit and again on submit. Results from superseded edits are dropped.
+    pub fn custom_async<F, Fut>(&mut self, check: F) -> &mut Self
+    where
+        F: Fn(V) -> Fut + 'static,
+        Fut: Future<Output = Result<(), String>> + 'static,
+    {
+        self.async_checks
+            .push(Rc::new(move |value| Box::pin(check(value))));
+        self
+    }
+
+    fn validate(&self, value: &V) -> Option<String> {
+        let text = (self.get)(value);
+        self.checks
+            .iter()
+            .find_map(|check| check(value, &text).err())
+    }
+
+    async fn validate_async(&self, value: V) -> Option<String>
+    where
+        V: Clone,
+    {
+        for check in &self.async_checks {
+            if let Err(message) = check(value.clone()).await {
+                return Some(message);
+            }
+        }
+        None
+    }
+}
+
+/// The set of fields passed to the [`use_form`] setup closure
+pub struct FormRules<V> {
+    fields: Vec<FieldRules<V>>,
+}
+
+impl<V: 'static> FormRules<V> {
+    /// Declare a field. `get` and `set` map between the form value and the
+    /// text shown in the input.
+    pub fn field(
+        &mut self,
+        name: impl Into<String>,
+        get: impl Fn(&V) -> String + 'static,
+        set: impl Fn(&mut V, String) + 'static,
+    ) -> &mut FieldRules<V> {
+        self.fields.push(FieldRules {
+            name: name.into(),
+            get: Rc::new(get),
+            set: Rc::new(set),
+            checks: Vec::new(),
+            async_checks: Vec::new(),
+        });
+        self.fields.last_mut().expect("field was just pushed")
+    }
+}
+
+/// Handle returned by [`use_form`]. It is `Copy`, so it can be moved into
+/// any number of event handlers.
+pub struct Form<V: 'static> {
+    value: Signal<V>,
+    initial: Signal<V>,
+    fields: CopyValue<Rc<[FieldRules<V>]>>,
+    errors: Signal<HashMap<String, String>>,
+    async_errors: Signal<HashMap<String, String>>,
+    pending: Signal<HashMap<String, u64>>,
+    generation: CopyValue<u64>,
+    touched: Signal<HashSet<String>>,
+    submitted: Signal<bool>,
+    submitting: Signal<bool>,
+    elements: CopyValue<HashMap<String, Rc<MountedData>>>,
+}
+
+impl<V: 'static> Clone for Form<V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<V: 'static> Copy for Form<V> {}
+
+impl<V: 'static> PartialEq for Form<V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+/// Create a form that owns a value of type `V`.
+///
+/// `init` builds the initial value, which is also what [`Form::is_dirty`]
+/// compares against. `setup` declares the fields and their validators; both
+/// run once, when the component is first rendered.
+pub fn use_form<V>(init: impl FnOnce() -> V, setup: impl FnOnce(&mut FormRules<V>)) -> Form<V>
+where
+    V: Clone + PartialEq + 'static,
+{
+    use_hook(|| {
+        let value = init();
+        let mut rules = FormRules { fields: Vec::new() };
+        setup(&mut rules);
+        let errors = validate_all(&rules.fields, &value);
+
+        Form {
+            initial: Signal::new(value.clone()),
+            value: Signal::new(value),
+            fields: CopyValue::new(rules.fields.into()),
+            errors: Signal::new(errors),
+            async_errors: Signal::new(HashMap::new()),
+            pending: Signal::new(HashMap::new()),
+            generation: CopyValue::new(0),
+            touched: Signal::new(HashSet::new()),
+            submitted: Signal::new(false),
+            submitting: Signal::new(false),
+            elements: CopyValue::new(HashMap::new()),
+        }
+    })
+}
+
+fn validate_all<V: 'static>(fields: &[FieldRules<V>], value: &V) -> HashMap<String, String> {
+    fields
+        .iter()
+        .filter_map(|field| Some((field.name.clone(), field.validate(value)?)))
+        .collect()
+}
+
+impl<V> Form<V>
+where
+    V: Clone + PartialEq + 'static,
+{
+    fn with_field<R>(&self, name: &str, f: impl FnOnce(&FieldRules<V>) -> R) -> Option<R> {
+        let fields = self.fields.read().clone();
+        let field = fields.iter().find(|field| field.name == name);
+        if field.is_none() {
+            tracing::warn!("use_form: unknown field `{name}`");
+        }
+        field.map(f)
+    }
+
+    /// The current form value
+    pub fn value(&self) -> V {
+        self.value.read().clone()
+    }
+
+    /// The text of a field, for an input's `value` prop
+    pub fn text(&self, name: &str) -> String {
+        self.with_field(name, |field| (field.get)(&self.value.read()))
+            .unwrap_or_default()
+    }
+
+    /// Set a field from its text, revalidate and start its async checks
+    pub fn set_text(&mut self, name: &str, text: String) {
+        let mut value = self.value;
+        if self
+            .with_field(name, |field| (field.set)(&mut value.write(), text))
+            .is_none()
+        {
+            return;
+        }
+        self.revalidate();
+        self.run_async(name);
+    }
+
+    /// Change the value directly and revalidate. Async checks run on submit.
+    pub fn update(&mut self, f: impl FnOnce(&mut V)) {
+        f(&mut self.value.write());
+        self.async_errors.write().clear();
+        self.revalidate();
+    }
+
+    fn revalidate(&mut self) {
+        let fields = self.fields.read().clone();
+        let errors = validate_all(&fields, &self.value.read());
+        self.errors.set(errors);
+    }
+
+    fn run_async(&mut self, name: &str) {
+        let fields = self.fields.read().clone();
+        let Some(field) = fields.iter().find(|field| field.name == name) else {
+            return;
+        };
+        self.async_errors.write().remove(name);
+        if field.async_checks.is_empty() || self.errors.read().contains_key(name) {
+            self.pending.write().remove(name);
+            return;
+        }
+
+        *self.generation.write() += 1;
+        let generation = *self.generation.read();
+        self.pending.write().insert(name.to_string(), generation);
+
+        let mut form = *self;
+        let name = name.to_string();
+        let value = self.value();
+        spawn(async move {
+            let field = fields
+                .iter()
+                .find(|field| field.name == name)
+                .expect("field exists");
+            let result = field.validate_async(value).await;
+            // A newer edit has started its own check
+            if form.pending.peek().get(&name) != Some(&generation) {
+                return;
+            }
+            form.pending.write().remove(&name);
+            if let Some(message) = result {
+                form.async_errors.write().insert(name, message);
+            }
+        });
+    }
+
+    /// Mark a field as touched, so its error is shown
+    pub fn touch(&mut self, name: &str) {
+        if !self.touched.read().contains(name) {
+            self.touched.write().insert(name.to_string());
+        }
+    }
+
+    /// Whether the field has been blurred since the form was created or reset
+    pub fn is_touched(&self, name: &str) -> bool {
+        self.touched.read().contains(name)
+    }
+
+    /// Whether the value differs from the initial value
+    pub fn is_dirty(&self) -> bool {
+        *self.value.read() != *self.initial.read()
+    }
+
+    /// Whether a field's text differs from its initial text
+    pub fn is_field_dirty(&self, name: &str) -> bool {
+        self.with_field(name, |field| {
+            (field.get)(&self.value.read()) != (field.get)(&self.initial.read())
+        })
+        .unwrap_or(false)
+    }
+
+    /// The field's current error, whether or not it is shown yet
+    pub fn validation_error(&self, name: &str) -> Option<String> {
+        let error = self.errors.read().get(name).cloned();
+        error.or_else(|| self.async_errors.read().get(name).cloned())
+    }
+
+    /// The error to display for a field, or `None` until the field has been
+    /// touched or a submit has been attempted. For [`FormField`](crate::FormField).
+    pub fn field_error(&self, name: &str) -> Option<String> {
+        if *self.submitted.read() || self.is_touched(name) {
+            self.validation_error(name)
+        } else {
+            None
+        }
+    }
+
+    /// Like [`Form::field_error`], as the `String` taken by the `error` prop
+    /// of `Input`, `Select` and `Textarea`
+    pub fn error(&self, name: &str) -> String {
+        self.field_error(name).unwrap_or_default()
+    }
+
+    /// Whether every field passes its checks, with no async checks in flight
+    pub fn is_valid(&self) -> bool {
+        self.errors.read().is_empty()
+            && self.async_errors.read().is_empty()
+            && self.pending.read().is_empty()
+    }
+
+    /// Whether an async check is running for the field
+    pub fn is_validating(&self, name: &str) -> bool {
+        self.pending.read().contains_key(name)
+    }
+
+    /// Whether the submit handler is running
+    pub fn is_submitting(&self) -> bool {
+        *self.submitting.read()
+    }
+
+    /// Restore the initial value and clear touched and submit state
+    pub fn reset(&mut self) {
+        let initial = self.initial.read().clone();
+        self.value.set(initial);
+        self.touched.write().clear();
+        self.async_errors.write().clear();
+        self.pending.write().clear();
+        self.submitted.set(false);
+        self.revalidate();
+    }
+
+    /// Handler for an input's `oninput` (or a select's `onchange`)
+    pub fn oninput(&self, name: &str) -> EventHandler<FormEvent> {
+        let mut form = *self;
+        let name = name.to_string();
+        EventHandler::new(move |evt: FormEvent| form.set_text(&name, evt.value()))
+    }
+
+    /// Handler for an input's `onblur`, marking the field touched
+    pub fn onblur(&self, name: &str) -> EventHandler<FocusEvent> {
+        let mut form = *self;
+        let name = name.to_string();
+        EventHandler::new(move |_| form.touch(&name))
+    }
+
+    /// Handler for an input's `onmounted`, so submit can focus the field
+    pub fn onmounted(&self, name: &str) -> EventHandler<MountedEvent> {
+        let mut elements = self.elements;
+        let name = name.to_string();
+        EventHandler::new(move |evt: MountedEvent| {
+            elements.write().insert(name.clone(), evt.data());
+        })
+    }
+
+    /// Handler for a `form`'s `onsubmit`.
+    ///
+    /// Validates every field, waiting for async checks. If anything fails,
+    /// all errors are shown and the first invalid field (in declaration
+    /// order) is focused. Otherwise `on_submit` runs with the current value
+    /// while [`Form::is_submitting`] is true.
+    pub fn handle_submit<F, Fut>(&self, on_submit: F) -> EventHandler<FormEvent>
+    where
+        F: Fn(V) -> Fut + 'static,
+        Fut: Future<Output = ()> + 'static,
+    {
+        let form = *self;
+        let on_submit = Rc::new(on_submit);
+        EventHandler::new(move |evt: FormEvent| {
+            evt.prevent_default();
+            let mut form = form;
+            let on_submit = on_submit.clone();
+            spawn(async move {
+                form.submit(on_submit.as_ref()).await;
+            });
+        })
+    }
+
+    /// Validate and submit, as [`Form::handle_submit`] does
+    pub async fn submit<Fut: Future<Output = ()>>(&mut self, on_submit: impl Fn(V) -> Fut) {
+        if *self.submitting.peek() {
+            return;
+        }
+        self.submitted.set(true);
+        self.submitting.set(true);
+        self.revalidate();
+
+        let fields = self.fields.read().clone();
+        let value = self.value();
+        self.pending.write().clear();
+        let mut async_errors = HashMap::new();
+        for field in fields.iter() {
+            if self.errors.peek().contains_key(&field.name) {
+                continue;
+            }
+            if let Some(message) = field.validate_async(value.clone()).await {
+                async_errors.insert(field.name.clone(), message);
+            }
+        }
+        self.async_errors.set(async_errors);
+
+        let invalid = fields
+            .iter()
+            .find(|field| self.validation_error(&field.name).is_some());
+        if let Some(field) = invalid {
+            self.submitting.set(false);
+            let element = self.elements.peek().get(&field.name).cloned();
+            if let Some(element) = element {
+                let _ = element.set_focus(true).await;
+            }
+            return;
+        }
+
+        on_submit(value).await;
+        self.submitting.set(false);
+    }
+}
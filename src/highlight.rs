@@ -0,0 +1,1939 @@
+//! Syntax highlighting for code blocks
+//!
+//! A small tokenizer written in plain Rust, so it produces identical output under
+//! SSR and wasm. It does not try to be a real parser: each language is described
+//! by a [`Syntax`] table (comments, strings, keywords...) and lexed by a shared
+//! scanner, with dedicated scanners for the markup and config languages where a
+//! C-like scanner would do a poor job (Markdown, HTML, CSS, YAML, Jinja, assembly).
+
+use crate::components::code_block::Language;
+
+/// Classification of a highlighted token
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+    /// Unclassified text (whitespace, plain identifiers, prose)
+    Text,
+    /// Language keyword (`fn`, `if`, `return`...)
+    Keyword,
+    /// Type name
+    Type,
+    /// Function or method name
+    Function,
+    /// Macro invocation (`println!`)
+    Macro,
+    /// String or character literal
+    String,
+    /// Numeric literal
+    Number,
+    /// Built-in constant (`true`, `null`, `SCREAMING_CASE`)
+    Constant,
+    /// Comment
+    Comment,
+    /// Operator (`+`, `=>`, `&&`...)
+    Operator,
+    /// Brackets, delimiters and separators
+    Punctuation,
+    /// Attribute, decorator or annotation
+    Attribute,
+    /// Object key or property name
+    Property,
+    /// Markup tag or selector
+    Tag,
+    /// Variable with a sigil (`$HOME`, `--gap`)
+    Variable,
+    /// Label or lifetime
+    Label,
+    /// Markdown heading
+    Heading,
+    /// Markdown emphasis
+    Emphasis,
+    /// Markdown strong emphasis
+    Strong,
+    /// Markdown link
+    Link,
+}
+
+impl TokenKind {
+    /// Get the token kind as a string
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Text => "text",
+            Self::Keyword => "keyword",
+            Self::Type => "type",
+            Self::Function => "function",
+            Self::Macro => "macro",
+            Self::String => "string",
+            Self::Number => "number",
+            Self::Constant => "constant",
+            Self::Comment => "comment",
+            Self::Operator => "operator",
+            Self::Punctuation => "punctuation",
+            Self::Attribute => "attribute",
+            Self::Property => "property",
+            Self::Tag => "tag",
+            Self::Variable => "variable",
+            Self::Label => "label",
+            Self::Heading => "heading",
+            Self::Emphasis => "emphasis",
+            Self::Strong => "strong",
+            Self::Link => "link",
+        }
+    }
+}
+
+/// A classified slice of the highlighted source
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token<'a> {
+    /// What kind of token this is
+    pub kind: TokenKind,
+    /// The source text of the token
+    pub text: &'a str,
+}
+
+/// Split `code` into highlighted tokens.
+///
+/// Concatenating the text of every returned token yields `code` back unchanged.
+#[must_use]
+pub fn highlight(code: &str, language: Language) -> Vec<Token<'_>> {
+    let mut lx = Lexer::new(code);
+    lex_language(&mut lx, language);
+    lx.tokens
+}
+
+fn lex_language(lx: &mut Lexer<'_>, language: Language) {
+    match language {
+        Language::Rust => lex_code(lx, &RUST),
+        Language::Javascript => lex_code(lx, &JAVASCRIPT),
+        Language::TypeScript => lex_code(lx, &TYPESCRIPT),
+        Language::Python => lex_code(lx, &PYTHON),
+        Language::Go => lex_code(lx, &GO),
+        Language::Bash => lex_code(lx, &BASH),
+        Language::Json => lex_code(lx, &JSON),
+        Language::Toml => lex_code(lx, &TOML),
+        Language::Css => lex_css(lx, false),
+        Language::Scss => lex_css(lx, true),
+        Language::Html => lex_html(lx),
+        Language::Yaml => lex_yaml(lx),
+        Language::Markdown => lex_markdown(lx),
+        Language::Asm => lex_asm(lx),
+        Language::C => lex_code(lx, &C),
+        Language::Cpp => lex_code(lx, &CPP),
+        Language::CSharp => lex_code(lx, &CSHARP),
+        Language::Java => lex_code(lx, &JAVA),
+        Language::Kotlin => lex_code(lx, &KOTLIN),
+        Language::Swift => lex_code(lx, &SWIFT),
+        Language::Ruby => lex_code(lx, &RUBY),
+        Language::Php => lex_code(lx, &PHP),
+        Language::Lua => lex_code(lx, &LUA),
+        Language::Perl => lex_code(lx, &PERL),
+        Language::R => lex_code(lx, &R),
+        Language::Scala => lex_code(lx, &SCALA),
+        Language::Elixir => lex_code(lx, &ELIXIR),
+        Language::Haskell => lex_code(lx, &HASKELL),
+        Language::Clojure => lex_code(lx, &CLOJURE),
+        Language::Zig => lex_code(lx, &ZIG),
+        Language::Sql => lex_code(lx, &SQL),
+        Language::Dockerfile => lex_code(lx, &DOCKERFILE),
+        Language::Makefile => lex_code(lx, &MAKEFILE),
+        Language::Git => lex_code(lx, &GIT),
+        Language::Xml => lex_html(lx),
+        Language::Jinja => lex_jinja(lx),
+    }
+}
+
+/// Cursor over the source that records contiguous tokens
+struct Lexer<'a> {
+    src: &'a str,
+    pos: usize,
+    tokens: Vec<Token<'a>>,
+}
+
+impl<'a> Lexer<'a> {
+    const fn new(src: &'a str) -> Self {
+        Self {
+            src,
+            pos: 0,
+            tokens: Vec::new(),
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.src[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn prev(&self) -> Option<char> {
+        self.src[..self.pos].chars().next_back()
+    }
+
+    fn at_line_start(&self) -> bool {
+        self.src[..self.pos]
+            .rsplit('\n')
+            .next()
+            .is_none_or(|line| line.trim().is_empty())
+    }
+
+    /// Emit the next `len` bytes as a token of the given kind
+    fn emit(&mut self, kind: TokenKind, len: usize) {
+        if len == 0 {
+            return;
+        }
+        let end = self.pos + len;
+        let text = &self.src[self.pos..end];
+        match self.tokens.last_mut() {
+            Some(last) if last.kind == kind => {
+                // Tokens are contiguous slices of `src`, so merging just widens the slice
+                let start = self.pos - last.text.len();
+                last.text = &self.src[start..end];
+            }
+            _ => self.tokens.push(Token { kind, text }),
+        }
+        self.pos = end;
+    }
+
+    /// Emit everything up to the end of the current line (excluding the newline)
+    fn emit_line(&mut self, kind: TokenKind) {
+        self.emit(kind, line_len(self.rest()));
+    }
+
+    /// Emit leading spaces and tabs as text
+    fn emit_blank(&mut self) {
+        let n = self.rest().len() - self.rest().trim_start_matches([' ', '\t']).len();
+        self.emit(TokenKind::Text, n);
+    }
+
+    /// Emit a single character, classified as operator, punctuation or text
+    fn emit_symbol(&mut self, c: char) {
+        let kind = if "(){}[];,.".contains(c) {
+            TokenKind::Punctuation
+        } else if "+-*/%=<>!&|^~?:@".contains(c) {
+            TokenKind::Operator
+        } else {
+            TokenKind::Text
+        };
+        self.emit(kind, c.len_utf8());
+    }
+
+    /// Lex a nested region with another language, appending its tokens
+    fn embed(&mut self, len: usize, language: Language) {
+        let mut inner = Lexer::new(&self.src[self.pos..self.pos + len]);
+        lex_language(&mut inner, language);
+        for token in inner.tokens {
+            self.emit(token.kind, token.text.len());
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Shared scanning helpers
+// ---------------------------------------------------------------------------
+
+fn is_ident_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+fn is_ident_char(c: char, extra: &[char]) -> bool {
+    c.is_alphanumeric() || c == '_' || extra.contains(&c)
+}
+
+/// Length of the identifier at the start of `s`
+fn ident_len(s: &str, extra: &[char]) -> usize {
+    s.char_indices()
+        .find(|&(_, c)| !is_ident_char(c, extra))
+        .map_or(s.len(), |(i, _)| i)
+}
+
+/// Length of the current line, excluding the newline
+fn line_len(s: &str) -> usize {
+    s.find('\n').unwrap_or(s.len())
+}
+
+/// Length of a quoted string starting at `s` (which begins with `open`)
+fn quoted_len(s: &str, open: &str, close: &str, escapes: bool, multiline: bool) -> usize {
+    let mut chars = s[open.len()..].char_indices();
+    while let Some((i, c)) = chars.next() {
+        let at = open.len() + i;
+        if escapes && c == '\\' {
+            chars.next();
+        } else if s[at..].starts_with(close) {
+            return at + close.len();
+        } else if c == '\n' && !multiline {
+            return at;
+        }
+    }
+    s.len()
+}
+
+/// Length of a numeric literal at the start of `s`
+fn number_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let radix = bytes.len() > 1
+        && bytes[0] == b'0'
+        && matches!(bytes[1], b'x' | b'X' | b'b' | b'B' | b'o' | b'O');
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        let next_is_digit = bytes.get(i + 1).is_some_and(u8::is_ascii_digit);
+        let ok = b.is_ascii_alphanumeric()
+            || b == b'_'
+            || (b == b'.' && next_is_digit && !radix)
+            || (matches!(b, b'+' | b'-')
+                && !radix
+                && i > 0
+                && matches!(bytes[i - 1], b'e' | b'E')
+                && next_is_digit);
+        if !ok {
+            break;
+        }
+        i += 1;
+    }
+    i
+}
+
+/// Length of a bracketed region (`[...]`, `${...}`) starting at `s`, honouring nesting.
+///
+/// An unterminated region ends at the end of the line, or of `s` when `multiline` is set.
+fn bracket_len(s: &str, open: char, close: char, multiline: bool) -> usize {
+    let mut depth = 0usize;
+    for (i, c) in s.char_indices() {
+        if c == open {
+            depth += 1;
+        } else if c == close {
+            depth = depth.saturating_sub(1);
+            if depth == 0 {
+                return i + c.len_utf8();
+            }
+        } else if c == '\n' && !multiline {
+            return i;
+        }
+    }
+    s.len()
+}
+
+fn first_non_blank(s: &str) -> Option<char> {
+    s.trim_start_matches([' ', '\t']).chars().next()
+}
+
+// ---------------------------------------------------------------------------
+// Table-driven scanner for C-like languages
+// ---------------------------------------------------------------------------
+
+/// Lexical description of a language for the shared scanner
+struct Syntax {
+    /// Line comment markers
+    line_comments: &'static [&'static str],
+    /// Block comment delimiters
+    block_comments: &'static [(&'static str, &'static str)],
+    /// Multi-character string delimiters (checked before `quotes`)
+    long_strings: &'static [&'static str],
+    /// Single-character string delimiters
+    quotes: &'static [char],
+    /// Prefixes that may directly precede a string (`r"..."`, `f'...'`)
+    string_prefixes: &'static [&'static str],
+    /// Whether single-character strings may span lines
+    multiline_strings: bool,
+    /// Language keywords
+    keywords: &'static [&'static str],
+    /// Keywords after which the next identifier names a function
+    fn_keywords: &'static [&'static str],
+    /// Keywords after which the next identifier names a type
+    type_keywords: &'static [&'static str],
+    /// Built-in type names
+    types: &'static [&'static str],
+    /// Built-in constants
+    constants: &'static [&'static str],
+    /// Built-in functions
+    builtins: &'static [&'static str],
+    /// Extra characters allowed inside identifiers
+    ident_chars: &'static [char],
+    /// Capitalized identifiers are types, all-caps ones constants
+    capitalized_types: bool,
+    /// `name!` is a macro invocation (Rust)
+    bang_macros: bool,
+    /// `'a` is a lifetime (Rust)
+    lifetimes: bool,
+    /// `r#"..."#` raw strings (Rust)
+    raw_strings: bool,
+    /// `#[...]` attributes (Rust)
+    hash_attributes: bool,
+    /// `@name` decorators
+    decorators: bool,
+    /// Sigils introducing variables (`$` in shell)
+    variable_sigils: &'static [char],
+    /// Identifiers or strings followed by this character are property keys
+    key_separator: Option<char>,
+    /// `[section]` headers at the start of a line
+    section_headers: bool,
+    /// `#include`-style directives at the start of a line (C family)
+    preprocessor: bool,
+    /// Word lists match regardless of case (SQL, Dockerfile)
+    ignore_case: bool,
+}
+
+impl Syntax {
+    const DEFAULT: Self = Self {
+        line_comments: &[],
+        block_comments: &[],
+        long_strings: &[],
+        quotes: &['"', '\''],
+        string_prefixes: &[],
+        multiline_strings: false,
+        keywords: &[],
+        fn_keywords: &[],
+        type_keywords: &[],
+        types: &[],
+        constants: &[],
+        builtins: &[],
+        ident_chars: &[],
+        capitalized_types: false,
+        bang_macros: false,
+        lifetimes: false,
+        raw_strings: false,
+        hash_attributes: false,
+        decorators: false,
+        variable_sigils: &[],
+        key_separator: None,
+        section_headers: false,
+        preprocessor: false,
+        ignore_case: false,
+    };
+
+    /// Whether `word` appears in one of this syntax's word lists
+    fn has_word(&self, list: &[&str], word: &str) -> bool {
+        if self.ignore_case {
+            list.iter().any(|w| w.eq_ignore_ascii_case(word))
+        } else {
+            list.contains(&word)
+        }
+    }
+}
+
+#[rustfmt::skip]
+const RUST: Syntax = Syntax {
+    line_comments: &["//"],
+    block_comments: &[("/*", "*/")],
+    quotes: &['"'],
+    string_prefixes: &["b", "c"],
+    multiline_strings: true,
+    keywords: &[
+        "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum",
+        "extern", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut",
+        "pub", "ref", "return", "self", "Self", "static", "struct", "super", "trait", "type",
+        "union", "unsafe", "use", "where", "while", "yield",
+    ],
+    fn_keywords: &["fn"],
+    type_keywords: &["struct", "enum", "trait", "type", "union"],
+    types: &[
+        "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
+        "f32", "f64", "bool", "char", "str",
+    ],
+    constants: &["true", "false"],
+    capitalized_types: true,
+    bang_macros: true,
+    lifetimes: true,
+    raw_strings: true,
+    hash_attributes: true,
+    ..Syntax::DEFAULT
+};
+
+#[rustfmt::skip]
+const JAVASCRIPT: Syntax = Syntax {
+    line_comments: &["//"],
+    block_comments: &[("/*", "*/")],
+    long_strings: &["`"],
+    keywords: &[
+        "async", "await", "break", "case", "catch", "class", "const", "continue", "debugger",
+        "default", "delete", "do", "else", "export", "extends", "finally", "for", "from",
+        "function", "get", "if", "import", "in", "instanceof", "let", "new", "of", "return", "set",
+        "static", "super", "switch", "this", "throw", "try", "typeof", "var", "void", "while",
+        "with", "yield",
+    ],
+    fn_keywords: &["function"],
+    type_keywords: &["class", "extends"],
+    constants: &["true", "false", "null", "undefined", "NaN", "Infinity"],
+    ident_chars: &['$'],
+    capitalized_types: true,
+    decorators: true,
+    ..Syntax::DEFAULT
+};
+
+#[rustfmt::skip]
+const TYPESCRIPT: Syntax = Syntax {
+    keywords: &[
+        "abstract", "as", "async", "await", "break", "case", "catch", "class", "const", "continue",
+        "debugger", "declare", "default", "delete", "do", "else", "enum", "export", "extends",
+        "finally", "for", "from", "function", "get", "if", "implements", "import", "in", "infer",
+        "instanceof", "interface", "is", "keyof", "let", "module", "namespace", "new", "of",
+        "private", "protected", "public", "readonly", "return", "satisfies", "set", "static",
+        "super", "switch", "this", "throw", "try", "type", "typeof", "var", "void", "while", "with",
+        "yield",
+    ],
+    type_keywords: &[
+        "class", "extends", "implements", "interface", "type", "enum",
+    ],
+    types: &[
+        "any", "bigint", "boolean", "never", "number", "object", "string", "symbol", "unknown",
+    ],
+    ..JAVASCRIPT
+};
+
+#[rustfmt::skip]
+const PYTHON: Syntax = Syntax {
+    line_comments: &["#"],
+    long_strings: &["\"\"\"", "'''"],
+    string_prefixes: &[
+        "rb", "br", "Rb", "bR", "RB", "BR", "f", "F", "r", "R", "b", "B", "u", "U",
+    ],
+    keywords: &[
+        "and", "as", "assert", "async", "await", "break", "case", "class", "continue", "def",
+        "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import", "in",
+        "is", "lambda", "match", "nonlocal", "not", "or", "pass", "raise", "return", "try",
+        "while", "with", "yield",
+    ],
+    fn_keywords: &["def"],
+    type_keywords: &["class"],
+    types: &[
+        "bool", "bytes", "dict", "float", "frozenset", "int", "list", "object", "set", "str",
+        "tuple",
+    ],
+    constants: &["True", "False", "None", "self", "cls"],
+    builtins: &[
+        "abs", "all", "any", "enumerate", "filter", "getattr", "hasattr", "isinstance", "iter",
+        "len", "map", "max", "min", "next", "open", "print", "range", "repr", "reversed", "setattr",
+        "sorted", "sum", "super", "type", "zip",
+    ],
+    capitalized_types: true,
+    decorators: true,
+    ..Syntax::DEFAULT
+};
+
+#[rustfmt::skip]
+const GO: Syntax = Syntax {
+    line_comments: &["//"],
+    block_comments: &[("/*", "*/")],
+    long_strings: &["`"],
+    keywords: &[
+        "break", "case", "chan", "const", "continue", "default", "defer", "else", "fallthrough",
+        "for", "func", "go", "goto", "if", "import", "interface", "map", "package", "range",
+        "return", "select", "struct", "switch", "type", "var",
+    ],
+    fn_keywords: &["func"],
+    type_keywords: &["type"],
+    types: &[
+        "any", "bool", "byte", "complex64", "complex128", "error", "float32", "float64", "int",
+        "int8", "int16", "int32", "int64", "rune", "string", "uint", "uint8", "uint16", "uint32",
+        "uint64", "uintptr",
+    ],
+    constants: &["true", "false", "nil", "iota"],
+    builtins: &[
+        "append", "cap", "close", "copy", "delete", "len", "make", "new", "panic", "print",
+        "println", "recover",
+    ],
+    ..Syntax::DEFAULT
+};
+
+#[rustfmt::skip]
+const BASH: Syntax = Syntax {
+    line_comments: &["#"],
+    multiline_strings: true,
+    keywords: &[
+        "break", "case", "continue", "declare", "do", "done", "elif", "else", "esac", "exit",
+        "export", "fi", "for", "function", "if", "in", "local", "readonly", "return", "select",
+        "shift", "source", "then", "unset", "until", "while",
+    ],
+    fn_keywords: &["function"],
+    constants: &["true", "false"],
+    builtins: &[
+        "alias", "cd", "echo", "eval", "exec", "printf", "pwd", "read", "set", "test", "trap",
+        "wait",
+    ],
+    variable_sigils: &['$'],
+    ..Syntax::DEFAULT
+};
+
+#[rustfmt::skip]
+const JSON: Syntax = Syntax {
+    quotes: &['"'],
+    constants: &["true", "false", "null"],
+    key_separator: Some(':'),
+    ..Syntax::DEFAULT
+};
+
+#[rustfmt::skip]
+const TOML: Syntax = Syntax {
+    line_comments: &["#"],
+    long_strings: &["\"\"\"", "'''"],
+    constants: &["true", "false", "inf", "nan"],
+    ident_chars: &['-'],
+    key_separator: Some('='),
+    section_headers: true,
+    ..Syntax::DEFAULT
+};
+
+#[rustfmt::skip]
+const C: Syntax = Syntax {
+    line_comments: &["//"],
+    block_comments: &[("/*", "*/")],
+    string_prefixes: &["L", "u8", "u", "U"],
+    keywords: &[
+        "auto", "break", "case", "const", "continue", "default", "do", "else", "enum", "extern",
+        "for", "goto", "if", "inline", "register", "restrict", "return", "sizeof", "static",
+        "struct", "switch", "typedef", "union", "volatile", "while",
+    ],
+    type_keywords: &["struct", "enum", "union"],
+    types: &[
+        "bool", "char", "double", "float", "int", "long", "short", "signed", "unsigned", "void",
+        "size_t", "ssize_t", "int8_t", "int16_t", "int32_t", "int64_t", "uint8_t", "uint16_t",
+        "uint32_t", "uint64_t", "FILE",
+    ],
+    constants: &["NULL", "true", "false"],
+    preprocessor: true,
+    ..Syntax::DEFAULT
+};
+
+#[rustfmt::skip]
+const CPP: Syntax = Syntax {
+    keywords: &[
+        "alignas", "alignof", "auto", "break", "case", "catch", "class", "const", "consteval",
+        "constexpr", "continue", "co_await", "co_return", "co_yield", "decltype", "default",
+        "delete", "do", "else", "enum", "explicit", "export", "extern", "final", "for", "friend",
+        "goto", "if", "inline", "mutable", "namespace", "new", "noexcept", "operator", "override",
+        "private", "protected", "public", "return", "sizeof", "static", "static_assert",
+        "struct", "switch", "template", "this", "throw", "try", "typedef", "typename", "union",
+        "using", "virtual", "volatile", "while",
+    ],
+    type_keywords: &["class", "struct", "enum", "union", "namespace"],
+    constants: &["NULL", "nullptr", "true", "false"],
+    ..C
+};
+
+#[rustfmt::skip]
+const CSHARP: Syntax = Syntax {
+    line_comments: &["//"],
+    block_comments: &[("/*", "*/")],
+    string_prefixes: &["$@", "@$", "$", "@"],
+    keywords: &[
+        "abstract", "as", "async", "await", "base", "break", "case", "catch", "checked", "class",
+        "const", "continue", "default", "delegate", "do", "else", "enum", "event", "explicit",
+        "extern", "finally", "fixed", "for", "foreach", "get", "goto", "if", "implicit", "in",
+        "init", "interface", "internal", "is", "lock", "namespace", "new", "operator", "out",
+        "override", "params", "partial", "private", "protected", "public", "readonly", "record",
+        "ref", "return", "sealed", "set", "sizeof", "stackalloc", "static", "struct", "switch",
+        "this", "throw", "try", "typeof", "unchecked", "unsafe", "using", "var", "virtual",
+        "void", "volatile", "when", "where", "while", "yield",
+    ],
+    type_keywords: &["class", "struct", "enum", "interface", "record", "namespace"],
+    types: &[
+        "bool", "byte", "char", "decimal", "double", "dynamic", "float", "int", "long", "nint",
+        "nuint", "object", "sbyte", "short", "string", "uint", "ulong", "ushort",
+    ],
+    constants: &["true", "false", "null"],
+    capitalized_types: true,
+    preprocessor: true,
+    ..Syntax::DEFAULT
+};
+
+#[rustfmt::skip]
+const JAVA: Syntax = Syntax {
+    line_comments: &["//"],
+    block_comments: &[("/*", "*/")],
+    long_strings: &["\"\"\""],
+    keywords: &[
+        "abstract", "assert", "break", "case", "catch", "class", "const", "continue", "default",
+        "do", "else", "enum", "extends", "final", "finally", "for", "goto", "if", "implements",
+        "import", "instanceof", "interface", "native", "new", "package", "permits", "private",
+        "protected", "public", "record", "return", "sealed", "static", "strictfp", "super",
+        "switch", "synchronized", "this", "throw", "throws", "transient", "try", "var", "void",
+        "volatile", "while", "yield",
+    ],
+    type_keywords: &["class", "interface", "enum", "record", "extends", "implements"],
+    types: &["boolean", "byte", "char", "double", "float", "int", "long", "short"],
+    constants: &["true", "false", "null"],
+    capitalized_types: true,
+    decorators: true,
+    ..Syntax::DEFAULT
+};
+
+#[rustfmt::skip]
+const KOTLIN: Syntax = Syntax {
+    line_comments: &["//"],
+    block_comments: &[("/*", "*/")],
+    long_strings: &["\"\"\""],
+    keywords: &[
+        "abstract", "as", "break", "by", "catch", "class", "companion", "const", "continue",
+        "data", "do", "else", "enum", "finally", "for", "fun", "if", "import", "in", "init",
+        "inline", "interface", "internal", "is", "lateinit", "object", "open", "operator",
+        "out", "override", "package", "private", "protected", "public", "return", "sealed",
+        "super", "suspend", "this", "throw", "try", "typealias", "val", "var", "when", "while",
+    ],
+    fn_keywords: &["fun"],
+    type_keywords: &["class", "interface", "object", "typealias"],
+    constants: &["true", "false", "null"],
+    capitalized_types: true,
+    decorators: true,
+    ..Syntax::DEFAULT
+};
+
+#[rustfmt::skip]
+const SWIFT: Syntax = Syntax {
+    line_comments: &["//"],
+    block_comments: &[("/*", "*/")],
+    long_strings: &["\"\"\""],
+    quotes: &['"'],
+    keywords: &[
+        "actor", "as", "associatedtype", "async", "await", "break", "case", "catch", "class",
+        "continue", "default", "defer", "deinit", "do", "else", "enum", "extension",
+        "fallthrough", "fileprivate", "final", "for", "func", "guard", "if", "import", "in",
+        "init", "inout", "internal", "is", "let", "mutating", "open", "operator", "override",
+        "private", "protocol", "public", "repeat", "rethrows", "return", "self", "Self",
+        "static", "struct", "subscript", "super", "switch", "throw", "throws", "try",
+        "typealias", "var", "where", "while",
+    ],
+    fn_keywords: &["func"],
+    type_keywords: &["class", "struct", "enum", "protocol", "extension", "actor", "typealias"],
+    constants: &["true", "false", "nil"],
+    capitalized_types: true,
+    decorators: true,
+    ..Syntax::DEFAULT
+};
+
+#[rustfmt::skip]
+const RUBY: Syntax = Syntax {
+    line_comments: &["#"],
+    block_comments: &[("=begin", "=end")],
+    multiline_strings: true,
+    keywords: &[
+        "alias", "and", "begin", "break", "case", "class", "def", "do", "else", "elsif", "end",
+        "ensure", "for", "if", "in", "module", "next", "not", "or", "redo", "rescue", "retry",
+        "return", "super", "then", "undef", "unless", "until", "when", "while", "yield",
+    ],
+    fn_keywords: &["def"],
+    type_keywords: &["class", "module"],
+    constants: &["true", "false", "nil", "self"],
+    builtins: &[
+        "attr_accessor", "attr_reader", "attr_writer", "include", "extend", "puts", "print",
+        "require", "require_relative", "raise", "private", "protected", "public",
+    ],
+    ident_chars: &['?', '!'],
+    capitalized_types: true,
+    variable_sigils: &['@', '$'],
+    ..Syntax::DEFAULT
+};
+
+#[rustfmt::skip]
+const PHP: Syntax = Syntax {
+    line_comments: &["//", "#"],
+    block_comments: &[("/*", "*/")],
+    multiline_strings: true,
+    keywords: &[
+        "abstract", "and", "as", "break", "case", "catch", "class", "clone", "const", "continue",
+        "declare", "default", "do", "echo", "else", "elseif", "enum", "extends", "final",
+        "finally", "fn", "for", "foreach", "function", "global", "if", "implements", "include",
+        "instanceof", "interface", "match", "namespace", "new", "or", "print", "private",
+        "protected", "public", "readonly", "require", "require_once", "return", "static",
+        "switch", "throw", "trait", "try", "use", "while", "yield",
+    ],
+    fn_keywords: &["function"],
+    type_keywords: &["class", "interface", "trait", "enum", "extends", "implements"],
+    types: &["array", "bool", "float", "int", "iterable", "mixed", "object", "string", "void"],
+    constants: &["true", "false", "null", "TRUE", "FALSE", "NULL"],
+    capitalized_types: true,
+    variable_sigils: &['$'],
+    ..Syntax::DEFAULT
+};
+
+#[rustfmt::skip]
+const LUA: Syntax = Syntax {
+    line_comments: &["--"],
+    block_comments: &[("--[[", "]]")],
+    keywords: &[
+        "and", "break", "do", "else", "elseif", "end", "for", "function", "goto", "if", "in",
+        "local", "not", "or", "repeat", "return", "then", "until", "while",
+    ],
+    fn_keywords: &["function"],
+    constants: &["true", "false", "nil"],
+    builtins: &[
+        "assert", "error", "ipairs", "next", "pairs", "pcall", "print", "require", "select",
+        "setmetatable", "getmetatable", "tonumber", "tostring", "type", "unpack",
+    ],
+    ..Syntax::DEFAULT
+};
+
+#[rustfmt::skip]
+const PERL: Syntax = Syntax {
+    line_comments: &["#"],
+    block_comments: &[("=pod", "=cut")],
+    multiline_strings: true,
+    keywords: &[
+        "die", "do", "else", "elsif", "eval", "for", "foreach", "if", "last", "local", "my",
+        "next", "no", "our", "package", "redo", "require", "return", "sub", "unless", "until",
+        "use", "while",
+    ],
+    fn_keywords: &["sub"],
+    type_keywords: &["package"],
+    builtins: &[
+        "chomp", "defined", "delete", "each", "exists", "join", "keys", "map", "open", "print",
+        "printf", "push", "pop", "shift", "sort", "split", "sprintf", "unshift", "values",
+    ],
+    variable_sigils: &['$', '@', '%'],
+    ..Syntax::DEFAULT
+};
+
+#[rustfmt::skip]
+const R: Syntax = Syntax {
+    line_comments: &["#"],
+    multiline_strings: true,
+    keywords: &[
+        "break", "else", "for", "function", "if", "in", "next", "repeat", "return", "while",
+    ],
+    constants: &[
+        "TRUE", "FALSE", "NULL", "NA", "NA_integer_", "NA_real_", "NA_character_", "Inf", "NaN",
+    ],
+    builtins: &["c", "library", "list", "print", "require", "source"],
+    ident_chars: &['.'],
+    ..Syntax::DEFAULT
+};
+
+#[rustfmt::skip]
+const SCALA: Syntax = Syntax {
+    line_comments: &["//"],
+    block_comments: &[("/*", "*/")],
+    long_strings: &["\"\"\""],
+    string_prefixes: &["s", "f", "raw"],
+    keywords: &[
+        "abstract", "case", "catch", "class", "def", "do", "else", "enum", "extends", "extension",
+        "final", "finally", "for", "given", "if", "implicit", "import", "lazy", "match", "new",
+        "object", "override", "package", "private", "protected", "return", "sealed", "super",
+        "then", "this", "throw", "trait", "try", "type", "using", "val", "var", "while", "with",
+        "yield",
+    ],
+    fn_keywords: &["def"],
+    type_keywords: &["class", "object", "trait", "type", "enum", "extends", "with"],
+    constants: &["true", "false", "null"],
+    capitalized_types: true,
+    decorators: true,
+    ..Syntax::DEFAULT
+};
+
+#[rustfmt::skip]
+const ELIXIR: Syntax = Syntax {
+    line_comments: &["#"],
+    long_strings: &["\"\"\"", "'''"],
+    multiline_strings: true,
+    keywords: &[
+        "after", "alias", "and", "case", "catch", "cond", "def", "defimpl", "defmacro",
+        "defmacrop", "defmodule", "defp", "defprotocol", "defstruct", "do", "else", "end", "fn",
+        "for", "if", "import", "in", "not", "or", "quote", "raise", "receive", "require",
+        "rescue", "try", "unless", "unquote", "use", "when", "with",
+    ],
+    fn_keywords: &["def", "defp", "defmacro", "defmacrop"],
+    type_keywords: &["defmodule", "defprotocol", "defimpl"],
+    constants: &["true", "false", "nil"],
+    ident_chars: &['?', '!'],
+    capitalized_types: true,
+    decorators: true,
+    ..Syntax::DEFAULT
+};
+
+#[rustfmt::skip]
+const HASKELL: Syntax = Syntax {
+    line_comments: &["--"],
+    block_comments: &[("{-", "-}")],
+    keywords: &[
+        "case", "class", "data", "default", "deriving", "do", "else", "forall", "if", "import",
+        "in", "infix", "infixl", "infixr", "instance", "let", "module", "newtype", "of",
+        "qualified", "then", "type", "where",
+    ],
+    type_keywords: &["class", "data", "newtype", "type", "instance"],
+    constants: &["True", "False", "Nothing", "otherwise"],
+    ident_chars: &['\''],
+    capitalized_types: true,
+    ..Syntax::DEFAULT
+};
+
+#[rustfmt::skip]
+const CLOJURE: Syntax = Syntax {
+    line_comments: &[";"],
+    quotes: &['"'],
+    multiline_strings: true,
+    keywords: &[
+        "case", "catch", "cond", "def", "defmacro", "defmethod", "defmulti", "defn", "defn-",
+        "defonce", "defprotocol", "defrecord", "deftype", "do", "finally", "fn", "if", "if-let",
+        "let", "letfn", "loop", "ns", "quote", "recur", "throw", "try", "when", "when-let",
+    ],
+    fn_keywords: &["defn", "defn-", "defmacro"],
+    type_keywords: &["defrecord", "deftype", "defprotocol"],
+    constants: &["true", "false", "nil"],
+    ident_chars: &['-', '?', '!', '*', '<', '>', '/', '.'],
+    ..Syntax::DEFAULT
+};
+
+#[rustfmt::skip]
+const ZIG: Syntax = Syntax {
+    line_comments: &["//"],
+    keywords: &[
+        "align", "allowzero", "and", "asm", "async", "await", "break", "catch", "comptime",
+        "const", "continue", "defer", "else", "enum", "errdefer", "error", "export", "extern",
+        "fn", "for", "if", "inline", "noalias", "nosuspend", "opaque", "or", "orelse", "packed",
+        "pub", "resume", "return", "struct", "suspend", "switch", "test", "threadlocal", "try",
+        "union", "unreachable", "usingnamespace", "var", "volatile", "while",
+    ],
+    fn_keywords: &["fn"],
+    types: &[
+        "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
+        "f16", "f32", "f64", "f128", "bool", "void", "noreturn", "type", "anyerror", "anytype",
+        "comptime_int", "comptime_float",
+    ],
+    constants: &["true", "false", "null", "undefined"],
+    capitalized_types: true,
+    decorators: true,
+    ..Syntax::DEFAULT
+};
+
+#[rustfmt::skip]
+const SQL: Syntax = Syntax {
+    line_comments: &["--"],
+    block_comments: &[("/*", "*/")],
+    quotes: &['\''],
+    multiline_strings: true,
+    keywords: &[
+        "add", "all", "alter", "and", "as", "asc", "begin", "between", "by", "case", "check",
+        "commit", "constraint", "create", "cross", "default", "delete", "desc", "distinct",
+        "drop", "else", "end", "exists", "foreign", "from", "full", "group", "having", "if",
+        "in", "index", "inner", "insert", "into", "is", "join", "key", "left", "like", "limit",
+        "not", "offset", "on", "or", "order", "outer", "primary", "references", "returning",
+        "right", "rollback", "select", "set", "table", "then", "transaction", "union", "unique",
+        "update", "values", "view", "when", "where", "with",
+    ],
+    type_keywords: &["table", "view", "index"],
+    types: &[
+        "bigint", "blob", "boolean", "char", "date", "decimal", "double", "float", "int",
+        "integer", "json", "jsonb", "numeric", "real", "serial", "smallint", "text", "time",
+        "timestamp", "timestamptz", "uuid", "varchar",
+    ],
+    constants: &["null", "true", "false"],
+    builtins: &["avg", "coalesce", "count", "max", "min", "now", "sum"],
+    ignore_case: true,
+    ..Syntax::DEFAULT
+};
+
+#[rustfmt::skip]
+const DOCKERFILE: Syntax = Syntax {
+    line_comments: &["#"],
+    keywords: &[
+        "ADD", "ARG", "AS", "CMD", "COPY", "ENTRYPOINT", "ENV", "EXPOSE", "FROM", "HEALTHCHECK",
+        "LABEL", "MAINTAINER", "ONBUILD", "RUN", "SHELL", "STOPSIGNAL", "USER", "VOLUME",
+        "WORKDIR",
+    ],
+    ignore_case: true,
+    variable_sigils: &['$'],
+    ..Syntax::DEFAULT
+};
+
+#[rustfmt::skip]
+const MAKEFILE: Syntax = Syntax {
+    line_comments: &["#"],
+    keywords: &[
+        ".PHONY", "define", "else", "endef", "endif", "export", "ifdef", "ifeq", "ifndef",
+        "ifneq", "include", "override", "unexport", "vpath",
+    ],
+    builtins: &[
+        "addprefix", "addsuffix", "basename", "call", "dir", "error", "eval", "filter",
+        "filter-out", "findstring", "foreach", "info", "notdir", "origin", "patsubst", "shell",
+        "sort", "strip", "subst", "warning", "wildcard", "word", "words",
+    ],
+    ident_chars: &['-', '.'],
+    variable_sigils: &['$'],
+    key_separator: Some('='),
+    ..Syntax::DEFAULT
+};
+
+#[rustfmt::skip]
+const GIT: Syntax = Syntax {
+    line_comments: &["#"],
+    quotes: &[],
+    ..Syntax::DEFAULT
+};
+
+/// Expressions inside Jinja `{{ }}` and `{% %}` tags
+#[rustfmt::skip]
+const JINJA: Syntax = Syntax {
+    keywords: &[
+        "and", "as", "block", "call", "elif", "else", "endblock", "endcall", "endfilter",
+        "endfor", "endif", "endmacro", "endraw", "endset", "endwith", "extends", "filter", "for",
+        "from", "if", "import", "in", "include", "is", "macro", "not", "or", "raw", "recursive",
+        "set", "with",
+    ],
+    fn_keywords: &["macro"],
+    constants: &["true", "false", "none", "True", "False", "None", "loop"],
+    ..Syntax::DEFAULT
+};
+
+/// Scan source code using a [`Syntax`] table
+fn lex_code(lx: &mut Lexer<'_>, syn: &Syntax) {
+    // Set after keywords like `fn` or `struct` to classify the name that follows
+    let mut pending: Option<TokenKind> = None;
+
+    while let Some(c) = lx.peek() {
+        let rest = lx.rest();
+
+        if c.is_whitespace() {
+            let n = rest
+                .find(|c: char| !c.is_whitespace())
+                .unwrap_or(rest.len());
+            lx.emit(TokenKind::Text, n);
+            continue;
+        }
+
+        let def_kind = pending.take();
+
+        if syn.variable_sigils.contains(&c)
+            && let Some(n) = variable_len(rest)
+        {
+            lx.emit(TokenKind::Variable, n);
+            continue;
+        }
+
+        // Checked before line comments, which may prefix the opener (Lua `--[[`)
+        if let Some((open, close)) = syn
+            .block_comments
+            .iter()
+            .find(|(open, _)| rest.starts_with(open))
+        {
+            let n = rest[open.len()..]
+                .find(close)
+                .map_or(rest.len(), |i| open.len() + i + close.len());
+            lx.emit(TokenKind::Comment, n);
+            continue;
+        }
+
+        if syn.preprocessor && c == '#' && lx.at_line_start() {
+            lx.emit_line(TokenKind::Macro);
+            continue;
+        }
+
+        if let Some(marker) = syn.line_comments.iter().find(|m| rest.starts_with(**m)) {
+            // A shell `#` only starts a comment at the beginning of a word
+            let word_start = *marker != "#" || lx.prev().is_none_or(char::is_whitespace);
+            if word_start {
+                lx.emit_line(TokenKind::Comment);
+                continue;
+            }
+        }
+
+        if syn.hash_attributes && (rest.starts_with("#[") || rest.starts_with("#![")) {
+            lx.emit(TokenKind::Attribute, bracket_len(rest, '[', ']', true));
+            continue;
+        }
+
+        if syn.decorators && c == '@' {
+            let n = ident_len(&rest[1..], &['.']);
+            if n > 0 {
+                lx.emit(TokenKind::Attribute, 1 + n);
+                continue;
+            }
+        }
+
+        if syn.section_headers && c == '[' && lx.at_line_start() {
+            lx.emit(TokenKind::Tag, bracket_len(rest, '[', ']', false));
+            continue;
+        }
+
+        if let Some(n) = string_len(rest, syn) {
+            let kind = if is_key(&rest[n..], syn) {
+                TokenKind::Property
+            } else {
+                TokenKind::String
+            };
+            lx.emit(kind, n);
+            continue;
+        }
+
+        if syn.lifetimes && c == '\'' {
+            let n = ident_len(&rest[1..], &[]);
+            if n > 0 && !rest[1 + n..].starts_with('\'') {
+                lx.emit(TokenKind::Label, 1 + n);
+                continue;
+            }
+            let n = quoted_len(rest, "'", "'", true, false);
+            lx.emit(TokenKind::String, n);
+            continue;
+        }
+
+        let after_ident = lx.prev().is_some_and(|p| is_ident_char(p, &[]));
+        let leading_dot = c == '.' && rest[1..].starts_with(|d: char| d.is_ascii_digit());
+        if !after_ident && (c.is_ascii_digit() || leading_dot) {
+            lx.emit(TokenKind::Number, number_len(rest).max(1));
+            continue;
+        }
+
+        if is_ident_start(c) || (c != '-' && syn.ident_chars.contains(&c)) {
+            let n = ident_len(rest, syn.ident_chars);
+            let word = &rest[..n];
+            let after = &rest[n..];
+
+            if syn.bang_macros && after.starts_with('!') && !after.starts_with("!=") {
+                lx.emit(TokenKind::Macro, n + 1);
+                continue;
+            }
+
+            let kind = if is_key(after, syn) {
+                TokenKind::Property
+            } else if syn.has_word(syn.keywords, word) {
+                if syn.has_word(syn.fn_keywords, word) {
+                    pending = Some(TokenKind::Function);
+                } else if syn.has_word(syn.type_keywords, word) {
+                    pending = Some(TokenKind::Type);
+                }
+                TokenKind::Keyword
+            } else if let Some(kind) = def_kind {
+                kind
+            } else if syn.has_word(syn.constants, word) {
+                TokenKind::Constant
+            } else if syn.has_word(syn.types, word) {
+                TokenKind::Type
+            } else if syn.capitalized_types && word.starts_with(|c: char| c.is_uppercase()) {
+                let screaming = word.len() > 1
+                    && word
+                        .chars()
+                        .all(|c| c.is_uppercase() || c.is_ascii_digit() || c == '_');
+                if screaming {
+                    TokenKind::Constant
+                } else {
+                    TokenKind::Type
+                }
+            } else if syn.has_word(syn.builtins, word) || first_non_blank(after) == Some('(') {
+                TokenKind::Function
+            } else {
+                TokenKind::Text
+            };
+            lx.emit(kind, n);
+            continue;
+        }
+
+        lx.emit_symbol(c);
+    }
+}
+
+/// Whether the text after an identifier or string marks it as a property key
+fn is_key(after: &str, syn: &Syntax) -> bool {
+    let Some(sep) = syn.key_separator else {
+        return false;
+    };
+    let after = after.trim_start_matches([' ', '\t']);
+    after.starts_with(sep) && !after[sep.len_utf8()..].starts_with(sep)
+}
+
+/// Length of a string literal at the start of `s`, if there is one
+fn string_len(s: &str, syn: &Syntax) -> Option<usize> {
+    // Longest prefix first so `rb"..."` wins over `r`
+    let prefix = syn
+        .string_prefixes
+        .iter()
+        .filter(|p| s.starts_with(**p))
+        .map(|p| p.len())
+        .max()
+        .unwrap_or(0);
+
+    for start in [prefix, 0] {
+        let body = &s[start..];
+
+        if syn.raw_strings {
+            // r"..." / r#"..."# / br#"..."#
+            if let Some(raw) = body.strip_prefix('r') {
+                let hashes = raw.len() - raw.trim_start_matches('#').len();
+                if raw[hashes..].starts_with('"') {
+                    let close = format!("\"{}", "#".repeat(hashes));
+                    let open_len = 1 + hashes + 1;
+                    let n = body[open_len..]
+                        .find(&close)
+                        .map_or(body.len(), |i| open_len + i + close.len());
+                    return Some(start + n);
+                }
+            }
+        }
+
+        if let Some(delim) = syn.long_strings.iter().find(|d| body.starts_with(**d)) {
+            return Some(start + quoted_len(body, delim, delim, true, true));
+        }
+
+        if let Some(q) = syn.quotes.iter().find(|q| body.starts_with(**q)) {
+            let q = q.to_string();
+            return Some(start + quoted_len(body, &q, &q, true, syn.multiline_strings));
+        }
+
+        if start == 0 {
+            break;
+        }
+    }
+    None
+}
+
+/// Length of a shell-style variable reference (`$x`, `${x}`, `$1`, `$@`)
+fn variable_len(s: &str) -> Option<usize> {
+    let body = &s[1..];
+    if body.starts_with('{') {
+        return Some(1 + bracket_len(body, '{', '}', false));
+    }
+    let n = ident_len(body, &[]);
+    if n > 0 {
+        return Some(1 + n);
+    }
+    body.starts_with(|c: char| "@#?*!$-".contains(c))
+        .then_some(2)
+}
+
+// ---------------------------------------------------------------------------
+// CSS / SCSS
+// ---------------------------------------------------------------------------
+
+fn lex_css(lx: &mut Lexer<'_>, scss: bool) {
+    while let Some(c) = lx.peek() {
+        let rest = lx.rest();
+
+        if c.is_whitespace() {
+            let n = rest
+                .find(|c: char| !c.is_whitespace())
+                .unwrap_or(rest.len());
+            lx.emit(TokenKind::Text, n);
+        } else if rest.starts_with("/*") {
+            let n = rest.find("*/").map_or(rest.len(), |i| i + 2);
+            lx.emit(TokenKind::Comment, n);
+        } else if scss && rest.starts_with("//") {
+            lx.emit_line(TokenKind::Comment);
+        } else if c == '@' {
+            // At-rule keyword, then its prelude (`(max-width: 600px)`, `mixin-name(...)`)
+            lx.emit(TokenKind::Keyword, 1 + ident_len(&rest[1..], &['-']));
+            lex_css_value(lx, scss);
+        } else if scss && rest.starts_with("#{") {
+            lx.emit(
+                TokenKind::Variable,
+                1 + bracket_len(&rest[1..], '{', '}', false),
+            );
+        } else if css_in_declaration(rest) {
+            lex_css_declaration(lx, scss);
+        } else {
+            lex_css_selector(lx, c);
+        }
+    }
+}
+
+/// Whether the text at `s` is a `property: value` declaration rather than a selector
+fn css_in_declaration(s: &str) -> bool {
+    if !s.starts_with(|c: char| c.is_alphabetic() || c == '-' || c == '$') {
+        return false;
+    }
+    // A selector is followed by `{`; a declaration ends with `;` or `}`
+    match s.find(['{', ';', '}']) {
+        Some(i) if s[..i].ends_with('#') => s[..i].contains(':'),
+        Some(i) => s.as_bytes()[i] != b'{' && s[..i].contains(':'),
+        None => s.contains(':'),
+    }
+}
+
+fn lex_css_declaration(lx: &mut Lexer<'_>, scss: bool) {
+    let rest = lx.rest();
+    let n = ident_len(rest, &['-', '$']);
+    // Custom properties and SCSS variables are highlighted as variables
+    let kind = if rest.starts_with("--") || rest.starts_with('$') {
+        TokenKind::Variable
+    } else {
+        TokenKind::Property
+    };
+    lx.emit(kind, n);
+    lx.emit_blank();
+    if lx.rest().starts_with(':') {
+        lx.emit(TokenKind::Punctuation, 1);
+    }
+    lex_css_value(lx, scss);
+}
+
+/// Lex a property value or at-rule prelude, up to the `;`, `{` or `}` that ends it
+fn lex_css_value(lx: &mut Lexer<'_>, scss: bool) {
+    while let Some(c) = lx.peek() {
+        let rest = lx.rest();
+        if c == ';' || c == '{' || c == '}' || rest.starts_with("/*") {
+            break;
+        }
+        if c == '"' || c == '\'' {
+            let q = c.to_string();
+            lx.emit(TokenKind::String, quoted_len(rest, &q, &q, true, false));
+        } else if scss && rest.starts_with("#{") {
+            lx.emit(
+                TokenKind::Variable,
+                1 + bracket_len(&rest[1..], '{', '}', false),
+            );
+        } else if c == '#' && rest[1..].starts_with(|c: char| c.is_ascii_hexdigit()) {
+            lx.emit(TokenKind::Number, 1 + ident_len(&rest[1..], &[]));
+        } else if c.is_ascii_digit()
+            || ((c == '.' || c == '-') && rest[1..].starts_with(|d: char| d.is_ascii_digit()))
+        {
+            let n = 1 + rest[1..]
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '.' || c == '%'))
+                .unwrap_or(rest.len() - 1);
+            lx.emit(TokenKind::Number, n);
+        } else if c == '!' {
+            lx.emit(TokenKind::Keyword, 1 + ident_len(&rest[1..], &[]));
+        } else if c == '$' || rest.starts_with("--") {
+            lx.emit(TokenKind::Variable, ident_len(rest, &['-', '$']));
+        } else if is_ident_start(c) || c == '-' {
+            let n = ident_len(rest, &['-']);
+            let kind = if &rest[..n] == "-" {
+                TokenKind::Operator
+            } else if rest[n..].starts_with('(') {
+                TokenKind::Function
+            } else {
+                TokenKind::Constant
+            };
+            lx.emit(kind, n);
+        } else if c.is_whitespace() {
+            lx.emit(TokenKind::Text, c.len_utf8());
+        } else {
+            lx.emit_symbol(c);
+        }
+    }
+}
+
+fn lex_css_selector(lx: &mut Lexer<'_>, c: char) {
+    let rest = lx.rest();
+    match c {
+        '.' | '#' => {
+            let n = 1 + ident_len(&rest[1..], &['-']);
+            let kind = if c == '.' {
+                TokenKind::Type
+            } else {
+                TokenKind::Constant
+            };
+            lx.emit(kind, n);
+        }
+        ':' => {
+            let colons = if rest.starts_with("::") { 2 } else { 1 };
+            let n = colons + ident_len(&rest[colons..], &['-']);
+            lx.emit(TokenKind::Attribute, n);
+        }
+        '[' => lx.emit(TokenKind::Attribute, bracket_len(rest, '[', ']', false)),
+        '&' | '>' | '+' | '~' | '*' => lx.emit(TokenKind::Operator, 1),
+        _ if c.is_ascii_digit() => lx.emit(TokenKind::Number, number_len(rest)),
+        _ if is_ident_start(c) => lx.emit(TokenKind::Tag, ident_len(rest, &['-'])),
+        _ => lx.emit_symbol(c),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// HTML
+// ---------------------------------------------------------------------------
+
+fn lex_html(lx: &mut Lexer<'_>) {
+    while let Some(c) = lx.peek() {
+        let rest = lx.rest();
+
+        if rest.starts_with("<!--") {
+            let n = rest.find("-->").map_or(rest.len(), |i| i + 3);
+            lx.emit(TokenKind::Comment, n);
+        } else if rest.starts_with("<!") {
+            let n = rest.find('>').map_or(rest.len(), |i| i + 1);
+            lx.emit(TokenKind::Keyword, n);
+        } else if c == '<' && rest[1..].starts_with(|c: char| c.is_alphabetic() || c == '/') {
+            let closing = rest.starts_with("</");
+            lx.emit(TokenKind::Punctuation, if closing { 2 } else { 1 });
+            let name_len = ident_len(lx.rest(), &['-', ':']);
+            let name = lx.rest()[..name_len].to_ascii_lowercase();
+            lx.emit(TokenKind::Tag, name_len);
+            lex_html_attributes(lx);
+
+            // Highlight embedded scripts and styles with their own lexers
+            if !closing && (name == "script" || name == "style") {
+                let end = format!("</{name}");
+                let body_len = lx
+                    .rest()
+                    .to_ascii_lowercase()
+                    .find(&end)
+                    .unwrap_or(lx.rest().len());
+                let language = if name == "script" {
+                    Language::Javascript
+                } else {
+                    Language::Css
+                };
+                lx.embed(body_len, language);
+            }
+        } else if c == '&' {
+            let n = rest[1..]
+                .find(|c: char| !(c.is_alphanumeric() || c == '#'))
+                .filter(|&i| i > 0 && rest[1 + i..].starts_with(';'))
+                .map_or(1, |i| i + 2);
+            let kind = if n > 1 {
+                TokenKind::Constant
+            } else {
+                TokenKind::Text
+            };
+            lx.emit(kind, n);
+        } else {
+            let n = rest
+                .find(['<', '&'])
+                .unwrap_or(rest.len())
+                .max(c.len_utf8());
+            lx.emit(TokenKind::Text, n);
+        }
+    }
+}
+
+fn lex_html_attributes(lx: &mut Lexer<'_>) {
+    while let Some(c) = lx.peek() {
+        let rest = lx.rest();
+        if c == '>' || rest.starts_with("/>") {
+            lx.emit(TokenKind::Punctuation, if c == '>' { 1 } else { 2 });
+            return;
+        }
+        if c.is_whitespace() {
+            let n = rest
+                .find(|c: char| !c.is_whitespace())
+                .unwrap_or(rest.len());
+            lx.emit(TokenKind::Text, n);
+        } else if c == '"' || c == '\'' {
+            let q = c.to_string();
+            lx.emit(TokenKind::String, quoted_len(rest, &q, &q, false, true));
+        } else if c == '=' {
+            lx.emit(TokenKind::Operator, 1);
+        } else if lx.prev() == Some('=') {
+            // Unquoted attribute value
+            let n = rest
+                .find(|c: char| c.is_whitespace() || c == '>')
+                .unwrap_or(rest.len());
+            lx.emit(TokenKind::String, n);
+        } else {
+            let n = rest
+                .find(|c: char| c.is_whitespace() || "=>/\"'".contains(c))
+                .unwrap_or(rest.len())
+                .max(c.len_utf8());
+            lx.emit(TokenKind::Attribute, n);
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Jinja
+// ---------------------------------------------------------------------------
+
+/// Jinja templates: HTML with `{{ }}`, `{% %}` and `{# #}` tags
+fn lex_jinja(lx: &mut Lexer<'_>) {
+    while !lx.rest().is_empty() {
+        let rest = lx.rest();
+        let Some(start) = ["{{", "{%", "{#"]
+            .iter()
+            .filter_map(|open| rest.find(open))
+            .min()
+        else {
+            lx.embed(rest.len(), Language::Html);
+            break;
+        };
+        lx.embed(start, Language::Html);
+
+        let rest = lx.rest();
+        if rest.starts_with("{#") {
+            let n = rest.find("#}").map_or(rest.len(), |i| i + 2);
+            lx.emit(TokenKind::Comment, n);
+            continue;
+        }
+
+        let close = if rest.starts_with("{{") { "}}" } else { "%}" };
+        let Some(end) = rest[2..].find(close).map(|i| i + 2) else {
+            lx.emit(TokenKind::Text, rest.len());
+            break;
+        };
+        lx.emit(TokenKind::Punctuation, 2);
+        let mut inner = Lexer::new(&rest[2..end]);
+        lex_code(&mut inner, &JINJA);
+        for token in inner.tokens {
+            lx.emit(token.kind, token.text.len());
+        }
+        lx.emit(TokenKind::Punctuation, 2);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// YAML
+// ---------------------------------------------------------------------------
+
+fn lex_yaml(lx: &mut Lexer<'_>) {
+    // Indentation of the key owning a `|` / `>` block scalar
+    let mut block_scalar: Option<usize> = None;
+
+    while lx.peek().is_some() {
+        let line = &lx.rest()[..line_len(lx.rest())];
+        let indent = line.len() - line.trim_start().len();
+        let trimmed = line.trim_start();
+
+        if let Some(owner) = block_scalar {
+            if trimmed.is_empty() || indent > owner {
+                lx.emit(TokenKind::String, line.len());
+                lx.emit(TokenKind::Text, usize::from(lx.peek().is_some()));
+                continue;
+            }
+            block_scalar = None;
+        }
+
+        lx.emit_blank();
+
+        if trimmed.starts_with('#') {
+            lx.emit_line(TokenKind::Comment);
+        } else if trimmed.starts_with("---") || trimmed.starts_with("...") {
+            lx.emit_line(TokenKind::Punctuation);
+        } else {
+            while lx.rest().starts_with("- ") || lx.rest() == "-" || lx.rest().starts_with("-\n") {
+                lx.emit(TokenKind::Punctuation, 1);
+                lx.emit_blank();
+            }
+            let key_indent = lx.pos - (lx.src[..lx.pos].rfind('\n').map_or(0, |i| i + 1));
+            if let Some(key_len) = yaml_key_len(&lx.rest()[..line_len(lx.rest())]) {
+                lx.emit(TokenKind::Property, key_len);
+                lx.emit(TokenKind::Punctuation, 1);
+            }
+            if lex_yaml_value(lx) {
+                block_scalar = Some(key_indent);
+            }
+        }
+
+        // Newline
+        lx.emit(TokenKind::Text, usize::from(lx.peek().is_some()));
+    }
+}
+
+/// Length of a `key` at the start of a YAML line, if the line is a mapping entry
+fn yaml_key_len(line: &str) -> Option<usize> {
+    if line.starts_with(['"', '\'']) {
+        let q = &line[..1];
+        let n = quoted_len(line, q, q, q == "\"", false);
+        return line[n..].starts_with(':').then_some(n);
+    }
+    if line.starts_with(['{', '[', '#', '&', '*', '!', '|', '>']) {
+        return None;
+    }
+    let bytes = line.as_bytes();
+    for i in 1..bytes.len() {
+        if bytes[i] == b':' && bytes.get(i + 1).is_none_or(|b| *b == b' ' || *b == b'\t') {
+            return Some(i);
+        }
+        if bytes[i] == b'#' && bytes[i - 1] == b' ' {
+            // A comment ends the candidate key
+            return None;
+        }
+    }
+    None
+}
+
+fn is_yaml_number(scalar: &str) -> bool {
+    let digits = scalar.trim_start_matches(['-', '+']);
+    let starts_numeric = digits.starts_with(|c: char| c.is_ascii_digit())
+        || (digits.starts_with('.') && digits[1..].starts_with(|c: char| c.is_ascii_digit()));
+    starts_numeric && number_len(digits) == digits.len()
+}
+
+/// Lex the rest of a YAML line as a value. Returns true if it opens a block scalar.
+fn lex_yaml_value(lx: &mut Lexer<'_>) -> bool {
+    let mut block = false;
+    while let Some(c) = lx.peek() {
+        let rest = lx.rest();
+        if c == '\n' {
+            break;
+        }
+        let word_start = lx
+            .prev()
+            .is_none_or(|p| p.is_whitespace() || "[{,:".contains(p));
+        if c == ' ' || c == '\t' {
+            lx.emit_blank();
+        } else if c == '#' && word_start {
+            lx.emit_line(TokenKind::Comment);
+        } else if c == '"' || c == '\'' {
+            let q = c.to_string();
+            lx.emit(TokenKind::String, quoted_len(rest, &q, &q, c == '"', true));
+        } else if (c == '&' || c == '*') && word_start {
+            lx.emit(TokenKind::Variable, 1 + ident_len(&rest[1..], &['-']));
+        } else if c == '!' && word_start {
+            let n = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            lx.emit(TokenKind::Type, n);
+        } else if (c == '|' || c == '>') && word_start {
+            let n = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            lx.emit(TokenKind::Operator, n);
+            block = true;
+        } else if "[]{},".contains(c) {
+            lx.emit(TokenKind::Punctuation, 1);
+        } else {
+            // Plain scalar: up to a comment, flow indicator or end of line
+            let line = &rest[..line_len(rest)];
+            let mut end = line.len();
+            for (i, ch) in line.char_indices() {
+                let prev_blank = i > 0 && line[..i].ends_with([' ', '\t']);
+                if (ch == '#' && prev_blank) || (i > 0 && ",]}".contains(ch)) {
+                    end = i;
+                    break;
+                }
+            }
+            let scalar = line[..end].trim_end();
+            let n = scalar.len().max(c.len_utf8());
+            let kind = match scalar {
+                "true" | "false" | "yes" | "no" | "on" | "off" | "null" | "~" | "True"
+                | "False" | "Null" | "NULL" => TokenKind::Constant,
+                _ if is_yaml_number(scalar) => TokenKind::Number,
+                _ => TokenKind::String,
+            };
+            lx.emit(kind, n);
+        }
+    }
+    block
+}
+
+// ---------------------------------------------------------------------------
+// Markdown
+// ---------------------------------------------------------------------------
+
+fn lex_markdown(lx: &mut Lexer<'_>) {
+    // Closing marker of the fenced code block we're inside, if any
+    let mut fence: Option<String> = None;
+
+    while lx.peek().is_some() {
+        let line = &lx.rest()[..line_len(lx.rest())];
+        let trimmed = line.trim_start();
+
+        if let Some(marker) = &fence {
+            if trimmed.starts_with(marker.as_str()) {
+                lx.emit(TokenKind::Punctuation, line.len());
+                fence = None;
+            } else {
+                lx.emit(TokenKind::String, line.len());
+            }
+        } else if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            let fence_char = trimmed.as_bytes()[0] as char;
+            let fence_len = trimmed.len() - trimmed.trim_start_matches(fence_char).len();
+            fence = Some(fence_char.to_string().repeat(fence_len));
+            lx.emit_blank();
+            lx.emit(TokenKind::Punctuation, fence_len);
+            lx.emit_line(TokenKind::Attribute);
+        } else if is_atx_heading(trimmed) {
+            lx.emit(TokenKind::Heading, line.len());
+        } else if is_thematic_break(trimmed) {
+            lx.emit(TokenKind::Punctuation, line.len());
+        } else {
+            lx.emit_blank();
+            while lx.rest().starts_with('>') {
+                lx.emit(TokenKind::Punctuation, 1);
+                lx.emit_blank();
+            }
+            if let Some(n) = list_marker_len(lx.rest()) {
+                lx.emit(TokenKind::Punctuation, n);
+                lx.emit_blank();
+                let r = lx.rest();
+                if r.starts_with("[ ]") || r.starts_with("[x]") || r.starts_with("[X]") {
+                    lx.emit(TokenKind::Keyword, 3);
+                }
+            }
+            let len = line_len(lx.rest());
+            lex_markdown_inline(lx, len);
+        }
+
+        lx.emit(TokenKind::Text, usize::from(lx.peek().is_some()));
+    }
+}
+
+fn is_atx_heading(line: &str) -> bool {
+    let hashes = line.len() - line.trim_start_matches('#').len();
+    (1..=6).contains(&hashes) && (line.len() == hashes || line[hashes..].starts_with([' ', '\t']))
+}
+
+fn is_thematic_break(line: &str) -> bool {
+    let line = line.trim_end();
+    ['-', '*', '_'].iter().any(|&m| {
+        line.chars().filter(|&c| c == m).count() >= 3 && line.chars().all(|c| c == m || c == ' ')
+    })
+}
+
+/// Length of a list marker (`- `, `* `, `+ `, `1. `, `1) `) at the start of `s`
+fn list_marker_len(s: &str) -> Option<usize> {
+    if s.starts_with(['-', '*', '+']) && s[1..].starts_with([' ', '\t']) {
+        return Some(1);
+    }
+    let digits = s.len() - s.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    (digits > 0 && s[digits..].starts_with(['.', ')']) && s[digits + 1..].starts_with([' ', '\t']))
+        .then_some(digits + 1)
+}
+
+/// Lex `len` bytes of inline Markdown (emphasis, code spans, links)
+fn lex_markdown_inline(lx: &mut Lexer<'_>, len: usize) {
+    let end = lx.pos + len;
+    while lx.pos < end {
+        let rest = &lx.src[lx.pos..end];
+        let c = rest.chars().next().unwrap_or(' ');
+
+        if c == '\\' && rest.len() > 1 {
+            let n = 1 + rest[1..].chars().next().map_or(0, char::len_utf8);
+            lx.emit(TokenKind::Text, n);
+        } else if c == '`' {
+            let ticks = rest.len() - rest.trim_start_matches('`').len();
+            let marker = &rest[..ticks];
+            let n = rest[ticks..]
+                .find(marker)
+                .map_or(ticks, |i| ticks + i + ticks);
+            let kind = if n > ticks {
+                TokenKind::String
+            } else {
+                TokenKind::Text
+            };
+            lx.emit(kind, n);
+        } else if rest.starts_with("**") || rest.starts_with("__") {
+            let marker = &rest[..2];
+            match rest[2..].find(marker) {
+                Some(i) if i > 0 => lx.emit(TokenKind::Strong, i + 4),
+                _ => lx.emit(TokenKind::Text, 2),
+            }
+        } else if (c == '*' || (c == '_' && !lx.prev().is_some_and(char::is_alphanumeric)))
+            && rest.len() > 1
+            && !rest[1..].starts_with([' ', '\t'])
+        {
+            let marker = &rest[..1];
+            match rest[1..].find(marker) {
+                Some(i) if i > 0 => lx.emit(TokenKind::Emphasis, i + 2),
+                _ => lx.emit(TokenKind::Text, 1),
+            }
+        } else if let Some(struck) = rest.strip_prefix("~~") {
+            match struck.find("~~") {
+                Some(i) if i > 0 => lx.emit(TokenKind::Comment, i + 4),
+                _ => lx.emit(TokenKind::Text, 2),
+            }
+        } else if c == '[' || rest.starts_with("![") {
+            let bang = usize::from(c == '!');
+            let text_len = bracket_len(&rest[bang..], '[', ']', false);
+            let target = &rest[bang + text_len..];
+            if text_len > 1 && target.starts_with('(') {
+                lx.emit(TokenKind::Link, bang + text_len);
+                lx.emit(TokenKind::String, bracket_len(target, '(', ')', false));
+            } else {
+                lx.emit(TokenKind::Text, bang + 1);
+            }
+        } else if c == '<' && (rest.starts_with("<http") || rest.starts_with("<mailto:")) {
+            let n = rest.find('>').map_or(rest.len(), |i| i + 1);
+            lx.emit(TokenKind::Link, n);
+        } else {
+            let n = rest[c.len_utf8()..]
+                .find(['\\', '`', '*', '_', '~', '[', '!', '<'])
+                .map_or(rest.len(), |i| i + c.len_utf8());
+            lx.emit(TokenKind::Text, n);
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Assembly
+// ---------------------------------------------------------------------------
+
+#[rustfmt::skip]
+const ASM_REGISTERS: &[&str] = &[
+    "rax", "rbx", "rcx", "rdx", "rsi", "rdi", "rbp", "rsp", "rip", "eax", "ebx", "ecx", "edx",
+    "esi", "edi", "ebp", "esp", "ax", "bx", "cx", "dx", "al", "bl", "cl", "dl", "ah", "bh", "ch",
+    "dh", "sp", "lr", "pc", "fp", "xzr", "wzr",
+];
+
+fn is_asm_register(word: &str) -> bool {
+    let lower = word.to_ascii_lowercase();
+    if ASM_REGISTERS.contains(&lower.as_str()) {
+        return true;
+    }
+    // r8-r15 (+ suffixes), x0-x30, w0-w30, v0-v31, xmm0..., ymm0..., zmm0...
+    let numbered = |prefix: &str| {
+        lower.strip_prefix(prefix).is_some_and(|n| {
+            n.starts_with(|c: char| c.is_ascii_digit())
+                && n.trim_start_matches(|c: char| c.is_ascii_digit()).len() <= 1
+        })
+    };
+    ["r", "x", "w", "v", "q", "d", "s", "xmm", "ymm", "zmm"]
+        .iter()
+        .any(|p| numbered(p))
+}
+
+fn lex_asm(lx: &mut Lexer<'_>) {
+    while lx.peek().is_some() {
+        lx.emit_blank();
+        let mut expect_mnemonic = true;
+
+        while let Some(c) = lx.peek() {
+            let rest = lx.rest();
+            if c == '\n' {
+                break;
+            }
+            if c == ';' || rest.starts_with("//") || (c == '#' && lx.at_line_start()) {
+                lx.emit_line(TokenKind::Comment);
+            } else if rest.starts_with("/*") {
+                let n = rest.find("*/").map_or(rest.len(), |i| i + 2);
+                lx.emit(TokenKind::Comment, n);
+            } else if c == ' ' || c == '\t' {
+                lx.emit_blank();
+            } else if c == '"' || c == '\'' {
+                let q = c.to_string();
+                lx.emit(TokenKind::String, quoted_len(rest, &q, &q, true, false));
+            } else if c == '%' {
+                lx.emit(TokenKind::Variable, 1 + ident_len(&rest[1..], &[]));
+            } else if (c == '$' || c == '#')
+                && rest[1..].starts_with(|d: char| d.is_ascii_digit() || d == '-')
+            {
+                let sign = usize::from(rest[1..].starts_with('-'));
+                lx.emit(TokenKind::Number, 1 + sign + number_len(&rest[1 + sign..]));
+            } else if c.is_ascii_digit() {
+                lx.emit(TokenKind::Number, number_len(rest));
+            } else if is_ident_start(c) || c == '.' {
+                let n = ident_len(rest, &['.', '$']).max(1);
+                let word = &rest[..n];
+                let kind = if rest[n..].starts_with(':') {
+                    TokenKind::Label
+                } else if c == '.' && expect_mnemonic {
+                    TokenKind::Attribute
+                } else if expect_mnemonic {
+                    TokenKind::Keyword
+                } else if is_asm_register(word) {
+                    TokenKind::Variable
+                } else {
+                    TokenKind::Text
+                };
+                if kind != TokenKind::Label {
+                    expect_mnemonic = false;
+                }
+                lx.emit(kind, n);
+            } else {
+                lx.emit_symbol(c);
+            }
+        }
+
+        lx.emit(TokenKind::Text, usize::from(lx.peek().is_some()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LANGUAGES: &[Language] = &[
+        Language::Rust,
+        Language::Asm,
+        Language::Scss,
+        Language::Javascript,
+        Language::TypeScript,
+        Language::Html,
+        Language::Css,
+        Language::Json,
+        Language::Toml,
+        Language::Yaml,
+        Language::Markdown,
+        Language::Python,
+        Language::Bash,
+        Language::Go,
+        Language::C,
+        Language::Cpp,
+        Language::CSharp,
+        Language::Java,
+        Language::Kotlin,
+        Language::Swift,
+        Language::Ruby,
+        Language::Php,
+        Language::Lua,
+        Language::Perl,
+        Language::R,
+        Language::Scala,
+        Language::Elixir,
+        Language::Haskell,
+        Language::Clojure,
+        Language::Zig,
+        Language::Sql,
+        Language::Xml,
+        Language::Jinja,
+        Language::Dockerfile,
+        Language::Makefile,
+        Language::Git,
+    ];
+
+    const SAMPLES: &[&str] = &[
+        "",
+        "\n",
+        "   \t ",
+        "fn main() {\n    println!(\"héllo, {}\", 'x');\n}\n",
+        "\"unterminated string",
+        "'",
+        "/* unterminated block comment\nstill going",
+        "// trailing comment without newline",
+        "# heading\n- item *em* **strong** [link](url)\n```rust\nlet x = 1;\n```",
+        "<div class=\"a\" {{ value }}><!-- c --></div><unclosed",
+        "key: value\n  - 'quoted' # note\nother: 0x1F\n",
+        ".a:hover { color: #fff; --gap: 1rem; }\n@media (x) { $v: 1; }",
+        "r#\"raw \"# string\"#; b'\\'' 1.5e-3 0b1010 'a: loop {}",
+        "{% if x %}{{ y | upper }}{# c #}{% endif %}",
+        "mov rax, [rbx+8] ; comment\nlabel:\n",
+        "日本語 🎉 emoji \\ back\\slash\r\nwindows line\r\n",
+        "$HOME ${VAR:-default} $(cmd) `tick` <<EOF\n",
+        "x = [1, 2, {\"k\": null}] @decorator\n",
+    ];
+
+    #[test]
+    fn tokens_reproduce_the_input() {
+        for &language in LANGUAGES {
+            for &sample in SAMPLES {
+                let tokens = highlight(sample, language);
+                let joined: String = tokens.iter().map(|t| t.text).collect();
+                assert_eq!(joined, sample, "{language:?} changed {sample:?}");
+                assert!(
+                    tokens.iter().all(|t| !t.text.is_empty()),
+                    "{language:?} produced an empty token for {sample:?}"
+                );
+                assert!(
+                    tokens.windows(2).all(|w| w[0].kind != w[1].kind),
+                    "{language:?} left adjacent tokens of the same kind for {sample:?}"
+                );
+            }
+        }
+    }
+
+    fn kind_of(code: &str, language: Language, text: &str) -> Option<TokenKind> {
+        highlight(code, language)
+            .into_iter()
+            .find(|t| t.text == text)
+            .map(|t| t.kind)
+    }
+
+    #[test]
+    fn rust_tokens() {
+        let code = "fn main() { let s = \"hi\"; // done\n}";
+        assert_eq!(
+            kind_of(code, Language::Rust, "fn"),
+            Some(TokenKind::Keyword)
+        );
+        assert_eq!(
+            kind_of(code, Language::Rust, "\"hi\""),
+            Some(TokenKind::String)
+        );
+        assert_eq!(
+            kind_of(code, Language::Rust, "// done"),
+            Some(TokenKind::Comment)
+        );
+    }
+
+    #[test]
+    fn unterminated_string_runs_to_the_end() {
+        let tokens = highlight("let s = \"open", Language::Rust);
+        assert_eq!(
+            tokens.last().map(|t| (t.kind, t.text)),
+            Some((TokenKind::String, "\"open"))
+        );
+    }
+
+    #[test]
+    fn python_comment_and_string() {
+        let code = "x = 'a#b'  # real comment";
+        assert_eq!(
+            kind_of(code, Language::Python, "'a#b'"),
+            Some(TokenKind::String)
+        );
+        assert_eq!(
+            kind_of(code, Language::Python, "# real comment"),
+            Some(TokenKind::Comment)
+        );
+    }
+}
@@ -2,11 +2,15 @@
 
 use dioxus::prelude::*;
 
+pub mod ansi;
 pub mod components;
+pub mod form;
+pub mod highlight;
 pub mod hooks;
 pub mod utils;
 
 pub use components::*;
+pub use form::{use_form, FieldRules, Form, FormRules};
 pub use hooks::{use_callback_channel, CallbackSender};
 
 // NOTE: asset!() macro requires CARGO_MANIFEST_DIR which isn't set in Buck2 builds.
@@ -50,3 +50,50 @@ impl MountedInputExt for dioxus::html::MountedData {
             .and_then(|el| el.dyn_into::<web_sys::HtmlInputElement>().ok())
     }
 }
+
+/// Write text to the system clipboard (no-op outside the browser)
+pub fn write_clipboard(text: &str) {
+    #[cfg(target_arch = "wasm32")]
+    if let Some(window) = web_sys::window() {
+        let _ = window.navigator().clipboard().write_text(text);
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    let _ = text;
+}
+
+/// Offer text as a file download (no-op outside the browser)
+pub fn download_text(filename: &str, mime_type: &str, text: &str) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        use wasm_bindgen::JsCast;
+
+        let Some(document) = web_sys::window().and_then(|w| w.document()) else {
+            return;
+        };
+        let Ok(link) = document.create_element("a") else {
+            return;
+        };
+        // A blob URL keeps large exports out of the URL itself
+        let options = web_sys::BlobPropertyBag::new();
+        options.set_type(&format!("{mime_type};charset=utf-8"));
+        let parts = js_sys::Array::of1(&wasm_bindgen::JsValue::from_str(text));
+        let Ok(blob) = web_sys::Blob::new_with_str_sequence_and_options(&parts, &options) else {
+            return;
+        };
+        let Ok(url) = web_sys::Url::create_object_url_with_blob(&blob) else {
+            return;
+        };
+        let _ = link.set_attribute("href", &url);
+        let _ = link.set_attribute("download", filename);
+        if let Ok(link) = link.dyn_into::<web_sys::HtmlElement>() {
+            link.click();
+        }
+        // Release the blob once the download has started
+        gloo_timers::callback::Timeout::new(0, move || {
+            let _ = web_sys::Url::revoke_object_url(&url);
+        })
+        .forget();
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    let _ = (filename, mime_type, text);
+}